@@ -0,0 +1,99 @@
+//! Benchmarks for the CPU-bound parts of hot zome paths: encoding and decoding of
+//! remote signals and deserializing the entries returned by the get_all_* functions.
+//!
+//! The DHT work of get_all_attachments and get_all_descendent_rooms needs a running
+//! conductor and is not covered here.
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use hdk::prelude::*;
+use room::remote_signals::{SignalEnvelope, SignalPayload};
+use room_integrity::*;
+
+fn fake_agent() -> AgentPubKey {
+    AgentPubKey::from_raw_36(vec![0xdb; 36])
+}
+
+fn envelope(payload: SignalPayload) -> SignalEnvelope {
+    SignalEnvelope {
+        sent_at: Timestamp::from_micros(0),
+        payload,
+    }
+}
+
+fn sdp_data_signal() -> SignalEnvelope {
+    envelope(SignalPayload::SdpData {
+        from_agent: fake_agent(),
+        connection_id: String::from("b1a3c2d4-5e6f-4a7b-8c9d-0e1f2a3b4c5d"),
+        // SDP offers of video calls are typically a few kilobytes
+        data: "a=candidate:1 1 udp 2122260223 192.168.1.2 54400 typ host\r\n".repeat(64),
+        trace_id: None,
+    })
+}
+
+fn bench_signals(c: &mut Criterion) {
+    c.bench_function("encode SdpData signal", |b| {
+        b.iter_batched(
+            sdp_data_signal,
+            |signal| ExternIO::encode(black_box(signal)).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    let encoded = ExternIO::encode(sdp_data_signal()).unwrap();
+    c.bench_function("decode SdpData signal", |b| {
+        b.iter(|| black_box(&encoded).decode::<SignalEnvelope>().unwrap())
+    });
+
+    let ping = ExternIO::encode(envelope(SignalPayload::Ping {
+        from_agent: fake_agent(),
+    }))
+    .unwrap();
+    c.bench_function("decode Ping signal", |b| {
+        b.iter(|| black_box(&ping).decode::<SignalEnvelope>().unwrap())
+    });
+}
+
+fn bench_entry_decoding(c: &mut Criterion) {
+    let attachments: Vec<Entry> = (0..100)
+        .map(|i| {
+            Entry::try_from(Attachment {
+                wal: format!("weave://hrl/uhC0kdnahash/uhCkkactionhash{i}?context=abc"),
+                sort_key: Some(i),
+                publish_at: None,
+                audited: false,
+            })
+            .unwrap()
+        })
+        .collect();
+    c.bench_function("decode 100 attachments", |b| {
+        b.iter(|| {
+            black_box(&attachments)
+                .iter()
+                .map(|entry| Attachment::try_from(entry).unwrap())
+                .collect::<Vec<Attachment>>()
+        })
+    });
+
+    let descendent_rooms: Vec<Entry> = (0..100)
+        .map(|i| {
+            Entry::try_from(DescendentRoom {
+                network_seed_appendix: format!("{i:032x}"),
+                dna_hash: DnaHash::from_raw_36(vec![i as u8; 36]),
+                name: format!("Breakout room {i}"),
+                icon_src: Some("data:image/png;base64,".to_string() + &"A".repeat(2048)),
+                meta_data: None,
+            })
+            .unwrap()
+        })
+        .collect();
+    c.bench_function("decode 100 descendent rooms", |b| {
+        b.iter(|| {
+            black_box(&descendent_rooms)
+                .iter()
+                .map(|entry| DescendentRoom::try_from(entry).unwrap())
+                .collect::<Vec<DescendentRoom>>()
+        })
+    });
+}
+
+criterion_group!(benches, bench_signals, bench_entry_decoding);
+criterion_main!(benches);
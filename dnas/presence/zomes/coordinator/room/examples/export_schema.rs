@@ -0,0 +1,9 @@
+//! Prints the JSON schema of the signals and zome function types of the room zome,
+//! e.g. `cargo run --example export_schema --features schema > ui/src/room-schema.json`
+fn main() {
+    let schema = room::schema::signal_schema();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&schema).expect("Failed to serialize schema")
+    );
+}
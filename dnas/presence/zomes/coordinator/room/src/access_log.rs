@@ -0,0 +1,136 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_agents::get_all_agents;
+use crate::attachment::get_latest_attachment;
+use crate::remote_signals::{encode_signal, SignalPayload};
+use crate::role_assignment::{get_other_hosts, has_role};
+
+/// Maximum number of accesses to a single attachment that a host records on behalf
+/// of other agents
+pub const MAX_DISCLOSED_ACCESSES_PER_ATTACHMENT: usize = 500;
+
+/// Maximum number of accesses to a single attachment that a host records on behalf
+/// of a single agent
+pub const MAX_DISCLOSED_ACCESSES_PER_SENDER: usize = 20;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RecordAttachmentAccessInput {
+    /// The original action of the attachment
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub attachment_hash: ActionHash,
+    /// Whether the hosts of the room get to record the access in their access logs
+    /// as well
+    #[serde(default)]
+    pub disclose_to_hosts: bool,
+}
+
+/// Records in this agent's private access log that it has fetched the attachment.
+/// To be called by clients whenever they open an attachment marked as audited.
+#[hdk_extern]
+pub fn record_attachment_access(input: RecordAttachmentAccessInput) -> ExternResult<ActionHash> {
+    if !is_audited(input.attachment_hash.clone())? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only accesses to audited attachments can be recorded"
+        ))));
+    }
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let accessed_at = sys_time()?;
+    let action_hash = create_entry(EntryTypes::AttachmentAccess(AttachmentAccess {
+        attachment_hash: input.attachment_hash.clone(),
+        accessed_by: my_pub_key.clone(),
+        accessed_at,
+    }))?;
+    if !input.disclose_to_hosts {
+        return Ok(action_hash);
+    }
+
+    let hosts = get_other_hosts()?;
+    if hosts.is_empty() {
+        return Ok(action_hash);
+    }
+    let signal_payload = SignalPayload::AttachmentAccessed {
+        from_agent: my_pub_key,
+        attachment_hash: input.attachment_hash,
+        accessed_at,
+    };
+    let encoded_signal = encode_signal(signal_payload)?;
+    send_remote_signal(encoded_signal, hosts)?;
+    Ok(action_hash)
+}
+
+/// Returns the accesses in this agent's access log, oldest first, optionally only
+/// those to the given attachment. For hosts, this includes the accesses disclosed
+/// to them.
+#[hdk_extern]
+pub fn get_access_log(attachment_hash: Option<ActionHash>) -> ExternResult<Vec<AttachmentAccess>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::AttachmentAccess.try_into()?)
+            .include_entries(true),
+    )?;
+    Ok(records
+        .into_iter()
+        .filter_map(|record| {
+            record
+                .entry()
+                .to_app_option::<AttachmentAccess>()
+                .ok()
+                .flatten()
+        })
+        .filter(|access| {
+            !attachment_hash
+                .as_ref()
+                .is_some_and(|attachment_hash| &access.attachment_hash != attachment_hash)
+        })
+        .collect())
+}
+
+/// Records an access that another member of the room has disclosed to this agent,
+/// if this agent is a host and the attachment is audited. Disclosures beyond the
+/// limits per attachment are dropped.
+pub fn receive_attachment_access(
+    from_agent: AgentPubKey,
+    attachment_hash: ActionHash,
+    accessed_at: Timestamp,
+) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !has_role(&my_pub_key, RoomRole::Host)? || accessed_at > sys_time()? {
+        return Ok(());
+    }
+    if !get_all_agents(())?.contains(&from_agent) || !is_audited(attachment_hash.clone())? {
+        return Ok(());
+    }
+    let disclosed_accesses: Vec<AttachmentAccess> = get_access_log(Some(attachment_hash.clone()))?
+        .into_iter()
+        .filter(|access| access.accessed_by != my_pub_key)
+        .collect();
+    let disclosed_by_sender = disclosed_accesses
+        .iter()
+        .filter(|access| access.accessed_by == from_agent)
+        .count();
+    if disclosed_accesses.len() >= MAX_DISCLOSED_ACCESSES_PER_ATTACHMENT
+        || disclosed_by_sender >= MAX_DISCLOSED_ACCESSES_PER_SENDER
+    {
+        return Ok(());
+    }
+    create_entry(EntryTypes::AttachmentAccess(AttachmentAccess {
+        attachment_hash,
+        accessed_by: from_agent,
+        accessed_at,
+    }))?;
+    Ok(())
+}
+
+/// Whether the latest version of the attachment is marked as audited
+fn is_audited(attachment_hash: ActionHash) -> ExternResult<bool> {
+    let Some(record) = get_latest_attachment(attachment_hash)? else {
+        return Ok(false);
+    };
+    Ok(record
+        .entry()
+        .to_app_option::<Attachment>()
+        .map_err(|e| wasm_error!(e))?
+        .is_some_and(|attachment| attachment.audited))
+}
@@ -0,0 +1,43 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+#[cfg(feature = "chat")]
+use crate::chat_read_marker::get_unread_count;
+use crate::presence_timeline::get_call_participants;
+use crate::room_settings::get_latest_room_settings;
+
+/// What the sidebar shows for a room
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ActivitySummary {
+    /// Whether any agent has recorded a heartbeat from within a call during the
+    /// current or the previous presence bucket
+    pub call_ongoing: bool,
+    /// Number of unread chat messages. None if this build has no chat.
+    pub unread_count: Option<u32>,
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub next_meeting_at: Option<Timestamp>,
+}
+
+/// Summarizes the activity of the room for UIs listing many rooms, e.g. the Moss
+/// sidebar. Meant to be polled across all rooms of the user, so it only does a
+/// fixed number of DHT queries regardless of the size of the room.
+#[hdk_extern]
+pub fn get_activity_summary(_: ()) -> ExternResult<ActivitySummary> {
+    let now = sys_time()?;
+    let call_ongoing = !get_call_participants()?.is_empty();
+
+    #[cfg(feature = "chat")]
+    let unread_count = Some(get_unread_count(())?);
+    #[cfg(not(feature = "chat"))]
+    let unread_count = None;
+
+    let next_meeting_at = get_latest_room_settings()?
+        .next_meeting_at
+        .filter(|next_meeting_at| *next_meeting_at > now);
+    Ok(ActivitySummary {
+        call_ongoing,
+        unread_count,
+        next_meeting_at,
+    })
+}
@@ -0,0 +1,160 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::remote_signals::{encode_signal, SignalPayload};
+use crate::role_assignment::{get_role_proof, get_roles_for_agent, get_room_hosts, get_room_roles};
+use crate::room_settings::get_latest_room_settings;
+
+/// Maximum number of join requests an admitting agent keeps pending. Further
+/// requests are dropped until some have been answered.
+pub const MAX_PENDING_JOIN_REQUESTS: usize = 100;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RespondToJoinRequestInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub admitted: bool,
+}
+
+/// Asks the hosts and moderators of the room to admit this agent to the call.
+/// Their responses arrive as AdmitResponse signals.
+#[hdk_extern]
+pub fn request_admission(_: ()) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if is_admitted(&my_pub_key)? {
+        return Ok(());
+    }
+
+    let signal_payload = SignalPayload::JoinRequest {
+        from_agent: my_pub_key,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, get_admitting_agents()?)
+}
+
+/// Admits or denies the agent and tells it the outcome. Only hosts and moderators
+/// may admit agents.
+#[hdk_extern]
+pub fn respond_to_join_request(input: RespondToJoinRequestInput) -> ExternResult<()> {
+    if input.admitted {
+        let granted_via = get_role_proof(&[RoomRole::Host, RoomRole::Moderator], "admit agents")?;
+        let action_hash = create_entry(EntryTypes::Admission(Admission {
+            agent: input.agent.clone(),
+            granted_via,
+        }))?;
+        create_link(
+            input.agent.clone(),
+            action_hash,
+            LinkTypes::AgentToAdmissions,
+            (),
+        )?;
+    }
+    create_entry(EntryTypes::AdmissionRequest(AdmissionRequest {
+        agent: input.agent.clone(),
+        status: if input.admitted {
+            AdmissionStatus::Admitted
+        } else {
+            AdmissionStatus::Denied
+        },
+    }))?;
+
+    let signal_payload = SignalPayload::AdmitResponse {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        admitted: input.admitted,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.agent])
+}
+
+/// Returns the agents that have asked this agent to admit them and haven't been
+/// admitted or denied yet, oldest request first
+#[hdk_extern]
+pub fn get_pending_join_requests(_: ()) -> ExternResult<Vec<AgentPubKey>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::AdmissionRequest.try_into()?)
+            .include_entries(true),
+    )?;
+    let mut latest_requests: Vec<AdmissionRequest> = Vec::new();
+    for record in records {
+        let Some(admission_request) = record
+            .entry()
+            .to_app_option::<AdmissionRequest>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        latest_requests.retain(|request| request.agent != admission_request.agent);
+        latest_requests.push(admission_request);
+    }
+    Ok(latest_requests
+        .into_iter()
+        .filter(|request| request.status == AdmissionStatus::Pending)
+        .map(|request| request.agent)
+        .collect())
+}
+
+/// Records a JoinRequest received from the given agent if we are able to admit it.
+/// Repeated requests of an agent that is already pending are passed on to the UI
+/// without another write, and requests beyond MAX_PENDING_JOIN_REQUESTS are dropped.
+pub fn handle_join_request(from_agent: AgentPubKey) -> ExternResult<bool> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !is_host_or_moderator(my_pub_key)? {
+        return Ok(false);
+    }
+    let pending = get_pending_join_requests(())?;
+    if pending.contains(&from_agent) {
+        return Ok(true);
+    }
+    if pending.len() >= MAX_PENDING_JOIN_REQUESTS {
+        return Ok(false);
+    }
+    create_entry(EntryTypes::AdmissionRequest(AdmissionRequest {
+        agent: from_agent,
+        status: AdmissionStatus::Pending,
+    }))?;
+    Ok(true)
+}
+
+/// Whether the agent may connect to the call. Hosts and moderators don't need to
+/// be admitted, and neither does anyone if the waiting room is disabled.
+pub fn is_admitted(agent: &AgentPubKey) -> ExternResult<bool> {
+    if !get_latest_room_settings()?.waiting_room {
+        return Ok(true);
+    }
+    if is_host_or_moderator(agent.clone())? {
+        return Ok(true);
+    }
+    let links = get_links(
+        GetLinksInputBuilder::try_new(agent.clone(), LinkTypes::AgentToAdmissions)?.build(),
+    )?;
+    Ok(!links.is_empty())
+}
+
+/// Whether the agent holds a role that lets it admit others. Contributors don't.
+fn is_host_or_moderator(agent: AgentPubKey) -> ExternResult<bool> {
+    Ok(get_roles_for_agent(agent)?
+        .iter()
+        .any(|role| matches!(role, RoomRole::Host | RoomRole::Moderator)))
+}
+
+/// Returns the agents able to admit others: the hosts, including the progenitor and
+/// the agent the hosting has been transferred to, and the moderators
+fn get_admitting_agents() -> ExternResult<Vec<AgentPubKey>> {
+    let mut agents: Vec<AgentPubKey> = get_room_roles(())?
+        .into_iter()
+        .filter(|holder| holder.role == RoomRole::Moderator)
+        .map(|holder| holder.agent)
+        .collect();
+    agents.extend(get_room_hosts()?);
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    agents.retain(|agent| agent != &my_pub_key);
+    agents.sort();
+    agents.dedup();
+    Ok(agents)
+}
@@ -0,0 +1,144 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::moderation_log::log_moderation_action;
+use crate::role_assignment::get_moderator_proof;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BanAgentInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BannedAgent {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub reason: Option<String>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub banned_by: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub banned_at: Timestamp,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent_ban_hash: ActionHash,
+}
+
+/// Bans the agent from the room. Only the progenitor of the room and moderators
+/// may do so.
+#[hdk_extern]
+pub fn ban_agent(input: BanAgentInput) -> ExternResult<ActionHash> {
+    let granted_via = get_moderator_proof("ban agents")?;
+    let action_hash = create_entry(EntryTypes::AgentBan(AgentBan {
+        agent: input.agent.clone(),
+        reason: input.reason.clone(),
+        granted_via,
+    }))?;
+    let path = Path::from(BANNED_AGENTS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::BannedAgents,
+        (),
+    )?;
+    log_moderation_action(
+        ModerationActionKind::Ban,
+        input.agent,
+        input.reason,
+        Some(action_hash.clone()),
+    )?;
+    Ok(action_hash)
+}
+
+/// Lifts all bans of the agent that this agent is allowed to lift
+#[hdk_extern]
+pub fn unban_agent(agent: AgentPubKey) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let is_progenitor = room_progenitor()?.as_ref() == Some(&my_pub_key);
+    let path = Path::from(BANNED_AGENTS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::BannedAgents)?.build(),
+    )?;
+    let banned_agents = banned_agents_for_links(links.clone())?;
+    for banned_agent in banned_agents {
+        if banned_agent.agent != agent || !(is_progenitor || banned_agent.banned_by == my_pub_key) {
+            continue;
+        }
+        for link in links.iter() {
+            if link.target.clone().into_action_hash().as_ref() == Some(&banned_agent.agent_ban_hash)
+            {
+                delete_link(link.create_link_hash.clone())?;
+            }
+        }
+        let delete_hash = delete_entry(banned_agent.agent_ban_hash)?;
+        log_moderation_action(
+            ModerationActionKind::Unban,
+            agent.clone(),
+            None,
+            Some(delete_hash),
+        )?;
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_banned_agents(_: ()) -> ExternResult<Vec<BannedAgent>> {
+    let path = Path::from(BANNED_AGENTS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::BannedAgents)?.build(),
+    )?;
+    banned_agents_for_links(links)
+}
+
+/// Returns the agents currently banned from the room, for reads to filter out
+/// their content
+pub fn get_banned_agent_keys() -> ExternResult<Vec<AgentPubKey>> {
+    Ok(get_banned_agents(())?
+        .into_iter()
+        .map(|banned_agent| banned_agent.agent)
+        .collect())
+}
+
+/// Whether the agent is currently banned from the room
+pub fn is_banned(agent: &AgentPubKey) -> ExternResult<bool> {
+    Ok(get_banned_agent_keys()?.contains(agent))
+}
+
+/// Fails if this agent has been banned from the room
+pub fn ensure_not_banned() -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if get_banned_agent_keys()?.contains(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "You have been banned from this room"
+        ))));
+    }
+    Ok(())
+}
+
+fn banned_agents_for_links(links: Vec<Link>) -> ExternResult<Vec<BannedAgent>> {
+    let mut banned_agents = Vec::new();
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get(action_hash.clone(), GetOptions::default())? else {
+            continue;
+        };
+        if let Some(agent_ban) = record
+            .entry()
+            .to_app_option::<AgentBan>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            banned_agents.push(BannedAgent {
+                agent: agent_ban.agent,
+                reason: agent_ban.reason,
+                banned_by: record.action().author().clone(),
+                banned_at: record.action().timestamp(),
+                agent_ban_hash: action_hash,
+            });
+        }
+    }
+    Ok(banned_agents)
+}
@@ -0,0 +1,29 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+#[hdk_extern]
+pub fn set_status(status: Status) -> ExternResult<()> {
+    create_entry(EntryTypes::AgentStatus(AgentStatus { status }))?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_status(_: ()) -> ExternResult<Status> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::AgentStatus.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records.last() else {
+        return Ok(Status::default());
+    };
+    let agent_status = latest_record
+        .entry()
+        .to_app_option::<AgentStatus>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(agent_status.map(|a| a.status).unwrap_or_default())
+}
+
+pub fn is_do_not_disturb() -> ExternResult<bool> {
+    Ok(get_status(())? == Status::DoNotDisturb)
+}
@@ -1,24 +1,151 @@
 use hdk::prelude::*;
 use room_integrity::*;
+
+use crate::agent_ban::{ensure_not_banned, get_banned_agent_keys};
+use crate::deprecation_notice::get_deprecation_notice;
+use crate::room_settings::get_latest_room_settings;
+use crate::Signal;
+
 pub const ALL_AGENTS: &str = "ALL_AGENTS";
+
+/// Version of this zome, advertised in the tag of our AllAgents link
+pub const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Tag of AllAgents links. Links created by older versions have an empty tag.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+pub struct AllAgentsTag {
+    pub joined_at: Timestamp,
+    pub client_version: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AgentDetails {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    /// Microseconds since the UNIX epoch
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub joined_at: Timestamp,
+    /// None if the agent joined with a version that didn't advertise it
+    pub client_version: Option<String>,
+}
+
+/// Returns all agents of the room that haven't been banned from it
 #[hdk_extern]
 pub fn get_all_agents(_: ()) -> ExternResult<Vec<AgentPubKey>> {
     let path = Path::from(ALL_AGENTS);
     let links = get_links(
         GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllAgents)?.build(),
     )?;
+    let banned_agents = get_banned_agent_keys()?;
     Ok(links
         .into_iter()
         .filter_map(|link| AgentPubKey::try_from(link.target).ok())
+        .filter(|agent| !banned_agents.contains(agent))
         .collect())
 }
+/// Returns when the agent first linked itself from the ALL_AGENTS anchor, if it has
+pub fn get_joined_at(agent: &AgentPubKey) -> ExternResult<Option<Timestamp>> {
+    let path = Path::from(ALL_AGENTS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllAgents)?.build(),
+    )?;
+    Ok(links
+        .into_iter()
+        .filter(|link| AgentPubKey::try_from(link.target.clone()).ok().as_ref() == Some(agent))
+        .map(|link| link.timestamp)
+        .min())
+}
+/// Returns all agents of the room along with when they joined and the version they
+/// joined with, sorted by join time. Banned agents are left out.
+#[hdk_extern]
+pub fn get_all_agents_detailed(_: ()) -> ExternResult<Vec<AgentDetails>> {
+    let path = Path::from(ALL_AGENTS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllAgents)?.build(),
+    )?;
+    let banned_agents = get_banned_agent_keys()?;
+    let mut agents: Vec<AgentDetails> = links
+        .into_iter()
+        .filter_map(|link| {
+            let agent = AgentPubKey::try_from(link.target).ok()?;
+            if banned_agents.contains(&agent) {
+                return None;
+            }
+            let tag = AllAgentsTag::try_from(SerializedBytes::from(UnsafeBytes::from(link.tag.0)));
+            Some(match tag {
+                Ok(tag) => AgentDetails {
+                    agent,
+                    joined_at: tag.joined_at,
+                    client_version: Some(tag.client_version),
+                },
+                Err(_) => AgentDetails {
+                    agent,
+                    joined_at: link.timestamp,
+                    client_version: None,
+                },
+            })
+        })
+        .collect();
+    agents.sort_by_key(|agent_details| agent_details.joined_at);
+    Ok(agents)
+}
 #[hdk_extern]
 pub fn add_agent_to_anchor(_: ()) -> ExternResult<ActionHash> {
+    ensure_not_banned()?;
+    let locked = get_latest_room_settings()?.locked;
+    let max_members = RoomDnaProperties::get()?.max_members;
+    if locked || max_members.is_some() {
+        let agents = get_all_agents(())?;
+        if !agents.contains(&agent_info()?.agent_initial_pubkey) {
+            if locked {
+                return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                    "This room has been locked"
+                ))));
+            }
+            if max_members.is_some_and(|max_members| agents.len() >= max_members as usize) {
+                return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                    "This room has reached its maximum number of members"
+                ))));
+            }
+        }
+    }
     let path = Path::from(ALL_AGENTS);
+    let tag = SerializedBytes::try_from(AllAgentsTag {
+        joined_at: sys_time()?,
+        client_version: CLIENT_VERSION.into(),
+    })
+    .map_err(|e| wasm_error!(e))?;
     create_link(
         path.path_entry_hash()?,
         agent_info()?.agent_initial_pubkey,
         LinkTypes::AllAgents,
-        (),
+        LinkTag::new(tag.bytes().clone()),
     )
 }
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JoinRoomOutput {
+    pub welcome_message: Option<WelcomeMessage>,
+    pub deprecation_notice: Option<DeprecationNotice>,
+}
+
+/// To be called by the UI when the agent enters the room. Returns the orientation
+/// configured by the hosts and whether the room is deprecated, and also emits both
+/// as local signals.
+#[hdk_extern]
+pub fn join_room(_: ()) -> ExternResult<JoinRoomOutput> {
+    let welcome_message = get_latest_room_settings()?.welcome_message;
+    if let Some(welcome_message) = welcome_message.clone() {
+        emit_signal(Signal::WelcomeMessage { welcome_message })?;
+    }
+    let deprecation_notice = get_deprecation_notice(())?;
+    if let Some(deprecation_notice) = deprecation_notice.clone() {
+        emit_signal(Signal::DeprecationNotice { deprecation_notice })?;
+    }
+    Ok(JoinRoomOutput {
+        welcome_message,
+        deprecation_notice,
+    })
+}
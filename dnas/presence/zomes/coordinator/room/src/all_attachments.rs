@@ -1,22 +1,52 @@
 use hdk::prelude::*;
 use room_integrity::*;
+
+use crate::agent_ban::get_banned_agent_keys;
+use crate::attachment::{get_latest_attachment, get_trashed_attachment_hashes};
+use crate::helper::emit_progress;
+/// Returns the original records of all attachments that are not in the trash, ordered
+/// by the sort key of their latest revision and then by the time they were added.
+/// Attachments of other agents that are scheduled for later are left out, as are
+/// attachments of banned agents.
 #[hdk_extern]
 pub fn get_all_attachments(_: ()) -> ExternResult<Vec<Record>> {
     let path = Path::from("all_attachments");
     let links = get_links(
         GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllAttachments)?.build(),
     )?;
+    let trashed_attachment_hashes = get_trashed_attachment_hashes()?;
+    let banned_agents = get_banned_agent_keys()?;
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let now = sys_time()?;
+    let total = links.len();
     let mut attachments = Vec::new();
-    for link in links {
+    for (i, link) in links.into_iter().enumerate() {
         match ActionHash::try_from(link.target) {
-            Ok(ah) => {
-                let maybe_record = get(ah, GetOptions::default())?;
-                if let Some(record) = maybe_record {
-                    attachments.push(record);
+            Ok(ah) if !trashed_attachment_hashes.contains(&ah) => {
+                let maybe_record = get(ah.clone(), GetOptions::default())?;
+                if let Some(record) =
+                    maybe_record.filter(|record| !banned_agents.contains(record.action().author()))
+                {
+                    let latest_attachment = get_latest_attachment(ah)?
+                        .and_then(|latest| latest.entry().to_app_option::<Attachment>().ok())
+                        .flatten();
+                    let is_visible = record.action().author() == &my_pub_key
+                        || !latest_attachment
+                            .as_ref()
+                            .is_some_and(|attachment| !attachment.is_published_at(now));
+                    if is_visible {
+                        let sort_key = latest_attachment.and_then(|attachment| attachment.sort_key);
+                        attachments.push((sort_key, link.timestamp, record));
+                    }
                 }
             }
-            Err(_) => (),
+            _ => (),
         }
+        emit_progress("get_all_attachments", i + 1, total)?;
     }
-    Ok(attachments)
+    attachments.sort_by_key(|(sort_key, timestamp, _)| (sort_key.is_none(), *sort_key, *timestamp));
+    Ok(attachments
+        .into_iter()
+        .map(|(_, _, record)| record)
+        .collect())
 }
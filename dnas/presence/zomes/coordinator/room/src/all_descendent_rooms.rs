@@ -1,9 +1,13 @@
 use hdk::prelude::*;
 use room_integrity::*;
+
+use crate::helper::emit_progress;
+use crate::room_settings::ensure_not_archived;
 pub const ALL_DESCENDENT_ROOMS: &str = "ALL_DESCENDENT_ROOMS";
 
 #[hdk_extern]
 pub fn create_descendent_room(input: DescendentRoom) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
     let path = Path::from(ALL_DESCENDENT_ROOMS);
     let room_entry_hash = hash_entry(input.clone())?;
     create_entry(EntryTypes::DescendentRoom(input))?;
@@ -16,9 +20,27 @@ pub fn create_descendent_room(input: DescendentRoom) -> ExternResult<ActionHash>
     )
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DeriveNetworkSeedInput {
+    pub parent_network_seed: String,
+    pub network_seed_appendix: String,
+}
+
+/// Returns the full network seed of the descendent room, so that all clients clone
+/// its cell with the same seed. See descendent_room_network_seed for the scheme.
+#[hdk_extern]
+pub fn derive_descendent_room_network_seed(input: DeriveNetworkSeedInput) -> ExternResult<String> {
+    Ok(descendent_room_network_seed(
+        &input.parent_network_seed,
+        &input.network_seed_appendix,
+    ))
+}
+
 /// Deletes the link from the anchor to that descendent room
 #[hdk_extern]
 pub fn delete_descendent_room(action_hash: ActionHash) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
     delete_link(action_hash)
 }
 
@@ -31,8 +53,9 @@ pub fn get_all_descendent_rooms(
         GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllDescendentRooms)?
             .build(),
     )?;
+    let total = links.len();
     let mut result = Vec::new();
-    for link in links {
+    for (i, link) in links.into_iter().enumerate() {
         match EntryHash::try_from(link.target) {
             Ok(eh) => {
                 let maybe_record = get(eh, GetOptions::default())?;
@@ -46,6 +69,7 @@ pub fn get_all_descendent_rooms(
             }
             Err(_) => (),
         }
+        emit_progress("get_all_descendent_rooms", i + 1, total)?;
     }
     // In principle it would be possible that we get duplicate rooms here since there may be multiple
     // links pointing to the same room. However since the UI side creation process generates a UUID
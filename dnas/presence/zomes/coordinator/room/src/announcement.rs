@@ -0,0 +1,104 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::role_assignment::get_role_proof;
+use crate::room_settings::ensure_not_archived;
+use crate::scheduled_publication::schedule_announcement_publication;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CreateAnnouncementInput {
+    pub message: String,
+    pub locale: Option<String>,
+    /// Time from which on the announcement is shown to everyone but its author.
+    /// Shown immediately if not set.
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub publish_at: Option<Timestamp>,
+}
+
+/// Publishes an announcement to all members of the room, or schedules it for its
+/// publish_at time. Parallel announcements in other languages are published as
+/// separate announcements with their own locale.
+#[hdk_extern]
+pub fn create_announcement(input: CreateAnnouncementInput) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    let locale = input.locale.clone().unwrap_or_default();
+    let announcement = Announcement {
+        message: input.message,
+        locale: input.locale,
+        publish_at: input.publish_at,
+        granted_via: get_role_proof(
+            &[RoomRole::Host, RoomRole::Moderator, RoomRole::Contributor],
+            "publish announcements",
+        )?,
+    };
+    schedule_announcement_publication(&announcement)?;
+    let action_hash = create_entry(EntryTypes::Announcement(announcement))?;
+    let path = Path::from(ANNOUNCEMENTS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllAnnouncements,
+        LinkTag::new(locale.into_bytes()),
+    )?;
+    Ok(action_hash)
+}
+
+/// Returns the announcements meant for readers of the given locale, oldest first.
+/// Announcements without a locale are always included. If no locale is given, the
+/// announcements of all locales are returned. Scheduled announcements are only
+/// included for their author until they get published.
+#[hdk_extern]
+pub fn get_announcements(locale: Option<String>) -> ExternResult<Vec<Record>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let now = sys_time()?;
+    let mut links = get_announcement_links()?;
+    links.sort_by_key(|link| link.timestamp);
+    let mut records = Vec::new();
+    for link in links {
+        if let Some(locale) = &locale {
+            let announcement_locale = String::from_utf8(link.tag.into_inner()).ok();
+            let announcement_locale = announcement_locale.filter(|locale| !locale.is_empty());
+            if !locale_matches(announcement_locale.as_deref(), locale) {
+                continue;
+            }
+        }
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get(action_hash, GetOptions::default())? else {
+            continue;
+        };
+        let is_visible = record.action().author() == &my_pub_key
+            || !record
+                .entry()
+                .to_app_option::<Announcement>()
+                .ok()
+                .flatten()
+                .is_some_and(|announcement| !announcement.is_published_at(now));
+        if is_visible {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+/// Withdraws an announcement of this agent
+#[hdk_extern]
+pub fn delete_announcement(action_hash: ActionHash) -> ExternResult<ActionHash> {
+    for link in get_announcement_links()? {
+        if link.target.clone().into_action_hash().as_ref() == Some(&action_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(action_hash)
+}
+
+fn get_announcement_links() -> ExternResult<Vec<Link>> {
+    let path = Path::from(ANNOUNCEMENTS);
+    get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllAnnouncements)?
+            .build(),
+    )
+}
@@ -0,0 +1,291 @@
+use hdk::prelude::*;
+
+use crate::remote_signals::ZOME_API_VERSION;
+
+/// Who may call a zome function
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CapabilityLevel {
+    /// Callable by any agent via the unrestricted cap grant created in init
+    Unrestricted,
+    /// Callable only by the agent that owns the cell, i.e. its UI or other cells of
+    /// the same agent. This includes the moderator functions: a grant that others
+    /// could call them with would let them moderate in this agent's name.
+    Author,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ZomeFunctionManifest {
+    pub name: String,
+    /// Semantic version of the function's signature. Its major version follows
+    /// ZOME_API_VERSION.
+    pub version: String,
+    pub capability: CapabilityLevel,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ApiManifest {
+    pub zome_api_version: u32,
+    pub functions: Vec<ZomeFunctionManifest>,
+}
+
+/// Functions that are part of the unrestricted cap grant created in init
+pub const UNRESTRICTED_FUNCTIONS: [&str; 6] = [
+    "recv_remote_signal",
+    "connectivity_ping",
+    "accept_host_transfer",
+    "commit_host_transfer",
+    "accept_minutes_finalization",
+    "commit_minutes_finalization",
+];
+
+/// Names of the zome functions exposed by this build of the zome. Callbacks like
+/// init and post_commit are not included. To be kept in sync with the #[hdk_extern]
+/// functions of the modules.
+fn exposed_functions() -> Vec<&'static str> {
+    let mut functions = vec![
+        "api_manifest",
+        "request_admission",
+        "respond_to_join_request",
+        "get_pending_join_requests",
+        "ban_agent",
+        "unban_agent",
+        "get_banned_agents",
+        "set_status",
+        "get_status",
+        "create_announcement",
+        "get_announcements",
+        "delete_announcement",
+        "get_all_agents",
+        "get_all_agents_detailed",
+        "add_agent_to_anchor",
+        "join_room",
+        "get_all_attachments",
+        "create_descendent_room",
+        "delete_descendent_room",
+        "get_all_descendent_rooms",
+        "derive_descendent_room_network_seed",
+        "create_attachment",
+        "get_latest_attachment",
+        "get_original_attachment",
+        "get_all_revisions_for_attachment",
+        "update_attachment",
+        "delete_attachment",
+        "get_all_deletes_for_attachment",
+        "get_oldest_delete_for_attachment",
+        "trash_attachment",
+        "restore_attachment",
+        "get_trashed_attachments",
+        "record_attachment_access",
+        "get_access_log",
+        "purge_attachment",
+        "export_attachment_feed",
+        "get_blocked_agents",
+        "block_agent",
+        "unblock_agent",
+        "get_latest_call_session_end",
+        "new_connection_id",
+        "connectivity_ping",
+        "connectivity_check",
+        "set_debug_level",
+        "get_debug_level",
+        "set_deprecation_notice",
+        "get_deprecation_notice",
+        "clear_deprecation_notice",
+        "set_directory_listing",
+        "get_directory_listing",
+        "clear_directory_listing",
+        "subscribe_to_events",
+        "unsubscribe_from_events",
+        "get_event_subscriptions",
+        "get_supported_features",
+        "get_sibling_lobbies",
+        "register_sibling_lobby",
+        "unregister_sibling_lobby",
+        "get_federated_rooms",
+        "transfer_host",
+        "consent_to_host_transfer",
+        "accept_host_transfer",
+        "commit_host_transfer",
+        "get_host_transfers",
+        "create_invitation",
+        "revoke_invitation",
+        "get_invite_code_hash",
+        "get_mailbox",
+        "share_media_key",
+        "rotate_media_keys",
+        "get_media_keys",
+        "finalize_minutes",
+        "consent_to_co_sign_minutes",
+        "accept_minutes_finalization",
+        "commit_minutes_finalization",
+        "get_minutes_approvals",
+        "get_moderation_log",
+        "claim_ownership",
+        "veto_ownership_claim",
+        "get_ownership_claims",
+        "set_permission_override",
+        "clear_permission_override",
+        "get_permission_overrides",
+        "pin_content",
+        "unpin_content",
+        "get_pinned_content",
+        "record_presence_heartbeat",
+        "get_presence_timeline",
+        "get_activity_summary",
+        "set_call_active",
+        "is_call_active",
+        "set_presenter",
+        "get_presenter_state",
+        "set_privacy_mode",
+        "get_privacy_mode",
+        "set_prompt_deck",
+        "get_prompt_deck",
+        "draw_prompt",
+        "recv_remote_signal",
+        "ping",
+        "ping_ui",
+        "pong_ui",
+        "send_init_request",
+        "send_init_accept",
+        "send_reject_init",
+        "send_sdp_data",
+        "send_close_connection",
+        "send_renegotiation_needed",
+        "send_reconnect_request",
+        "report_connection_stats",
+        "send_quality_change_request",
+        "advertise_simulcast_layers",
+        "send_simulcast_layer_request",
+        "send_reaction",
+        "send_emergency",
+        "get_zome_api_version",
+        "send_client_hello",
+        "kick_agent",
+        "mute_agent",
+        "end_call_for_all",
+        "assign_role",
+        "revoke_role",
+        "get_my_roles",
+        "get_room_roles",
+        "attest_attendance",
+        "get_attendance_attestations",
+        "claim_attendance_promotion",
+        "get_room_info",
+        "set_room_info",
+        "get_room_metadata",
+        "get_public_room_preview",
+        "get_room_settings",
+        "set_room_settings",
+        "publish_scheduled_attachments",
+        "publish_scheduled_announcements",
+        "search_room_content",
+        "get_recent_signal_traces",
+        "grant_speaking_turn",
+        "get_participation_summary",
+        "get_my_storage_footprint",
+        "get_room_storage_footprint",
+        "create_task",
+        "get_latest_task",
+        "update_task",
+        "delete_task",
+        "get_tasks",
+        "notify_due_tasks",
+    ];
+    if cfg!(feature = "breakouts") {
+        functions.extend([
+            "create_breakout_assignments",
+            "get_breakout_assignments",
+            "get_breakout_assignment_for_agent",
+            "get_latest_breakout_round",
+            "generate_breakout_groups",
+            "start_breakouts",
+            "run_breakout_timer",
+        ]);
+    }
+    if cfg!(feature = "captions") {
+        functions.extend([
+            "set_caption_preference",
+            "get_caption_preference",
+            "get_caption_preferences",
+        ]);
+    }
+    if cfg!(feature = "chat") {
+        functions.extend([
+            "create_chat_message",
+            "get_chat_message",
+            "get_latest_chat_message",
+            "get_all_revisions_for_chat_message",
+            "update_chat_message",
+            "delete_chat_message",
+            "get_thread",
+            "get_all_chat_messages",
+            "mark_read",
+            "get_read_up_to",
+            "get_unread_count",
+        ]);
+    }
+    if cfg!(feature = "check-ins") {
+        functions.extend(["create_check_in", "get_checkin_summary"]);
+    }
+    if cfg!(feature = "devices") {
+        functions.extend([
+            "hand_off_call",
+            "get_pending_call_hand_off",
+            "sign_device_binding",
+            "bind_device",
+            "unbind_device",
+            "get_device_bindings",
+            "get_primary_agent",
+            "get_all_participants",
+        ]);
+    }
+    if cfg!(feature = "external-references") {
+        functions.extend(["register_external_reference", "get_external_references"]);
+    }
+    if cfg!(feature = "interpretation") {
+        functions.extend([
+            "register_as_interpreter",
+            "unregister_as_interpreter",
+            "get_interpreters",
+        ]);
+    }
+    if cfg!(feature = "questions") {
+        functions.extend([
+            "ask_question",
+            "upvote_question",
+            "withdraw_question_upvote",
+            "mark_answered",
+            "get_question_queue",
+        ]);
+    }
+    if cfg!(feature = "schema") {
+        functions.push("get_signal_schema");
+    }
+    functions
+}
+
+/// Describes the zome functions exposed by this build of the zome, so that tooling
+/// can verify compatibility at install time instead of failing at the first call
+#[hdk_extern]
+pub fn api_manifest(_: ()) -> ExternResult<ApiManifest> {
+    let version = format!("{ZOME_API_VERSION}.0.0");
+    let functions = exposed_functions()
+        .into_iter()
+        .map(|name| ZomeFunctionManifest {
+            name: name.into(),
+            version: version.clone(),
+            capability: if UNRESTRICTED_FUNCTIONS.contains(&name) {
+                CapabilityLevel::Unrestricted
+            } else {
+                CapabilityLevel::Author
+            },
+        })
+        .collect();
+    Ok(ApiManifest {
+        zome_api_version: ZOME_API_VERSION,
+        functions,
+    })
+}
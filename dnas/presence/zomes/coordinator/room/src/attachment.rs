@@ -1,168 +1,160 @@
 use hdk::prelude::*;
 use room_integrity::*;
+
+use crate::agent_ban::ensure_not_banned;
+use crate::event_subscription::{notify_event_subscribers, RoomEvent};
+use crate::role_assignment::get_role_proof;
+use crate::room_settings::ensure_not_archived;
+use crate::scheduled_publication::schedule_publication;
+use crate::storage_footprint::ensure_within_quota;
+use crate::updatable_entry::*;
+
+impl UpdatableEntry for Attachment {
+    const UPDATES_LINK_TYPE: LinkTypes = LinkTypes::AttachmentUpdates;
+
+    fn into_entry_types(self) -> EntryTypes {
+        EntryTypes::Attachment(self)
+    }
+
+    fn index() -> Option<(Path, LinkTypes)> {
+        Some((Path::from("all_attachments"), LinkTypes::AllAttachments))
+    }
+}
+
 #[hdk_extern]
 pub fn create_attachment(attachment: Attachment) -> ExternResult<Record> {
-    let attachment_hash = create_entry(&EntryTypes::Attachment(attachment.clone()))?;
-    let record = get(attachment_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
-        WasmErrorInner::Guest(String::from("Could not find the newly created Attachment"))
-    ))?;
-    let path = Path::from("all_attachments");
-    create_link(
-        path.path_entry_hash()?,
-        attachment_hash.clone(),
-        LinkTypes::AllAttachments,
-        (),
-    )?;
+    ensure_not_archived()?;
+    ensure_not_banned()?;
+    ensure_within_quota(&Entry::try_from(&attachment)?)?;
+    schedule_publication(&attachment)?;
+    let is_published = attachment.is_published_at(sys_time()?);
+    let record = create_updatable(attachment)?;
+    if is_published {
+        notify_event_subscribers(RoomEvent::AttachmentCreated {
+            attachment_hash: record.action_address().clone(),
+        })?;
+    }
     Ok(record)
 }
 #[hdk_extern]
 pub fn get_latest_attachment(original_attachment_hash: ActionHash) -> ExternResult<Option<Record>> {
-    let links = get_links(
-        GetLinksInputBuilder::try_new(
-            original_attachment_hash.clone(),
-            LinkTypes::AttachmentUpdates,
-        )?
-        .build(),
-    )?;
-    let latest_link = links
-        .into_iter()
-        .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-    let latest_attachment_hash = match latest_link {
-        Some(link) => {
-            link.target
-                .clone()
-                .into_action_hash()
-                .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
-                    "No action hash associated with link"
-                ))))?
-        }
-        None => original_attachment_hash.clone(),
-    };
-    get(latest_attachment_hash, GetOptions::default())
+    get_latest_updatable::<Attachment>(original_attachment_hash)
 }
 #[hdk_extern]
 pub fn get_original_attachment(
     original_attachment_hash: ActionHash,
 ) -> ExternResult<Option<Record>> {
-    let Some(details) = get_details(original_attachment_hash, GetOptions::default())? else {
-        return Ok(None);
-    };
-    match details {
-        Details::Record(details) => Ok(Some(details.record)),
-        _ => Err(wasm_error!(WasmErrorInner::Guest(String::from(
-            "Malformed get details response"
-        )))),
-    }
+    get_original_updatable(original_attachment_hash)
 }
 #[hdk_extern]
 pub fn get_all_revisions_for_attachment(
     original_attachment_hash: ActionHash,
 ) -> ExternResult<Vec<Record>> {
-    let Some(original_record) = get_original_attachment(original_attachment_hash.clone())? else {
-        return Ok(vec![]);
-    };
-    let links = get_links(
-        GetLinksInputBuilder::try_new(
-            original_attachment_hash.clone(),
-            LinkTypes::AttachmentUpdates,
-        )?
-        .build(),
-    )?;
-    let get_input: Vec<GetInput> = links
-        .into_iter()
-        .map(|link| {
-            Ok(GetInput::new(
-                link.target
-                    .into_action_hash()
-                    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
-                        "No action hash associated with link"
-                    ))))?
-                    .into(),
-                GetOptions::default(),
-            ))
-        })
-        .collect::<ExternResult<Vec<GetInput>>>()?;
-    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
-    let mut records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
-    records.insert(0, original_record);
-    Ok(records)
+    get_updatable_revisions::<Attachment>(original_attachment_hash)
 }
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateAttachmentInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
     pub original_attachment_hash: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
     pub previous_attachment_hash: ActionHash,
     pub updated_attachment: Attachment,
 }
 #[hdk_extern]
 pub fn update_attachment(input: UpdateAttachmentInput) -> ExternResult<Record> {
-    let updated_attachment_hash = update_entry(
-        input.previous_attachment_hash.clone(),
-        &input.updated_attachment,
-    )?;
-    create_link(
-        input.original_attachment_hash.clone(),
-        updated_attachment_hash.clone(),
-        LinkTypes::AttachmentUpdates,
-        (),
-    )?;
-    let record =
-        get(updated_attachment_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
-            WasmErrorInner::Guest(String::from("Could not find the newly updated Attachment"))
-        ))?;
-    Ok(record)
+    ensure_not_archived()?;
+    ensure_within_quota(&Entry::try_from(&input.updated_attachment)?)?;
+    schedule_publication(&input.updated_attachment)?;
+    update_updatable(
+        input.original_attachment_hash,
+        input.previous_attachment_hash,
+        input.updated_attachment,
+    )
 }
+/// Moves the attachment to the trash. Kept for existing clients, equivalent to
+/// trash_attachment.
 #[hdk_extern]
 pub fn delete_attachment(original_attachment_hash: ActionHash) -> ExternResult<ActionHash> {
-    let details = get_details(original_attachment_hash.clone(), GetOptions::default())?.ok_or(
-        wasm_error!(WasmErrorInner::Guest(String::from(
-            "{pascal_entry_def_name} not found"
-        ))),
-    )?;
-    let _record = match details {
-        Details::Record(details) => Ok(details.record),
-        _ => Err(wasm_error!(WasmErrorInner::Guest(String::from(
-            "Malformed get details response"
-        )))),
-    }?;
-    let path = Path::from("all_attachments");
-    let links = get_links(
-        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllAttachments)?.build(),
-    )?;
-    for link in links {
-        if let Some(hash) = link.target.into_action_hash() {
-            if hash.eq(&original_attachment_hash) {
-                delete_link(link.create_link_hash)?;
-            }
-        }
-    }
-    delete_entry(original_attachment_hash)
+    trash_attachment(original_attachment_hash)
 }
 #[hdk_extern]
 pub fn get_all_deletes_for_attachment(
     original_attachment_hash: ActionHash,
 ) -> ExternResult<Option<Vec<SignedActionHashed>>> {
-    let Some(details) = get_details(original_attachment_hash, GetOptions::default())? else {
-        return Ok(None);
-    };
-    match details {
-        Details::Entry(_) => Err(wasm_error!(WasmErrorInner::Guest(
-            "Malformed details".into()
-        ))),
-        Details::Record(record_details) => Ok(Some(record_details.deletes)),
-    }
+    get_all_deletes_for_updatable(original_attachment_hash)
 }
 #[hdk_extern]
 pub fn get_oldest_delete_for_attachment(
     original_attachment_hash: ActionHash,
 ) -> ExternResult<Option<SignedActionHashed>> {
-    let Some(mut deletes) = get_all_deletes_for_attachment(original_attachment_hash)? else {
-        return Ok(None);
-    };
-    deletes.sort_by(|delete_a, delete_b| {
-        delete_a
-            .action()
-            .timestamp()
-            .cmp(&delete_b.action().timestamp())
-    });
-    Ok(deletes.first().cloned())
+    get_oldest_delete_for_updatable(original_attachment_hash)
+}
+/// Hides the attachment from get_all_attachments until it gets restored
+#[hdk_extern]
+pub fn trash_attachment(original_attachment_hash: ActionHash) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    let path = Path::from(TRASHED_ATTACHMENTS);
+    create_link(
+        path.path_entry_hash()?,
+        original_attachment_hash,
+        LinkTypes::TrashedAttachments,
+        (),
+    )
+}
+#[hdk_extern]
+pub fn restore_attachment(original_attachment_hash: ActionHash) -> ExternResult<()> {
+    ensure_not_archived()?;
+    for link in get_trash_links(&original_attachment_hash)? {
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+#[hdk_extern]
+pub fn get_trashed_attachments(_: ()) -> ExternResult<Vec<ActionHash>> {
+    get_trashed_attachment_hashes()
+}
+/// Irrevocably deletes an attachment that has been moved to the trash before. Only
+/// hosts and moderators may do so.
+#[hdk_extern]
+pub fn purge_attachment(original_attachment_hash: ActionHash) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    get_role_proof(&[RoomRole::Host, RoomRole::Moderator], "purge attachments")?;
+    let trash_links = get_trash_links(&original_attachment_hash)?;
+    if trash_links.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only attachments in the trash can be purged"
+        ))));
+    }
+    let delete_hash = delete_updatable::<Attachment>(original_attachment_hash)?;
+    for link in trash_links {
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(delete_hash)
+}
+pub fn get_trashed_attachment_hashes() -> ExternResult<Vec<ActionHash>> {
+    let mut hashes: Vec<ActionHash> = Vec::new();
+    for link in get_all_trash_links()? {
+        if let Some(hash) = link.target.into_action_hash() {
+            if !hashes.contains(&hash) {
+                hashes.push(hash);
+            }
+        }
+    }
+    Ok(hashes)
+}
+fn get_trash_links(original_attachment_hash: &ActionHash) -> ExternResult<Vec<Link>> {
+    Ok(get_all_trash_links()?
+        .into_iter()
+        .filter(|link| {
+            link.target.clone().into_action_hash().as_ref() == Some(original_attachment_hash)
+        })
+        .collect())
+}
+fn get_all_trash_links() -> ExternResult<Vec<Link>> {
+    let path = Path::from(TRASHED_ATTACHMENTS);
+    get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::TrashedAttachments)?
+            .build(),
+    )
 }
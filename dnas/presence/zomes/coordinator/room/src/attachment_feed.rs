@@ -0,0 +1,114 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_attachments::get_all_attachments;
+use crate::attachment::get_latest_attachment;
+
+/// Number of entries of a feed if not specified otherwise
+pub const DEFAULT_FEED_ENTRIES: u32 = 50;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExportAttachmentFeedInput {
+    pub title: String,
+    /// Maximum number of entries, DEFAULT_FEED_ENTRIES if not set
+    pub max_entries: Option<u32>,
+}
+
+/// Renders the most recently added or updated attachments of the room into an Atom
+/// feed, so that members can follow new material in a feed reader. Serving the
+/// feed is left to whatever gateway the member chooses.
+#[hdk_extern]
+pub fn export_attachment_feed(input: ExportAttachmentFeedInput) -> ExternResult<String> {
+    let mut entries = Vec::new();
+    for original_record in get_all_attachments(())? {
+        let original_hash = original_record.action_address().clone();
+        let Some(latest_record) = get_latest_attachment(original_hash.clone())? else {
+            continue;
+        };
+        let Some(attachment) = latest_record
+            .entry()
+            .to_app_option::<Attachment>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        entries.push((
+            original_hash,
+            original_record.action().author().clone(),
+            latest_record.action().timestamp(),
+            attachment,
+        ));
+    }
+    entries.sort_by(|(_, _, updated_a, _), (_, _, updated_b, _)| updated_b.cmp(updated_a));
+    entries.truncate(input.max_entries.unwrap_or(DEFAULT_FEED_ENTRIES) as usize);
+
+    let feed_updated = entries
+        .first()
+        .map(|(_, _, updated, _)| *updated)
+        .unwrap_or(Timestamp::from_micros(0));
+    let mut feed = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    feed.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    feed.push_str(&format!("  <title>{}</title>\n", escape_xml(&input.title)));
+    feed.push_str(&format!(
+        "  <id>urn:presence:room:{}</id>\n",
+        dna_info()?.hash
+    ));
+    feed.push_str(&format!(
+        "  <updated>{}</updated>\n",
+        to_rfc3339(feed_updated)
+    ));
+    for (original_hash, author, updated, attachment) in entries {
+        let wal = escape_xml(&attachment.wal);
+        feed.push_str("  <entry>\n");
+        feed.push_str(&format!("    <title>{wal}</title>\n"));
+        feed.push_str(&format!("    <link href=\"{wal}\"/>\n"));
+        feed.push_str(&format!(
+            "    <id>urn:presence:attachment:{original_hash}</id>\n"
+        ));
+        feed.push_str(&format!("    <updated>{}</updated>\n", to_rfc3339(updated)));
+        feed.push_str(&format!("    <author><name>{author}</name></author>\n"));
+        feed.push_str("  </entry>\n");
+    }
+    feed.push_str("</feed>\n");
+    Ok(feed)
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Formats the timestamp as required by Atom, e.g. 2024-05-14T09:30:00Z
+fn to_rfc3339(timestamp: Timestamp) -> String {
+    let seconds = timestamp.as_micros().div_euclid(1_000_000);
+    let days = seconds.div_euclid(86_400);
+    let seconds_of_day = seconds.rem_euclid(86_400);
+
+    // Converts days since the unix epoch into a civil date, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
@@ -0,0 +1,134 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::role_assignment::{get_role_proof, has_role};
+use crate::room_settings::ensure_not_archived;
+use crate::updatable_entry::get_latest_singleton;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AttestAttendanceInput {
+    /// The CallSessionEnd of the attended session
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub session: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub agents: Vec<AgentPubKey>,
+}
+
+/// Attests that the agents attended the session, so that they can earn the
+/// Contributor role if the room settings have an attendance promotion rule. Only
+/// hosts may do so.
+#[hdk_extern]
+pub fn attest_attendance(input: AttestAttendanceInput) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    if !has_role(&agent_info()?.agent_initial_pubkey, RoomRole::Host)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only hosts can attest attendance"
+        ))));
+    }
+    let action_hash = create_entry(EntryTypes::AttendanceAttestation(AttendanceAttestation {
+        session: input.session,
+        agents: input.agents.clone(),
+        granted_via: get_role_proof(&[RoomRole::Host], "attest attendance")?,
+    }))?;
+    for agent in input.agents {
+        create_link(
+            agent,
+            action_hash.clone(),
+            LinkTypes::AgentToAttendanceAttestations,
+            (),
+        )?;
+    }
+    Ok(action_hash)
+}
+
+/// Returns the attendance attestations that list the agent, oldest first
+#[hdk_extern]
+pub fn get_attendance_attestations(agent: AgentPubKey) -> ExternResult<Vec<Record>> {
+    let mut links = get_links(
+        GetLinksInputBuilder::try_new(agent, LinkTypes::AgentToAttendanceAttestations)?.build(),
+    )?;
+    links.sort_by_key(|link| link.timestamp);
+    let mut records = Vec::new();
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        if let Some(record) = get(action_hash, GetOptions::default())? {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+/// Assigns this agent the Contributor role via the attendance promotion rule of the
+/// latest room settings. Fails if the room has no such rule or if this agent hasn't
+/// attended enough sessions yet.
+#[hdk_extern]
+pub fn claim_attendance_promotion(_: ()) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if has_role(&my_pub_key, RoomRole::Contributor)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "You already hold the Contributor role"
+        ))));
+    }
+    let no_rule = || {
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "This room has no attendance promotion rule"
+        )))
+    };
+    let room_settings_record = get_latest_singleton::<RoomSettings>()?.ok_or_else(no_rule)?;
+    let attendance_promotion = room_settings_record
+        .entry()
+        .to_app_option::<RoomSettings>()
+        .map_err(|e| wasm_error!(e))?
+        .and_then(|room_settings| room_settings.attendance_promotion)
+        .ok_or_else(no_rule)?;
+
+    // One attestation per session, as several hosts may attest the same session
+    let mut sessions: Vec<ActionHash> = Vec::new();
+    let mut attended: Vec<ActionHash> = Vec::new();
+    for record in get_attendance_attestations(my_pub_key.clone())? {
+        let Some(attendance_attestation) = record
+            .entry()
+            .to_app_option::<AttendanceAttestation>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        if !sessions.contains(&attendance_attestation.session) {
+            sessions.push(attendance_attestation.session);
+            attended.push(record.action_address().clone());
+        }
+    }
+    let required = attendance_promotion.sessions as usize;
+    if attended.len() < required {
+        return Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "You have attended {} of the {required} sessions needed to become a contributor",
+            attended.len()
+        ))));
+    }
+    attended.truncate(required);
+
+    let action_hash = create_entry(EntryTypes::RoleAssignment(RoleAssignment {
+        agent: my_pub_key.clone(),
+        role: RoomRole::Contributor,
+        granted_via: Some(room_settings_record.action_address().clone()),
+        attended,
+    }))?;
+    let path = Path::from(ROLE_ASSIGNMENTS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllRoleAssignments,
+        (),
+    )?;
+    create_link(
+        my_pub_key,
+        action_hash.clone(),
+        LinkTypes::AgentToRoleAssignments,
+        (),
+    )?;
+    Ok(action_hash)
+}
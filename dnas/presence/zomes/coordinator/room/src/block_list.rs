@@ -0,0 +1,47 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+#[hdk_extern]
+pub fn get_blocked_agents(_: ()) -> ExternResult<Vec<AgentPubKey>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::BlockList.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records.last() else {
+        return Ok(vec![]);
+    };
+    let block_list = latest_record
+        .entry()
+        .to_app_option::<BlockList>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(block_list.map(|b| b.agents).unwrap_or_default())
+}
+
+/// Blocks the agent. Pings, call requests and connection data from blocked agents
+/// are silently dropped by recv_remote_signal.
+#[hdk_extern]
+pub fn block_agent(agent: AgentPubKey) -> ExternResult<()> {
+    let mut agents = get_blocked_agents(())?;
+    if agents.contains(&agent) {
+        return Ok(());
+    }
+    agents.push(agent);
+    create_entry(EntryTypes::BlockList(BlockList { agents }))?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn unblock_agent(agent: AgentPubKey) -> ExternResult<()> {
+    let mut agents = get_blocked_agents(())?;
+    if !agents.contains(&agent) {
+        return Ok(());
+    }
+    agents.retain(|a| a != &agent);
+    create_entry(EntryTypes::BlockList(BlockList { agents }))?;
+    Ok(())
+}
+
+pub fn is_blocked(agent: &AgentPubKey) -> ExternResult<bool> {
+    Ok(get_blocked_agents(())?.contains(agent))
+}
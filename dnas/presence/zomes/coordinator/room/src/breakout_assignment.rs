@@ -0,0 +1,102 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::room_settings::ensure_not_archived;
+
+/// Persists the given breakout assignments so that the same groups can be re-used
+/// later and agents that missed the broadcast can look up their breakout room. If
+/// the groups have been generated with generate_breakout_groups, the assignments
+/// should carry its seed so that the round can be audited later.
+#[hdk_extern]
+pub fn create_breakout_assignments(
+    assignments: Vec<BreakoutAssignment>,
+) -> ExternResult<Vec<ActionHash>> {
+    ensure_not_archived()?;
+    let path = Path::from(BREAKOUT_ASSIGNMENTS);
+    let mut action_hashes = Vec::new();
+    for assignment in assignments {
+        let agent = assignment.agent.clone();
+        let tag = breakout_round_tag(assignment.round);
+        let action_hash = create_entry(EntryTypes::BreakoutAssignment(assignment))?;
+        create_link(
+            path.path_entry_hash()?,
+            action_hash.clone(),
+            LinkTypes::AllBreakoutAssignments,
+            tag.clone(),
+        )?;
+        create_link(
+            agent,
+            action_hash.clone(),
+            LinkTypes::AgentToBreakoutAssignments,
+            tag,
+        )?;
+        action_hashes.push(action_hash);
+    }
+    Ok(action_hashes)
+}
+
+#[hdk_extern]
+pub fn get_breakout_assignments(round: u32) -> ExternResult<Vec<BreakoutAssignment>> {
+    let path = Path::from(BREAKOUT_ASSIGNMENTS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllBreakoutAssignments)?
+            .tag_prefix(breakout_round_tag(round))
+            .build(),
+    )?;
+    get_breakout_assignments_for_links(links)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetBreakoutAssignmentInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub round: u32,
+}
+
+/// Returns the breakout assignment of the given agent in the given round. If the agent
+/// got assigned multiple times in the same round, the latest assignment wins.
+#[hdk_extern]
+pub fn get_breakout_assignment_for_agent(
+    input: GetBreakoutAssignmentInput,
+) -> ExternResult<Option<BreakoutAssignment>> {
+    let mut links = get_links(
+        GetLinksInputBuilder::try_new(input.agent, LinkTypes::AgentToBreakoutAssignments)?
+            .tag_prefix(breakout_round_tag(input.round))
+            .build(),
+    )?;
+    links.sort_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+    Ok(get_breakout_assignments_for_links(links)?.pop())
+}
+
+/// Returns the highest breakout round for which assignments have been persisted
+#[hdk_extern]
+pub fn get_latest_breakout_round(_: ()) -> ExternResult<Option<u32>> {
+    let path = Path::from(BREAKOUT_ASSIGNMENTS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllBreakoutAssignments)?
+            .build(),
+    )?;
+    Ok(links
+        .into_iter()
+        .filter_map(|link| {
+            let bytes: [u8; 4] = link.tag.into_inner().try_into().ok()?;
+            Some(u32::from_be_bytes(bytes))
+        })
+        .max())
+}
+
+fn get_breakout_assignments_for_links(links: Vec<Link>) -> ExternResult<Vec<BreakoutAssignment>> {
+    let mut assignments = Vec::new();
+    for link in links {
+        if let Ok(ah) = ActionHash::try_from(link.target) {
+            let maybe_record = get(ah, GetOptions::default())?;
+            if let Some(record) = maybe_record {
+                if let Ok(Some(assignment)) = record.entry().to_app_option::<BreakoutAssignment>() {
+                    assignments.push(assignment);
+                }
+            }
+        }
+    }
+    Ok(assignments)
+}
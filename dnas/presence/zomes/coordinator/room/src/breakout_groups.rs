@@ -0,0 +1,74 @@
+use hdk::prelude::*;
+
+use crate::all_agents::get_all_agents;
+use crate::role_assignment::get_room_hosts;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GenerateBreakoutGroupsInput {
+    pub group_size: u32,
+    /// Whether to leave the calling agent (the facilitator) and the hosts of the room
+    /// out of the groups
+    pub exclude_hosts: bool,
+    /// Seed to reproduce a previous shuffle. A new random seed is used if None.
+    pub seed: Option<Vec<u8>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BreakoutGroups {
+    /// The seed the roster has been shuffled with. Shuffling the same roster with
+    /// the same seed yields the same groups which makes the assignment auditable.
+    /// Pass it on in the BreakoutAssignments of the round to record it.
+    pub seed: Vec<u8>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<Vec<u8>>>"))]
+    pub groups: Vec<Vec<AgentPubKey>>,
+}
+
+/// Randomly distributes all agents of the room into groups of at most group_size agents.
+///
+/// The roster is ordered by the blake2b hash of the seed concatenated with each agent's
+/// public key and then dealt into groups in a round-robin fashion so that group sizes
+/// differ by at most one.
+#[hdk_extern]
+pub fn generate_breakout_groups(
+    input: GenerateBreakoutGroupsInput,
+) -> ExternResult<BreakoutGroups> {
+    if input.group_size == 0 {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Group size must be at least 1"
+        ))));
+    }
+    let seed = match input.seed {
+        Some(seed) => seed,
+        None => random_bytes(32)?.to_vec(),
+    };
+
+    let mut roster = get_all_agents(())?;
+    if input.exclude_hosts {
+        let my_pub_key = agent_info()?.agent_initial_pubkey;
+        let hosts = get_room_hosts()?;
+        roster.retain(|agent| agent != &my_pub_key && !hosts.contains(agent));
+    }
+    roster.sort();
+    roster.dedup();
+
+    let mut keyed_roster = roster
+        .into_iter()
+        .map(|agent| {
+            let mut input = seed.clone();
+            input.extend_from_slice(agent.get_raw_39());
+            Ok((hash_blake2b(input, 32)?, agent))
+        })
+        .collect::<ExternResult<Vec<(Vec<u8>, AgentPubKey)>>>()?;
+    keyed_roster.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+
+    let group_size = input.group_size as usize;
+    let number_of_groups = keyed_roster.len().div_ceil(group_size);
+    let mut groups: Vec<Vec<AgentPubKey>> = vec![Vec::new(); number_of_groups];
+    for (i, (_, agent)) in keyed_roster.into_iter().enumerate() {
+        groups[i % number_of_groups].push(agent);
+    }
+
+    Ok(BreakoutGroups { seed, groups })
+}
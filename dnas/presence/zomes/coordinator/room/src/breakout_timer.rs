@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_agents::get_all_agents;
+use crate::remote_signals::{encode_signal, SignalPayload};
+use crate::room_settings::ensure_not_archived;
+
+/// Time before the end of a breakout round at which the participants are warned
+pub const BREAKOUT_WARNING_BEFORE_END: Duration = Duration::from_secs(120);
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StartBreakoutsInput {
+    pub round: u32,
+    pub duration_secs: u64,
+}
+
+/// Starts the timer of the given breakout round. The members of the room receive a
+/// BreakoutWarning BREAKOUT_WARNING_BEFORE_END before the round ends and
+/// RecallBreakouts once it has ended. The timer is kept by this agent's conductor,
+/// so the signals are only sent while it is running.
+#[hdk_extern]
+pub fn start_breakouts(input: StartBreakoutsInput) -> ExternResult<()> {
+    ensure_not_archived()?;
+    let ends_at = (sys_time()? + Duration::from_secs(input.duration_secs))
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.to_string())))?;
+    create_entry(EntryTypes::BreakoutTimer(BreakoutTimer {
+        round: input.round,
+        ends_at,
+    }))?;
+    schedule("run_breakout_timer")
+}
+
+/// Sends the BreakoutWarning and RecallBreakouts signals of the latest breakout timer
+/// and schedules itself for the next of them
+#[hdk_extern(infallible)]
+pub fn run_breakout_timer(_: Option<Schedule>) -> Option<Schedule> {
+    match advance_breakout_timer() {
+        Ok(next) => next.map(Schedule::Ephemeral),
+        Err(err) => {
+            error!("Failed to run the breakout timer: {err:?}");
+            None
+        }
+    }
+}
+
+/// Returns the time until the next signal of the latest breakout timer is due, or
+/// None if the breakout round has been recalled
+fn advance_breakout_timer() -> ExternResult<Option<Duration>> {
+    let Some(breakout_timer) = get_latest_breakout_timer()? else {
+        return Ok(None);
+    };
+    let now = sys_time()?;
+    let remaining =
+        Duration::from_micros((breakout_timer.ends_at.as_micros() - now.as_micros()).max(0) as u64);
+    if remaining > BREAKOUT_WARNING_BEFORE_END {
+        return Ok(Some(remaining - BREAKOUT_WARNING_BEFORE_END));
+    }
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let (signal_payload, next) = if remaining.is_zero() {
+        (
+            SignalPayload::RecallBreakouts {
+                from_agent: my_pub_key.clone(),
+                round: breakout_timer.round,
+            },
+            None,
+        )
+    } else {
+        (
+            SignalPayload::BreakoutWarning {
+                from_agent: my_pub_key.clone(),
+                round: breakout_timer.round,
+                ends_at: breakout_timer.ends_at,
+            },
+            Some(remaining),
+        )
+    };
+    emit_signal(signal_payload.clone())?;
+    let encoded_signal = encode_signal(signal_payload)?;
+    let agents: Vec<AgentPubKey> = get_all_agents(())?
+        .into_iter()
+        .filter(|agent| agent != &my_pub_key)
+        .collect();
+    send_remote_signal(encoded_signal, agents)?;
+    Ok(next)
+}
+
+fn get_latest_breakout_timer() -> ExternResult<Option<BreakoutTimer>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::BreakoutTimer.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records.last() else {
+        return Ok(None);
+    };
+    latest_record
+        .entry()
+        .to_app_option::<BreakoutTimer>()
+        .map_err(|e| wasm_error!(e))
+}
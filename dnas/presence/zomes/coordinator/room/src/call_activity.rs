@@ -0,0 +1,117 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_agents::get_all_agents;
+#[cfg(feature = "devices")]
+use crate::device_binding::get_my_sibling_devices;
+use crate::event_subscription::{notify_event_subscribers, RoomEvent};
+use crate::presence_timeline::get_call_participants;
+use crate::remote_signals::{encode_signal, SignalPayload};
+use crate::room_settings::get_latest_room_settings;
+
+/// Marks the calling device as active or inactive in a call and tells the members
+/// of the room that this agent joined or left the call. With the devices feature,
+/// the other devices of the same person are informed when becoming active, so that
+/// they can raise a DeviceConflict if they are call-active as well.
+#[hdk_extern]
+pub fn set_call_active(active: bool) -> ExternResult<()> {
+    create_entry(EntryTypes::CallActivity(CallActivity { active }))?;
+    announce_call_membership(active)?;
+    if !active {
+        return Ok(());
+    }
+    // Only the first agent to join starts the call. Call participation is taken from
+    // the presence heartbeats, so two agents joining within the same presence bucket
+    // may both notify the subscribers.
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let others_in_call = get_call_participants()?
+        .into_iter()
+        .any(|agent| agent != my_pub_key);
+    if !others_in_call {
+        notify_event_subscribers(RoomEvent::CallStarted)?;
+    }
+    #[cfg(feature = "devices")]
+    announce_to_sibling_devices()?;
+    Ok(())
+}
+
+/// Tells the other devices of the same person that this device became call-active
+#[cfg(feature = "devices")]
+fn announce_to_sibling_devices() -> ExternResult<()> {
+    let sibling_devices = get_my_sibling_devices()?;
+    if sibling_devices.is_empty() {
+        return Ok(());
+    }
+
+    let signal_payload = SignalPayload::DeviceCallActive {
+        from_agent: agent_info()?.agent_initial_pubkey,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, sibling_devices)
+}
+
+/// Sends a JoinedCall or LeftCall signal to all other members of the room
+fn announce_call_membership(joined: bool) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let chime = get_latest_room_settings()?.chime_policy.plays_chime(joined);
+    let signal_payload = if joined {
+        SignalPayload::JoinedCall {
+            from_agent: my_pub_key.clone(),
+            chime,
+        }
+    } else {
+        SignalPayload::LeftCall {
+            from_agent: my_pub_key.clone(),
+            chime,
+        }
+    };
+    let agents: Vec<AgentPubKey> = get_all_agents(())?
+        .into_iter()
+        .filter(|agent| agent != &my_pub_key)
+        .collect();
+    if agents.is_empty() {
+        return Ok(());
+    }
+    let encoded_signal = encode_signal(signal_payload)?;
+    send_remote_signal(encoded_signal, agents)
+}
+
+#[hdk_extern]
+pub fn is_call_active(_: ()) -> ExternResult<bool> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::CallActivity.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records.last() else {
+        return Ok(false);
+    };
+    let call_activity = latest_record
+        .entry()
+        .to_app_option::<CallActivity>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(call_activity.map(|c| c.active).unwrap_or(false))
+}
+
+/// Handles a sibling device becoming call-active while we might be call-active ourselves.
+/// In that case both UIs receive a DeviceConflict signal naming the respective other device.
+#[cfg(feature = "devices")]
+pub fn handle_device_call_active(from_agent: AgentPubKey) -> ExternResult<()> {
+    if !get_my_sibling_devices()?.contains(&from_agent) || !is_call_active(())? {
+        return Ok(());
+    }
+
+    emit_signal(SignalPayload::DeviceConflict {
+        from_agent: from_agent.clone(),
+    })?;
+
+    let signal_payload = SignalPayload::DeviceConflict {
+        from_agent: agent_info()?.agent_initial_pubkey,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![from_agent])
+}
@@ -0,0 +1,119 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::connection_binding::is_bound_to;
+use crate::device_binding::{get_my_sibling_devices, get_primary_agent};
+use crate::remote_signals::{encode_signal, SignalPayload};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HandOffInput {
+    /// Device of the same person that is to take over the call
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_device_key: AgentPubKey,
+    /// Connections of this device to the peers of the call
+    pub connection_ids: Vec<String>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub peers: Vec<AgentPubKey>,
+}
+
+/// Hands the call this device is in off to another device of the same person. The
+/// peers receive a HandOff signal telling them to replace the given connections
+/// with connections to the new device, and the new device receives the state of
+/// the call via get_pending_call_hand_off.
+#[hdk_extern]
+pub fn hand_off_call(input: HandOffInput) -> ExternResult<()> {
+    if !get_my_sibling_devices()?.contains(&input.to_device_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Calls can only be handed off to devices bound to the same person"
+        ))));
+    }
+
+    let signal_payload = SignalPayload::HandOff {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        to_device_key: input.to_device_key.clone(),
+        connection_ids: input.connection_ids,
+        peers: input.peers.clone(),
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    let mut recipients = input.peers;
+    recipients.push(input.to_device_key);
+    send_remote_signal(encoded_signal, recipients)?;
+
+    // The call now continues on the other device, which must not be reported as
+    // a DeviceConflict once it becomes call-active
+    create_entry(EntryTypes::CallActivity(CallActivity { active: false }))?;
+    Ok(())
+}
+
+/// Returns the call that has been handed off to this device if it hasn't joined
+/// a call since
+#[hdk_extern]
+pub fn get_pending_call_hand_off(_: ()) -> ExternResult<Option<CallHandOff>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::CallHandOff.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records.last() else {
+        return Ok(None);
+    };
+    let call_activities =
+        query(ChainQueryFilter::new().entry_type(UnitEntryTypes::CallActivity.try_into()?))?;
+    let has_joined_since = call_activities
+        .last()
+        .is_some_and(|record| record.action().action_seq() > latest_record.action().action_seq());
+    if has_joined_since {
+        return Ok(None);
+    }
+    latest_record
+        .entry()
+        .to_app_option::<CallHandOff>()
+        .map_err(|e| wasm_error!(e))
+}
+
+/// Handles a HandOff signal, both as the device taking over the call and as a peer
+/// of the call. Hand-offs to devices not bound to the same person as the sender
+/// are dropped.
+pub fn handle_hand_off(signal_payload: SignalPayload) -> ExternResult<()> {
+    let SignalPayload::HandOff {
+        from_agent,
+        to_device_key,
+        connection_ids,
+        peers,
+    } = signal_payload.clone()
+    else {
+        return Ok(());
+    };
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if to_device_key == my_pub_key {
+        if !get_my_sibling_devices()?.contains(&from_agent) {
+            return Ok(());
+        }
+        create_entry(EntryTypes::CallHandOff(CallHandOff {
+            from_device: from_agent,
+            peers,
+            connection_ids,
+        }))?;
+        return emit_signal(signal_payload);
+    }
+
+    if get_primary_agent(from_agent.clone())? != get_primary_agent(to_device_key.clone())? {
+        return Ok(());
+    }
+    // Peers only close connections that the handing-off device is party to
+    let mut bound_connection_ids = Vec::new();
+    for connection_id in connection_ids {
+        if is_bound_to(&connection_id, &from_agent)? {
+            bound_connection_ids.push(connection_id);
+        }
+    }
+    emit_signal(SignalPayload::HandOff {
+        from_agent,
+        to_device_key,
+        connection_ids: bound_connection_ids,
+        peers: vec![],
+    })
+}
@@ -0,0 +1,31 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::resolve_latest_record;
+use crate::role_assignment::get_role_proof;
+
+/// Persists the end of the current call session. Only hosts may do so.
+pub fn create_call_session_end() -> ExternResult<ActionHash> {
+    let granted_via = get_role_proof(&[RoomRole::Host], "end the call for everyone")?;
+    let action_hash = create_entry(EntryTypes::CallSessionEnd(CallSessionEnd { granted_via }))?;
+    let path = Path::from(CALL_SESSION_ENDS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllCallSessionEnds,
+        (),
+    )?;
+    Ok(action_hash)
+}
+
+/// Returns the marker of the call session that has been ended last, so that clients
+/// that missed the EndCallForAll signal can tear down connections that predate it
+#[hdk_extern]
+pub fn get_latest_call_session_end(_: ()) -> ExternResult<Option<Record>> {
+    let path = Path::from(CALL_SESSION_ENDS);
+    resolve_latest_record(
+        path.path_entry_hash()?,
+        LinkTypes::AllCallSessionEnds,
+        GetOptions::default(),
+    )
+}
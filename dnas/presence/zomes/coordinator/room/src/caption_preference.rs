@@ -0,0 +1,64 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::resolve_latest;
+use crate::remote_signals::{encode_signal, SignalPayload};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SetCaptionPreferenceInput {
+    pub caption_preference: CaptionPreference,
+    /// Agents to inform about the changed preference, e.g. the other participants of a call
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub to_agents: Vec<AgentPubKey>,
+}
+
+/// Persists the caption preference of the calling agent and informs the given agents
+/// about it so that they can enable caption generation if required
+#[hdk_extern]
+pub fn set_caption_preference(input: SetCaptionPreferenceInput) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let action_hash = create_entry(EntryTypes::CaptionPreference(
+        input.caption_preference.clone(),
+    ))?;
+    create_link(
+        my_pub_key.clone(),
+        action_hash,
+        LinkTypes::AgentToCaptionPreferences,
+        (),
+    )?;
+
+    let signal_payload = SignalPayload::CaptionPreference {
+        from_agent: my_pub_key,
+        needs_captions: input.caption_preference.needs_captions,
+        language: input.caption_preference.language,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, input.to_agents)
+}
+
+#[hdk_extern]
+pub fn get_caption_preference(agent: AgentPubKey) -> ExternResult<Option<CaptionPreference>> {
+    resolve_latest(
+        agent,
+        LinkTypes::AgentToCaptionPreferences,
+        GetOptions::default(),
+    )
+}
+
+/// Returns the caption preferences of those of the given agents that have set one,
+/// allowing a joining agent to find out whether anyone in the call needs captions
+#[hdk_extern]
+pub fn get_caption_preferences(
+    agents: Vec<AgentPubKey>,
+) -> ExternResult<Vec<(AgentPubKey, CaptionPreference)>> {
+    let mut preferences = Vec::new();
+    for agent in agents {
+        if let Some(preference) = get_caption_preference(agent.clone())? {
+            preferences.push((agent, preference));
+        }
+    }
+    Ok(preferences)
+}
@@ -0,0 +1,180 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::agent_ban::{ensure_not_banned, get_banned_agent_keys};
+use crate::helper::emit_progress;
+use crate::permission_override::is_permitted;
+use crate::remote_signals::{encode_signal, SignalPayload};
+use crate::room_settings::{ensure_not_archived, get_latest_room_settings};
+use crate::storage_footprint::ensure_within_quota;
+use crate::updatable_entry::*;
+
+impl UpdatableEntry for ChatMessage {
+    const UPDATES_LINK_TYPE: LinkTypes = LinkTypes::ChatMessageUpdates;
+
+    fn into_entry_types(self) -> EntryTypes {
+        EntryTypes::ChatMessage(self)
+    }
+
+    fn index() -> Option<(Path, LinkTypes)> {
+        Some((Path::from(ALL_CHAT_MESSAGES), LinkTypes::AllChatMessages))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CreateChatMessageInput {
+    pub chat_message: ChatMessage,
+    /// Agents to notify about the new message, e.g. the other participants of the
+    /// call. Everyone else catches up via get_all_chat_messages.
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub to_agents: Vec<AgentPubKey>,
+}
+
+#[hdk_extern]
+pub fn create_chat_message(input: CreateChatMessageInput) -> ExternResult<Record> {
+    ensure_not_archived()?;
+    ensure_not_banned()?;
+    let chat_enabled = get_latest_room_settings()?.chat_enabled;
+    if !is_permitted(
+        &agent_info()?.agent_initial_pubkey,
+        Capability::Chat,
+        chat_enabled,
+    )? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            if chat_enabled {
+                "You are not allowed to chat in this room"
+            } else {
+                "The chat is disabled in this room"
+            }
+        ))));
+    }
+    ensure_within_quota(&Entry::try_from(&input.chat_message)?)?;
+    let reply_to = input.chat_message.reply_to.clone();
+    let record = create_updatable(input.chat_message)?;
+    if let Some(reply_to) = reply_to {
+        create_link(
+            reply_to,
+            record.action_address().clone(),
+            LinkTypes::ChatMessageReplies,
+            (),
+        )?;
+    }
+
+    if !input.to_agents.is_empty() {
+        let signal_payload = SignalPayload::ChatMessage {
+            from_agent: agent_info()?.agent_initial_pubkey,
+            chat_message_hash: record.action_address().clone(),
+        };
+        let encoded_signal = encode_signal(signal_payload)?;
+        send_remote_signal(encoded_signal, input.to_agents)?;
+    }
+
+    Ok(record)
+}
+
+/// Returns the chat message as originally sent
+#[hdk_extern]
+pub fn get_chat_message(chat_message_hash: ActionHash) -> ExternResult<Option<Record>> {
+    get(chat_message_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_latest_chat_message(
+    original_chat_message_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+    get_latest_updatable::<ChatMessage>(original_chat_message_hash)
+}
+
+#[hdk_extern]
+pub fn get_all_revisions_for_chat_message(
+    original_chat_message_hash: ActionHash,
+) -> ExternResult<Vec<Record>> {
+    get_updatable_revisions::<ChatMessage>(original_chat_message_hash)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UpdateChatMessageInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub original_chat_message_hash: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub previous_chat_message_hash: ActionHash,
+    pub updated_chat_message: ChatMessage,
+}
+
+/// Edits one of this agent's own chat messages
+#[hdk_extern]
+pub fn update_chat_message(input: UpdateChatMessageInput) -> ExternResult<Record> {
+    ensure_not_archived()?;
+    ensure_within_quota(&Entry::try_from(&input.updated_chat_message)?)?;
+    update_updatable(
+        input.original_chat_message_hash,
+        input.previous_chat_message_hash,
+        input.updated_chat_message,
+    )
+}
+
+/// Deletes one of this agent's own chat messages and removes it from the chat and
+/// from the thread it replies to
+#[hdk_extern]
+pub fn delete_chat_message(original_chat_message_hash: ActionHash) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    let reply_to = get_original_updatable(original_chat_message_hash.clone())?
+        .and_then(|record| record.entry().to_app_option::<ChatMessage>().ok().flatten())
+        .and_then(|chat_message| chat_message.reply_to);
+    if let Some(reply_to) = reply_to {
+        let links = get_links(
+            GetLinksInputBuilder::try_new(reply_to, LinkTypes::ChatMessageReplies)?.build(),
+        )?;
+        for link in links {
+            if link.target.into_action_hash().as_ref() == Some(&original_chat_message_hash) {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+    delete_updatable::<ChatMessage>(original_chat_message_hash)
+}
+
+/// Returns the latest revision of all replies to the given chat message, oldest first
+#[hdk_extern]
+pub fn get_thread(original_chat_message_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let mut links = get_links(
+        GetLinksInputBuilder::try_new(original_chat_message_hash, LinkTypes::ChatMessageReplies)?
+            .build(),
+    )?;
+    links.sort_by_key(|link| link.timestamp);
+    let mut replies = Vec::new();
+    for link in links {
+        if let Ok(ah) = ActionHash::try_from(link.target) {
+            if let Some(record) = get_latest_updatable::<ChatMessage>(ah)? {
+                replies.push(record);
+            }
+        }
+    }
+    Ok(replies)
+}
+
+/// Returns the latest revision of all chat messages of the room, ordered by the
+/// time they were originally sent. Messages of banned agents are left out.
+#[hdk_extern]
+pub fn get_all_chat_messages(_: ()) -> ExternResult<Vec<Record>> {
+    let path = Path::from(ALL_CHAT_MESSAGES);
+    let mut links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllChatMessages)?.build(),
+    )?;
+    links.sort_by_key(|link| link.timestamp);
+    let banned_agents = get_banned_agent_keys()?;
+    let total = links.len();
+    let mut chat_messages = Vec::new();
+    for (i, link) in links.into_iter().enumerate() {
+        let is_banned = banned_agents.contains(&link.author);
+        if let (false, Ok(ah)) = (is_banned, ActionHash::try_from(link.target)) {
+            if let Some(record) = get_latest_updatable::<ChatMessage>(ah)? {
+                chat_messages.push(record);
+            }
+        }
+        emit_progress("get_all_chat_messages", i + 1, total)?;
+    }
+    Ok(chat_messages)
+}
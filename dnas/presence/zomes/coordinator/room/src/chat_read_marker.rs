@@ -0,0 +1,48 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+/// Marks all chat messages sent up to the given time as read. Markers only ever
+/// move forward, so that a device that is behind doesn't bring back old badges.
+#[hdk_extern]
+pub fn mark_read(read_up_to: Timestamp) -> ExternResult<()> {
+    if get_read_up_to(())?.is_some_and(|current| current >= read_up_to) {
+        return Ok(());
+    }
+    create_entry(EntryTypes::ChatReadMarker(ChatReadMarker { read_up_to }))?;
+    Ok(())
+}
+
+/// Returns the time up to which this agent has read the chat, if it has read it at all
+#[hdk_extern]
+pub fn get_read_up_to(_: ()) -> ExternResult<Option<Timestamp>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::ChatReadMarker.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records.last() else {
+        return Ok(None);
+    };
+    let chat_read_marker = latest_record
+        .entry()
+        .to_app_option::<ChatReadMarker>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(chat_read_marker.map(|marker| marker.read_up_to))
+}
+
+/// Returns the number of chat messages of other agents sent after the read marker
+#[hdk_extern]
+pub fn get_unread_count(_: ()) -> ExternResult<u32> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let read_up_to = get_read_up_to(())?;
+    let path = Path::from(ALL_CHAT_MESSAGES);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllChatMessages)?.build(),
+    )?;
+    let unread_count = links
+        .into_iter()
+        .filter(|link| link.author != my_pub_key)
+        .filter(|link| !read_up_to.is_some_and(|read_up_to| link.timestamp <= read_up_to))
+        .count();
+    Ok(unread_count as u32)
+}
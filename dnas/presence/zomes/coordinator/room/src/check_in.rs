@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::room_settings::ensure_not_archived;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CheckInSummary {
+    pub session: String,
+    /// Number of agents that checked in
+    pub count: u32,
+    /// Average over the check-ins that contain a score
+    pub average_score: Option<f32>,
+    pub emoji_counts: BTreeMap<String, u32>,
+    pub words: Vec<String>,
+}
+
+#[hdk_extern]
+pub fn create_check_in(check_in: CheckIn) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    let tag = check_in_session_tag(&check_in.session);
+    let action_hash = create_entry(EntryTypes::CheckIn(check_in))?;
+    let path = Path::from(CHECK_INS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllCheckIns,
+        tag,
+    )?;
+    Ok(action_hash)
+}
+
+/// Aggregates the check-ins of a session. Only the latest check-in of each agent
+/// is taken into account. Individual check-ins are not exposed, so that
+/// facilitators can track the mood of a group over the weeks without singling out
+/// anyone.
+#[hdk_extern]
+pub fn get_checkin_summary(session: String) -> ExternResult<CheckInSummary> {
+    let tag = check_in_session_tag(&session);
+    let path = Path::from(CHECK_INS);
+    let mut links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllCheckIns)?
+            .tag_prefix(tag.clone())
+            .build(),
+    )?;
+    // The tag is a prefix of the tags of longer session names
+    links.retain(|link| link.tag == tag);
+    links.sort_by(|link_a, link_b| link_b.timestamp.cmp(&link_a.timestamp));
+
+    let mut checked_in: Vec<AgentPubKey> = Vec::new();
+    let mut check_ins = Vec::new();
+    for link in links {
+        if checked_in.contains(&link.author) {
+            continue;
+        }
+        let Ok(action_hash) = ActionHash::try_from(link.target) else {
+            continue;
+        };
+        let Some(record) = get(action_hash, GetOptions::default())? else {
+            continue;
+        };
+        if let Some(check_in) = record
+            .entry()
+            .to_app_option::<CheckIn>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            checked_in.push(link.author);
+            check_ins.push(check_in);
+        }
+    }
+
+    let scores: Vec<u8> = check_ins.iter().filter_map(|c| c.score).collect();
+    let average_score = if scores.is_empty() {
+        None
+    } else {
+        Some(scores.iter().map(|s| *s as f32).sum::<f32>() / scores.len() as f32)
+    };
+    let mut emoji_counts = BTreeMap::new();
+    for emoji in check_ins.iter().filter_map(|c| c.emoji.clone()) {
+        *emoji_counts.entry(emoji).or_insert(0) += 1;
+    }
+    let words = check_ins.iter().filter_map(|c| c.word.clone()).collect();
+    Ok(CheckInSummary {
+        session,
+        count: check_ins.len() as u32,
+        average_score,
+        emoji_counts,
+        words,
+    })
+}
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use hdk::prelude::*;
+use room_integrity::*;
+
+/// Generates a random connection id for a new connection to the given agent and
+/// binds it to that agent
+#[hdk_extern]
+pub fn new_connection_id(peer: AgentPubKey) -> ExternResult<String> {
+    let connection_id = random_bytes(16)?
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    bind_connection(connection_id.clone(), peer)?;
+    Ok(connection_id)
+}
+
+/// Time after which connection bindings expire. Signals for connections that have
+/// been bound earlier are dropped.
+pub const CONNECTION_BINDING_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Maximum number of connections that a peer can have us bind to it within
+/// CONNECTION_BINDING_TTL by sending InitRequests
+pub const MAX_INCOMING_CONNECTION_BINDINGS_PER_PEER: usize = 20;
+
+/// Binds the connection id to the given peer unless it is bound already. Returns
+/// false if the connection id is bound to another peer.
+pub fn bind_connection(connection_id: String, peer: AgentPubKey) -> ExternResult<bool> {
+    let bindings = get_recent_connection_bindings()?;
+    match bindings
+        .into_iter()
+        .find(|binding| binding.connection_id == connection_id)
+    {
+        Some(binding) => Ok(binding.peer == peer),
+        None => {
+            create_entry(EntryTypes::ConnectionBinding(ConnectionBinding {
+                connection_id,
+                peer,
+            }))?;
+            Ok(true)
+        }
+    }
+}
+
+/// Same as bind_connection, for connections that the peer has requested. Also
+/// returns false if the peer has already requested
+/// MAX_INCOMING_CONNECTION_BINDINGS_PER_PEER connections within
+/// CONNECTION_BINDING_TTL, so that peers can't make us write bindings without limit.
+pub fn bind_incoming_connection(connection_id: String, peer: AgentPubKey) -> ExternResult<bool> {
+    let bindings = get_recent_connection_bindings()?;
+    if let Some(binding) = bindings
+        .iter()
+        .find(|binding| binding.connection_id == connection_id)
+    {
+        return Ok(binding.peer == peer);
+    }
+    let bindings_to_peer = bindings
+        .iter()
+        .filter(|binding| binding.peer == peer)
+        .count();
+    if bindings_to_peer >= MAX_INCOMING_CONNECTION_BINDINGS_PER_PEER {
+        return Ok(false);
+    }
+    bind_connection(connection_id, peer)
+}
+
+/// Whether the connection id has been bound to the given peer within
+/// CONNECTION_BINDING_TTL
+pub fn is_bound_to(connection_id: &str, peer: &AgentPubKey) -> ExternResult<bool> {
+    Ok(get_recent_connection_bindings()?
+        .into_iter()
+        .any(|binding| binding.connection_id == connection_id && &binding.peer == peer))
+}
+
+/// Returns the connection bindings of the last CONNECTION_BINDING_TTL, latest first
+fn get_recent_connection_bindings() -> ExternResult<Vec<ConnectionBinding>> {
+    let expired_before =
+        Timestamp::from_micros(sys_time()?.as_micros() - CONNECTION_BINDING_TTL.as_micros() as i64);
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::ConnectionBinding.try_into()?)
+            .include_entries(true),
+    )?;
+    Ok(records
+        .into_iter()
+        .rev()
+        .take_while(|record| record.action().timestamp() >= expired_before)
+        .filter_map(|record| record.entry().to_app_option::<ConnectionBinding>().ok()?)
+        .collect())
+}
@@ -0,0 +1,124 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_agents::{get_all_agents, ALL_AGENTS};
+
+/// Number of agents from the room that get pinged if no agents are specified
+pub const DEFAULT_CONNECTIVITY_CHECK_AGENTS: usize = 5;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConnectivityCheckInput {
+    /// Agents to ping. If empty, up to DEFAULT_CONNECTIVITY_CHECK_AGENTS agents of the
+    /// room are pinged instead.
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub agents: Vec<AgentPubKey>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AgentConnectivity {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub reachable: bool,
+    pub round_trip_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConnectivityReport {
+    pub agents: Vec<AgentConnectivity>,
+    pub dht_reachable: bool,
+    pub dht_get_ms: Option<u64>,
+    pub dht_error: Option<String>,
+    /// Whether the DHT is reachable and, if any agents have been pinged, at least
+    /// one of them responded
+    pub ready: bool,
+}
+
+/// Target of the remote calls made by connectivity_check. Does nothing.
+#[hdk_extern]
+pub fn connectivity_ping(_: ()) -> ExternResult<()> {
+    Ok(())
+}
+
+/// Pings the given agents and fetches the agents anchor from the network to tell
+/// whether this agent is ready to join a call
+#[hdk_extern]
+pub fn connectivity_check(input: ConnectivityCheckInput) -> ExternResult<ConnectivityReport> {
+    let agents = if input.agents.is_empty() {
+        let my_pub_key = agent_info()?.agent_initial_pubkey;
+        get_all_agents(())?
+            .into_iter()
+            .filter(|agent| agent != &my_pub_key)
+            .take(DEFAULT_CONNECTIVITY_CHECK_AGENTS)
+            .collect()
+    } else {
+        input.agents
+    };
+
+    let zome_name = zome_info()?.name;
+    let mut agent_reports = Vec::new();
+    for agent in agents {
+        let start = sys_time()?;
+        let response = call_remote(
+            agent.clone(),
+            zome_name.clone(),
+            FunctionName::from("connectivity_ping"),
+            None,
+            (),
+        );
+        let round_trip_ms = millis_since(start)?;
+        // Any response other than a network error means that the agent has been
+        // reached, even if it rejected the call e.g. because it joined before
+        // connectivity_ping got added to its capability grant
+        let agent_report = match response {
+            Ok(ZomeCallResponse::NetworkError(err)) => AgentConnectivity {
+                agent,
+                reachable: false,
+                round_trip_ms: None,
+                error: Some(err),
+            },
+            Ok(_) => AgentConnectivity {
+                agent,
+                reachable: true,
+                round_trip_ms: Some(round_trip_ms),
+                error: None,
+            },
+            Err(err) => AgentConnectivity {
+                agent,
+                reachable: false,
+                round_trip_ms: None,
+                error: Some(err.to_string()),
+            },
+        };
+        agent_reports.push(agent_report);
+    }
+
+    let start = sys_time()?;
+    let dht_response = GetLinksInputBuilder::try_new(
+        Path::from(ALL_AGENTS).path_entry_hash()?,
+        LinkTypes::AllAgents,
+    )
+    .and_then(|builder| get_links(builder.get_options(GetStrategy::Network).build()));
+    let (dht_reachable, dht_get_ms, dht_error) = match dht_response {
+        Ok(_) => (true, Some(millis_since(start)?), None),
+        Err(err) => (false, None, Some(err.to_string())),
+    };
+
+    let ready = dht_reachable
+        && (agent_reports.is_empty() || agent_reports.iter().any(|report| report.reachable));
+    Ok(ConnectivityReport {
+        agents: agent_reports,
+        dht_reachable,
+        dht_get_ms,
+        dht_error,
+        ready,
+    })
+}
+
+fn millis_since(start: Timestamp) -> ExternResult<u64> {
+    let elapsed_micros = sys_time()?.as_micros() - start.as_micros();
+    Ok((elapsed_micros.max(0) / 1000) as u64)
+}
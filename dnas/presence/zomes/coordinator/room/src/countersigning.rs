@@ -0,0 +1,138 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+/// Milliseconds the counterparty has to countersign an entry
+pub const COUNTERSIGNING_SESSION_MILLIS: u64 = 10_000;
+
+/// Commits the entry in a countersigning session with the counterparty. The
+/// counterparty gets called on accept_fn with accept_input(preflight_request), which
+/// has to return its PreflightResponse, and then on commit_fn with
+/// commit_input(session_data), which has to commit the entry via
+/// commit_countersigned.
+pub fn countersign_with<A, C>(
+    counterparty: AgentPubKey,
+    entry: EntryTypes,
+    accept_fn: &str,
+    accept_input: impl FnOnce(PreflightRequest) -> A,
+    commit_fn: &str,
+    commit_input: impl FnOnce(CounterSigningSessionData) -> C,
+) -> ExternResult<ActionHash>
+where
+    A: Serialize + std::fmt::Debug,
+    C: Serialize + std::fmt::Debug,
+{
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let ScopedEntryDefIndex {
+        zome_index,
+        zome_type,
+    } = ScopedEntryDefIndex::try_from(&entry)?;
+    let preflight_request = PreflightRequest::try_new(
+        hash_entry(&entry)?,
+        vec![(my_pub_key, vec![]), (counterparty.clone(), vec![])],
+        vec![],
+        0,
+        false,
+        session_times_from_millis(COUNTERSIGNING_SESSION_MILLIS)?,
+        ActionBase::Create(CreateBase::new(EntryType::App(AppEntryDef::new(
+            zome_type,
+            zome_index,
+            EntryVisibility::Public,
+        )))),
+        PreflightBytes(vec![]),
+    )
+    .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.to_string())))?;
+
+    let my_response = match accept_countersigning_preflight_request(preflight_request.clone())? {
+        PreflightRequestAcceptance::Accepted(response) => response,
+        _ => {
+            return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Could not start the countersigning session"
+            ))))
+        }
+    };
+    let zome_name = zome_info()?.name;
+    let their_response: PreflightResponse = call_counterparty(
+        counterparty.clone(),
+        zome_name.clone(),
+        accept_fn,
+        accept_input(preflight_request),
+    )?;
+    let session_data =
+        CounterSigningSessionData::try_from_responses(vec![my_response, their_response], vec![])
+            .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.to_string())))?;
+
+    let _: ActionHash = call_counterparty(
+        counterparty,
+        zome_name,
+        commit_fn,
+        commit_input(session_data.clone()),
+    )?;
+    commit_countersigned(session_data, entry)
+}
+
+/// Accepts the preflight request of a countersigning session for the given entry
+pub fn accept_preflight_request(
+    preflight_request: PreflightRequest,
+    entry: &EntryTypes,
+) -> ExternResult<PreflightResponse> {
+    if hash_entry(entry)? != *preflight_request.app_entry_hash() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The preflight request does not match the entry to countersign"
+        ))));
+    }
+    match accept_countersigning_preflight_request(preflight_request)? {
+        PreflightRequestAcceptance::Accepted(response) => Ok(response),
+        _ => Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not accept the countersigning session"
+        )))),
+    }
+}
+
+/// Commits the entry with the signatures of all agents of the session
+pub fn commit_countersigned(
+    session_data: CounterSigningSessionData,
+    entry: EntryTypes,
+) -> ExternResult<ActionHash> {
+    let ScopedEntryDefIndex {
+        zome_index,
+        zome_type,
+    } = ScopedEntryDefIndex::try_from(&entry)?;
+    let Entry::App(entry_bytes) = Entry::try_from(entry)? else {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only app entries can be countersigned"
+        ))));
+    };
+    create(CreateInput::new(
+        EntryDefLocation::app(zome_index, zome_type),
+        EntryVisibility::Public,
+        Entry::CounterSign(Box::new(session_data), entry_bytes),
+        ChainTopOrdering::Strict,
+    ))
+}
+
+fn call_counterparty<I, O>(
+    counterparty: AgentPubKey,
+    zome_name: ZomeName,
+    fn_name: &str,
+    payload: I,
+) -> ExternResult<O>
+where
+    I: Serialize + std::fmt::Debug,
+    O: serde::de::DeserializeOwned + std::fmt::Debug,
+{
+    match call_remote(
+        counterparty,
+        zome_name,
+        FunctionName::from(fn_name),
+        None,
+        payload,
+    )? {
+        ZomeCallResponse::Ok(response) => response.decode().map_err(|e| wasm_error!(e)),
+        ZomeCallResponse::NetworkError(err) => Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "The counterparty could not be reached: {err}"
+        )))),
+        _ => Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The counterparty refused to countersign"
+        )))),
+    }
+}
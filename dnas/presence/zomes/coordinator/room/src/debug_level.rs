@@ -0,0 +1,37 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+/// Sets how much of this zome's activity gets written to the conductor logs
+#[hdk_extern]
+pub fn set_debug_level(level: DebugLevel) -> ExternResult<()> {
+    if get_debug_level(())? == level {
+        return Ok(());
+    }
+    create_entry(EntryTypes::DebugLevelPreference(DebugLevelPreference {
+        level,
+    }))?;
+    Ok(())
+}
+
+/// Returns the debug level set by this agent, DebugLevel::Off by default
+#[hdk_extern]
+pub fn get_debug_level(_: ()) -> ExternResult<DebugLevel> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::DebugLevelPreference.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records.last() else {
+        return Ok(DebugLevel::default());
+    };
+    let preference = latest_record
+        .entry()
+        .to_app_option::<DebugLevelPreference>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(preference.map(|p| p.level).unwrap_or_default())
+}
+
+/// Whether messages of the given level are to be logged
+pub fn is_debug_enabled(level: DebugLevel) -> ExternResult<bool> {
+    Ok(level != DebugLevel::Off && get_debug_level(())? >= level)
+}
@@ -0,0 +1,66 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::resolve_latest;
+use crate::role_assignment::{get_role_proof, has_role};
+use crate::updatable_entry::*;
+
+impl UpdatableEntry for DeprecationNotice {
+    const UPDATES_LINK_TYPE: LinkTypes = LinkTypes::DeprecationNoticeUpdates;
+
+    fn into_entry_types(self) -> EntryTypes {
+        EntryTypes::DeprecationNotice(self)
+    }
+}
+
+impl SingletonEntry for DeprecationNotice {
+    const ANCHOR: &'static str = DEPRECATION_NOTICE;
+}
+
+/// Publishes the deprecation notice. Only hosts may do so.
+#[hdk_extern]
+pub fn set_deprecation_notice(deprecation_notice: DeprecationNotice) -> ExternResult<()> {
+    set_singleton(DeprecationNotice {
+        granted_via: get_deprecation_notice_proof()?,
+        withdrawn: false,
+        ..deprecation_notice
+    })?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_deprecation_notice(_: ()) -> ExternResult<Option<DeprecationNotice>> {
+    let path = Path::from(DEPRECATION_NOTICE);
+    let deprecation_notice: Option<DeprecationNotice> = resolve_latest(
+        path.path_entry_hash()?,
+        LinkTypes::DeprecationNoticeUpdates,
+        GetOptions::default(),
+    )?;
+    Ok(deprecation_notice.filter(|deprecation_notice| !deprecation_notice.withdrawn))
+}
+
+/// Withdraws the deprecation notice with a revision that marks it as withdrawn. Only
+/// hosts may do so.
+#[hdk_extern]
+pub fn clear_deprecation_notice(_: ()) -> ExternResult<()> {
+    if get_deprecation_notice(())?.is_none() {
+        return Ok(());
+    }
+    set_singleton(DeprecationNotice {
+        message: String::new(),
+        successor: None,
+        effective_at: None,
+        granted_via: get_deprecation_notice_proof()?,
+        withdrawn: true,
+    })?;
+    Ok(())
+}
+
+fn get_deprecation_notice_proof() -> ExternResult<Option<ActionHash>> {
+    if !has_role(&agent_info()?.agent_initial_pubkey, RoomRole::Host)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only hosts can write the deprecation notice"
+        ))));
+    }
+    get_role_proof(&[RoomRole::Host], "write the deprecation notice")
+}
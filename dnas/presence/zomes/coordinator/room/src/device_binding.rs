@@ -0,0 +1,157 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_agents::get_all_agents;
+use crate::helper::emit_progress;
+
+/// Signs the public key of another device of the calling agent. Needs to be called
+/// on the primary device and the signature then be passed to `bind_device` on the
+/// additional device.
+#[hdk_extern]
+pub fn sign_device_binding(device_agent: AgentPubKey) -> ExternResult<Signature> {
+    sign(agent_info()?.agent_initial_pubkey, device_agent)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BindDeviceInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub primary_agent: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub signature: Signature,
+}
+
+/// Binds the calling device to the given primary agent
+#[hdk_extern]
+pub fn bind_device(input: BindDeviceInput) -> ExternResult<ActionHash> {
+    let device_binding = DeviceBinding {
+        primary_agent: input.primary_agent.clone(),
+        device_agent: agent_info()?.agent_initial_pubkey,
+        signature: input.signature,
+    };
+    let device_agent = device_binding.device_agent.clone();
+    let action_hash = create_entry(EntryTypes::DeviceBinding(device_binding))?;
+    create_link(
+        input.primary_agent,
+        action_hash.clone(),
+        LinkTypes::AgentToDeviceBindings,
+        (),
+    )?;
+    create_link(
+        device_agent,
+        action_hash.clone(),
+        LinkTypes::AgentToDeviceBindings,
+        (),
+    )?;
+    Ok(action_hash)
+}
+
+/// Dissolves all bindings between the calling agent and the given agent
+#[hdk_extern]
+pub fn unbind_device(agent: AgentPubKey) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    for base in [my_pub_key.clone(), agent.clone()] {
+        let links = get_links(
+            GetLinksInputBuilder::try_new(base, LinkTypes::AgentToDeviceBindings)?.build(),
+        )?;
+        for link in links {
+            let Some(device_binding) = get_device_binding(link.target)? else {
+                continue;
+            };
+            let bound_agents = [device_binding.primary_agent, device_binding.device_agent];
+            if bound_agents.contains(&my_pub_key) && bound_agents.contains(&agent) {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_device_bindings(agent: AgentPubKey) -> ExternResult<Vec<DeviceBinding>> {
+    let links =
+        get_links(GetLinksInputBuilder::try_new(agent, LinkTypes::AgentToDeviceBindings)?.build())?;
+    let mut device_bindings = Vec::new();
+    for link in links {
+        if let Some(device_binding) = get_device_binding(link.target)? {
+            device_bindings.push(device_binding);
+        }
+    }
+    Ok(device_bindings)
+}
+
+/// Returns the primary agent the given agent is bound to as a device, or the agent
+/// itself if it is not bound to any other agent
+#[hdk_extern]
+pub fn get_primary_agent(agent: AgentPubKey) -> ExternResult<AgentPubKey> {
+    let primary_agent = get_device_bindings(agent.clone())?
+        .into_iter()
+        .find(|device_binding| device_binding.device_agent == agent)
+        .map(|device_binding| device_binding.primary_agent);
+    Ok(primary_agent.unwrap_or(agent))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Participant {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub primary_agent: AgentPubKey,
+    /// All agent keys of this participant that have joined the room, including the
+    /// primary agent if it has joined itself
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub devices: Vec<AgentPubKey>,
+}
+
+/// Returns all agents of the room grouped by the person they belong to, so that one
+/// person joining from multiple devices shows up as one participant
+#[hdk_extern]
+pub fn get_all_participants(_: ()) -> ExternResult<Vec<Participant>> {
+    let agents = get_all_agents(())?;
+    let total = agents.len();
+    let mut participants: Vec<Participant> = Vec::new();
+    for (i, agent) in agents.into_iter().enumerate() {
+        let primary_agent = get_primary_agent(agent.clone())?;
+        match participants
+            .iter_mut()
+            .find(|participant| participant.primary_agent == primary_agent)
+        {
+            Some(participant) => participant.devices.push(agent),
+            None => participants.push(Participant {
+                primary_agent,
+                devices: vec![agent],
+            }),
+        }
+        emit_progress("get_all_participants", i + 1, total)?;
+    }
+    Ok(participants)
+}
+
+fn get_device_binding(target: AnyLinkableHash) -> ExternResult<Option<DeviceBinding>> {
+    let Ok(action_hash) = ActionHash::try_from(target) else {
+        return Ok(None);
+    };
+    let Some(record) = get(action_hash, GetOptions::default())? else {
+        return Ok(None);
+    };
+    Ok(record
+        .entry()
+        .to_app_option::<DeviceBinding>()
+        .ok()
+        .flatten())
+}
+
+/// Returns all other devices bound to the same person as the calling agent
+pub fn get_my_sibling_devices() -> ExternResult<Vec<AgentPubKey>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let primary_agent = get_primary_agent(my_pub_key.clone())?;
+    let mut devices: Vec<AgentPubKey> = get_device_bindings(primary_agent.clone())?
+        .into_iter()
+        .filter(|device_binding| device_binding.primary_agent == primary_agent)
+        .map(|device_binding| device_binding.device_agent)
+        .collect();
+    devices.push(primary_agent);
+    devices.retain(|device| device != &my_pub_key);
+    devices.sort();
+    devices.dedup();
+    Ok(devices)
+}
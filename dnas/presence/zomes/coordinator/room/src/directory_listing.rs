@@ -0,0 +1,50 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::resolve_latest;
+use crate::updatable_entry::*;
+
+impl UpdatableEntry for DirectoryListing {
+    const UPDATES_LINK_TYPE: LinkTypes = LinkTypes::DirectoryListingUpdates;
+
+    fn into_entry_types(self) -> EntryTypes {
+        EntryTypes::DirectoryListing(self)
+    }
+}
+
+impl SingletonEntry for DirectoryListing {
+    const ANCHOR: &'static str = DIRECTORY_LISTING;
+}
+
+/// Opts the room into public directories with the given listing, or updates it
+#[hdk_extern]
+pub fn set_directory_listing(directory_listing: DirectoryListing) -> ExternResult<()> {
+    set_singleton(directory_listing)?;
+    Ok(())
+}
+
+/// Returns the listing that directories may index, or None if the room hasn't
+/// opted in
+#[hdk_extern]
+pub fn get_directory_listing(_: ()) -> ExternResult<Option<DirectoryListing>> {
+    let path = Path::from(DIRECTORY_LISTING);
+    resolve_latest(
+        path.path_entry_hash()?,
+        LinkTypes::DirectoryListingUpdates,
+        GetOptions::default(),
+    )
+}
+
+/// Opts the room out of public directories by deleting all links to its listing
+#[hdk_extern]
+pub fn clear_directory_listing(_: ()) -> ExternResult<()> {
+    let path = Path::from(DIRECTORY_LISTING);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::DirectoryListingUpdates)?
+            .build(),
+    )?;
+    for link in links {
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
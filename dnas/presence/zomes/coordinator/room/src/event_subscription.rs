@@ -0,0 +1,105 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::remote_signals::{encode_signal, SignalPayload};
+use crate::room_settings::ensure_not_archived;
+
+/// An event in the room, sent to the delivery agents of the subscriptions to its kind
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind")]
+pub enum RoomEvent {
+    AttachmentCreated {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        attachment_hash: ActionHash,
+    },
+    CallStarted,
+}
+
+impl RoomEvent {
+    pub fn kind(&self) -> EventKind {
+        match self {
+            RoomEvent::AttachmentCreated { .. } => EventKind::AttachmentCreated,
+            RoomEvent::CallStarted => EventKind::CallStarted,
+        }
+    }
+}
+
+#[hdk_extern]
+pub fn subscribe_to_events(event_subscription: EventSubscription) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    let action_hash = create_entry(EntryTypes::EventSubscription(event_subscription))?;
+    let path = Path::from(EVENT_SUBSCRIPTIONS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllEventSubscriptions,
+        (),
+    )?;
+    Ok(action_hash)
+}
+
+/// Deletes one of this agent's event subscriptions
+#[hdk_extern]
+pub fn unsubscribe_from_events(event_subscription_hash: ActionHash) -> ExternResult<()> {
+    for link in get_event_subscription_links()? {
+        if link.target.clone().into_action_hash().as_ref() == Some(&event_subscription_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(event_subscription_hash)?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_event_subscriptions(_: ()) -> ExternResult<Vec<Record>> {
+    let mut event_subscriptions = Vec::new();
+    for link in get_event_subscription_links()? {
+        if let Some(action_hash) = link.target.into_action_hash() {
+            if let Some(record) = get(action_hash, GetOptions::default())? {
+                event_subscriptions.push(record);
+            }
+        }
+    }
+    Ok(event_subscriptions)
+}
+
+/// Sends the event to the delivery agents of all subscriptions to its kind
+pub fn notify_event_subscribers(event: RoomEvent) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let kind = event.kind();
+    let mut delivery_agents: Vec<AgentPubKey> = Vec::new();
+    for record in get_event_subscriptions(())? {
+        let Some(event_subscription) = record
+            .entry()
+            .to_app_option::<EventSubscription>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        if event_subscription.event_kinds.contains(&kind)
+            && event_subscription.delivery_agent != my_pub_key
+            && !delivery_agents.contains(&event_subscription.delivery_agent)
+        {
+            delivery_agents.push(event_subscription.delivery_agent);
+        }
+    }
+    if delivery_agents.is_empty() {
+        return Ok(());
+    }
+
+    let signal_payload = SignalPayload::RoomEvent {
+        from_agent: my_pub_key,
+        event,
+    };
+    let encoded_signal = encode_signal(signal_payload)?;
+    send_remote_signal(encoded_signal, delivery_agents)
+}
+
+fn get_event_subscription_links() -> ExternResult<Vec<Link>> {
+    let path = Path::from(EVENT_SUBSCRIPTIONS);
+    get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllEventSubscriptions)?
+            .build(),
+    )
+}
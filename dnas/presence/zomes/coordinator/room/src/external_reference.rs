@@ -0,0 +1,51 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::emit_progress;
+use crate::room_settings::ensure_not_archived;
+
+/// Registers the WAL of an asset in another applet that embeds this room, so that
+/// it can be discovered from within presence
+#[hdk_extern]
+pub fn register_external_reference(wal: String) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    let path = Path::from(EXTERNAL_REFERENCES);
+    let external_reference = ExternalReference { wal };
+    let entry_hash = hash_entry(external_reference.clone())?;
+    create_entry(EntryTypes::ExternalReference(external_reference))?;
+
+    create_link(
+        path.path_entry_hash()?,
+        entry_hash,
+        LinkTypes::ExternalReferences,
+        (),
+    )
+}
+
+#[hdk_extern]
+pub fn get_external_references(_: ()) -> ExternResult<Vec<String>> {
+    let path = Path::from(EXTERNAL_REFERENCES);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::ExternalReferences)?
+            .build(),
+    )?;
+    let total = links.len();
+    let mut wals = Vec::new();
+    for (i, link) in links.into_iter().enumerate() {
+        if let Ok(eh) = EntryHash::try_from(link.target) {
+            let maybe_record = get(eh, GetOptions::default())?;
+            if let Some(record) = maybe_record {
+                if let Ok(Some(external_reference)) =
+                    record.entry().to_app_option::<ExternalReference>()
+                {
+                    // The same WAL may have been registered by multiple agents
+                    if !wals.contains(&external_reference.wal) {
+                        wals.push(external_reference.wal);
+                    }
+                }
+            }
+        }
+        emit_progress("get_external_references", i + 1, total)?;
+    }
+    Ok(wals)
+}
@@ -0,0 +1,75 @@
+use hdk::prelude::*;
+
+use crate::room_settings::get_latest_room_settings;
+
+pub const FEATURE_AUDIO_ONLY: &str = "audio-only";
+pub const FEATURE_BREAKOUTS: &str = "breakouts";
+pub const FEATURE_CAPTIONS: &str = "captions";
+pub const FEATURE_CHAT: &str = "chat";
+pub const FEATURE_CHECK_INS: &str = "check-ins";
+pub const FEATURE_DEVICE_BINDINGS: &str = "device-bindings";
+pub const FEATURE_EXTERNAL_REFERENCES: &str = "external-references";
+pub const FEATURE_INTERPRETATION: &str = "interpretation";
+pub const FEATURE_QUESTIONS: &str = "questions";
+pub const FEATURE_REACTIONS: &str = "reactions";
+pub const FEATURE_REACTION_PALETTE: &str = "reaction-palette";
+pub const FEATURE_SCREEN_SHARE: &str = "screen-share";
+pub const FEATURE_WAITING_ROOM: &str = "waiting-room";
+pub const FEATURE_WELCOME_MESSAGE: &str = "welcome-message";
+
+/// Features that are compiled into this zome
+fn compiled_features() -> Vec<&'static str> {
+    let mut features = vec![FEATURE_REACTIONS];
+    if cfg!(feature = "breakouts") {
+        features.push(FEATURE_BREAKOUTS);
+    }
+    if cfg!(feature = "captions") {
+        features.push(FEATURE_CAPTIONS);
+    }
+    if cfg!(feature = "chat") {
+        features.push(FEATURE_CHAT);
+    }
+    if cfg!(feature = "check-ins") {
+        features.push(FEATURE_CHECK_INS);
+    }
+    if cfg!(feature = "devices") {
+        features.push(FEATURE_DEVICE_BINDINGS);
+    }
+    if cfg!(feature = "external-references") {
+        features.push(FEATURE_EXTERNAL_REFERENCES);
+    }
+    if cfg!(feature = "interpretation") {
+        features.push(FEATURE_INTERPRETATION);
+    }
+    if cfg!(feature = "questions") {
+        features.push(FEATURE_QUESTIONS);
+    }
+    features
+}
+
+/// Returns a machine-readable list of the features supported by this zome in this
+/// room, so that clients can hide UI for features that are unavailable instead of
+/// hitting missing-function errors
+#[hdk_extern]
+pub fn get_supported_features(_: ()) -> ExternResult<Vec<String>> {
+    let room_settings = get_latest_room_settings()?;
+    let mut features: Vec<String> = compiled_features().into_iter().map(String::from).collect();
+    if room_settings.reaction_palette.is_some() {
+        features.push(FEATURE_REACTION_PALETTE.into());
+    }
+    if room_settings.welcome_message.is_some() {
+        features.push(FEATURE_WELCOME_MESSAGE.into());
+    }
+    if room_settings.audio_only {
+        features.push(FEATURE_AUDIO_ONLY.into());
+    } else if room_settings.screen_share_enabled {
+        features.push(FEATURE_SCREEN_SHARE.into());
+    }
+    if room_settings.waiting_room {
+        features.push(FEATURE_WAITING_ROOM.into());
+    }
+    if !room_settings.chat_enabled {
+        features.retain(|feature| feature != FEATURE_CHAT);
+    }
+    Ok(features)
+}
@@ -0,0 +1,122 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_descendent_rooms::get_all_descendent_rooms;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FederatedRoom {
+    /// DNA hash of the top-level room that lists this room
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub lobby: DnaHash,
+    pub descendent_room: DescendentRoom,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub author: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub link_hash: ActionHash,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FederatedRoomsOutput {
+    pub rooms: Vec<FederatedRoom>,
+    /// Sibling lobbies whose rooms could not be fetched, e.g. because their cell is
+    /// not installed in this conductor
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub unreachable_lobbies: Vec<DnaHash>,
+}
+
+#[hdk_extern]
+pub fn get_sibling_lobbies(_: ()) -> ExternResult<Vec<DnaHash>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::SiblingLobbies.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records.last() else {
+        return Ok(vec![]);
+    };
+    let sibling_lobbies = latest_record
+        .entry()
+        .to_app_option::<SiblingLobbies>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(sibling_lobbies.map(|s| s.dna_hashes).unwrap_or_default())
+}
+
+/// Registers the top-level room with the given DNA hash as a sibling of this one.
+/// Its cell needs to be installed for this agent for its rooms to be listed.
+#[hdk_extern]
+pub fn register_sibling_lobby(dna_hash: DnaHash) -> ExternResult<()> {
+    let mut dna_hashes = get_sibling_lobbies(())?;
+    if dna_hashes.contains(&dna_hash) || dna_hash == dna_info()?.hash {
+        return Ok(());
+    }
+    dna_hashes.push(dna_hash);
+    create_entry(EntryTypes::SiblingLobbies(SiblingLobbies { dna_hashes }))?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn unregister_sibling_lobby(dna_hash: DnaHash) -> ExternResult<()> {
+    let mut dna_hashes = get_sibling_lobbies(())?;
+    if !dna_hashes.contains(&dna_hash) {
+        return Ok(());
+    }
+    dna_hashes.retain(|d| d != &dna_hash);
+    create_entry(EntryTypes::SiblingLobbies(SiblingLobbies { dna_hashes }))?;
+    Ok(())
+}
+
+/// Returns the descendent rooms of this room and of all its registered sibling
+/// lobbies, fetched via bridge calls to the cells of the sibling lobbies
+#[hdk_extern]
+pub fn get_federated_rooms(_: ()) -> ExternResult<FederatedRoomsOutput> {
+    let lobby = dna_info()?.hash;
+    let mut rooms: Vec<FederatedRoom> = get_all_descendent_rooms(())?
+        .into_iter()
+        .map(|(descendent_room, author, link_hash)| FederatedRoom {
+            lobby: lobby.clone(),
+            descendent_room,
+            author,
+            link_hash,
+        })
+        .collect();
+
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let zome_name = zome_info()?.name;
+    let mut unreachable_lobbies = Vec::new();
+    for sibling_lobby in get_sibling_lobbies(())? {
+        let response = call(
+            CallTargetCell::OtherCell(CellId::new(sibling_lobby.clone(), my_pub_key.clone())),
+            zome_name.clone(),
+            FunctionName::from("get_all_descendent_rooms"),
+            None,
+            (),
+        );
+        let sibling_rooms = match response {
+            Ok(ZomeCallResponse::Ok(result)) => result
+                .decode::<Vec<(DescendentRoom, AgentPubKey, ActionHash)>>()
+                .ok(),
+            _ => None,
+        };
+        let Some(sibling_rooms) = sibling_rooms else {
+            unreachable_lobbies.push(sibling_lobby);
+            continue;
+        };
+        rooms.extend(
+            sibling_rooms
+                .into_iter()
+                .map(|(descendent_room, author, link_hash)| FederatedRoom {
+                    lobby: sibling_lobby.clone(),
+                    descendent_room,
+                    author,
+                    link_hash,
+                }),
+        );
+    }
+
+    Ok(FederatedRoomsOutput {
+        rooms,
+        unreachable_lobbies,
+    })
+}
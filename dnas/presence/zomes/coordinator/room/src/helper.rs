@@ -0,0 +1,79 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::Signal;
+
+/// Number of processed items after which another progress signal is emitted
+pub const PROGRESS_SIGNAL_INTERVAL: usize = 10;
+
+/// Returns the link of the given type from the given base with the latest timestamp
+pub fn get_latest_link(
+    base: impl Into<AnyLinkableHash>,
+    link_type: LinkTypes,
+) -> ExternResult<Option<Link>> {
+    let links = get_links(GetLinksInputBuilder::try_new(base, link_type)?.build())?;
+    Ok(links
+        .into_iter()
+        .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp)))
+}
+
+/// Returns the record that the latest link of the given type from the given base
+/// points to, if any
+pub fn resolve_latest_record(
+    base: impl Into<AnyLinkableHash>,
+    link_type: LinkTypes,
+    options: GetOptions,
+) -> ExternResult<Option<Record>> {
+    let Some(link) = get_latest_link(base, link_type)? else {
+        return Ok(None);
+    };
+    let action_hash =
+        ActionHash::try_from(link.target).map_err(|e| wasm_error!(WasmErrorInner::from(e)))?;
+    get(action_hash, options)
+}
+
+/// Same as resolve_latest_record but deserializes the entry of the record
+pub fn resolve_latest<T>(
+    base: impl Into<AnyLinkableHash>,
+    link_type: LinkTypes,
+    options: GetOptions,
+) -> ExternResult<Option<T>>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    let Some(record) = resolve_latest_record(base, link_type, options)? else {
+        return Ok(None);
+    };
+    record
+        .entry()
+        .to_app_option::<T>()
+        .map_err(|e| wasm_error!(e))
+}
+
+/// Follows the updates that the given action is part of back to the original action
+pub fn get_original_action_hash(action_hash: ActionHash) -> ExternResult<ActionHash> {
+    let mut action_hash = action_hash;
+    loop {
+        let record = get(action_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest(String::from("Could not find the revision"))
+        ))?;
+        match record.action() {
+            Action::Update(update) => action_hash = update.original_action_address.clone(),
+            _ => return Ok(action_hash),
+        }
+    }
+}
+
+/// Emits a Signal::ExportProgress every PROGRESS_SIGNAL_INTERVAL items and once all
+/// items have been processed, so that the UI can show the progress of long queries.
+/// Queries of fewer than PROGRESS_SIGNAL_INTERVAL items don't emit any signals.
+pub fn emit_progress(query: &str, done: usize, total: usize) -> ExternResult<()> {
+    if total < PROGRESS_SIGNAL_INTERVAL || (done % PROGRESS_SIGNAL_INTERVAL != 0 && done != total) {
+        return Ok(());
+    }
+    emit_signal(Signal::ExportProgress {
+        query: query.into(),
+        done: done as u32,
+        total: total as u32,
+    })
+}
@@ -0,0 +1,164 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::countersigning::{accept_preflight_request, commit_countersigned, countersign_with};
+use crate::role_assignment::get_role_proof;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AcceptHostTransferInput {
+    pub preflight_request: PreflightRequest,
+    pub host_transfer: HostTransfer,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CommitHostTransferInput {
+    pub session_data: CounterSigningSessionData,
+    pub host_transfer: HostTransfer,
+}
+
+/// Hands the hosting of the room over to the given agent. Both agents countersign
+/// the HostTransfer entry, so the new host has to be online and must have consented
+/// via consent_to_host_transfer.
+#[hdk_extern]
+pub fn transfer_host(to_host: AgentPubKey) -> ExternResult<ActionHash> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let host_transfer = HostTransfer {
+        from_host: my_pub_key,
+        to_host: to_host.clone(),
+        granted_via: get_role_proof(&[RoomRole::Host], "transfer the hosting")?,
+    };
+    let action_hash = countersign_with(
+        to_host,
+        EntryTypes::HostTransfer(host_transfer.clone()),
+        "accept_host_transfer",
+        |preflight_request| AcceptHostTransferInput {
+            preflight_request,
+            host_transfer: host_transfer.clone(),
+        },
+        "commit_host_transfer",
+        |session_data| CommitHostTransferInput {
+            session_data,
+            host_transfer: host_transfer.clone(),
+        },
+    )?;
+    let path = Path::from(HOST_TRANSFERS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllHostTransfers,
+        (),
+    )?;
+    Ok(action_hash)
+}
+
+/// Records that this agent agrees to take over the hosting of the room from the
+/// given host. To be called by the UI once its user has accepted the offer. The
+/// consent covers the next transfer from that host only.
+#[hdk_extern]
+pub fn consent_to_host_transfer(from_host: AgentPubKey) -> ExternResult<ActionHash> {
+    create_entry(EntryTypes::HostTransferConsent(HostTransferConsent {
+        from_host,
+    }))
+}
+
+/// Called remotely by the current host to have this agent countersign the transfer
+/// of the hosting to it. Only accepted if this agent has consented to the transfer.
+#[hdk_extern]
+pub fn accept_host_transfer(input: AcceptHostTransferInput) -> ExternResult<PreflightResponse> {
+    if input.host_transfer.to_host != agent_info()?.agent_initial_pubkey {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The hosting is not being transferred to this agent"
+        ))));
+    }
+    if call_info()?.provenance != input.host_transfer.from_host {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the current host can transfer the hosting"
+        ))));
+    }
+    if !has_consented_to_host_transfer(&input.host_transfer.from_host)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "This agent has not consented to take over the hosting"
+        ))));
+    }
+    accept_preflight_request(
+        input.preflight_request,
+        &EntryTypes::HostTransfer(input.host_transfer),
+    )
+}
+
+/// Called remotely by the current host once both agents have accepted the session
+#[hdk_extern]
+pub fn commit_host_transfer(input: CommitHostTransferInput) -> ExternResult<ActionHash> {
+    if call_info()?.provenance != input.host_transfer.from_host {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the current host can transfer the hosting"
+        ))));
+    }
+    commit_countersigned(
+        input.session_data,
+        EntryTypes::HostTransfer(input.host_transfer),
+    )
+}
+
+/// Whether this agent has consented to a transfer from the given host since the
+/// last host transfer it countersigned
+fn has_consented_to_host_transfer(from_host: &AgentPubKey) -> ExternResult<bool> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::HostTransferConsent.try_into()?)
+            .entry_type(UnitEntryTypes::HostTransfer.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records
+        .into_iter()
+        .max_by_key(|record| record.action().action_seq())
+    else {
+        return Ok(false);
+    };
+    Ok(latest_record
+        .entry()
+        .to_app_option::<HostTransferConsent>()
+        .ok()
+        .flatten()
+        .is_some_and(|consent| &consent.from_host == from_host))
+}
+
+/// Returns all host transfers of the room, oldest first
+#[hdk_extern]
+pub fn get_host_transfers(_: ()) -> ExternResult<Vec<Record>> {
+    let path = Path::from(HOST_TRANSFERS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllHostTransfers)?
+            .build(),
+    )?;
+    let mut records = Vec::new();
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        if let Some(record) = get(action_hash, GetOptions::default())? {
+            records.push(record);
+        }
+    }
+    records.sort_by_key(|record| record.action().timestamp());
+    Ok(records)
+}
+
+/// Returns the agent the hosting has last been transferred to, along with the
+/// HostTransfer that proves it
+pub fn get_current_host() -> ExternResult<Option<(AgentPubKey, ActionHash)>> {
+    let Some(record) = get_host_transfers(())?.pop() else {
+        return Ok(None);
+    };
+    let Some(host_transfer) = record
+        .entry()
+        .to_app_option::<HostTransfer>()
+        .map_err(|e| wasm_error!(e))?
+    else {
+        return Ok(None);
+    };
+    Ok(Some((
+        host_transfer.to_host,
+        record.action_address().clone(),
+    )))
+}
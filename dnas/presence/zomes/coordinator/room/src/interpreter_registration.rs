@@ -0,0 +1,69 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+/// Registers the calling agent as an interpreter into the given language. Listeners
+/// opt into the interpreted audio channel by sending an InitRequest with the connection
+/// type "interpretation:<language>" to the interpreter.
+#[hdk_extern]
+pub fn register_as_interpreter(language: String) -> ExternResult<ActionHash> {
+    let path = Path::from(INTERPRETERS);
+    let tag = LinkTag::new(language.clone().into_bytes());
+    let action_hash = create_entry(EntryTypes::InterpreterRegistration(
+        InterpreterRegistration { language },
+    ))?;
+    create_link(
+        path.path_entry_hash()?,
+        action_hash,
+        LinkTypes::AllInterpreterRegistrations,
+        tag,
+    )
+}
+
+#[hdk_extern]
+pub fn unregister_as_interpreter(language: String) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    for (agent, registered_language, create_link_hash) in get_interpreter_links()? {
+        if agent == my_pub_key && registered_language == language {
+            delete_link(create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns all agents that are currently registered as interpreters together with
+/// the language they interpret into
+#[hdk_extern]
+pub fn get_interpreters(_: ()) -> ExternResult<Vec<(AgentPubKey, String)>> {
+    Ok(get_interpreter_links()?
+        .into_iter()
+        .map(|(agent, language, _)| (agent, language))
+        .collect())
+}
+
+/// Whether the calling agent is currently registered as an interpreter into the given language
+pub fn is_registered_interpreter(language: &str) -> ExternResult<bool> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    Ok(get_interpreter_links()?
+        .into_iter()
+        .any(|(agent, registered_language, _)| {
+            agent == my_pub_key && registered_language == language
+        }))
+}
+
+fn get_interpreter_links() -> ExternResult<Vec<(AgentPubKey, String, ActionHash)>> {
+    let path = Path::from(INTERPRETERS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(
+            path.path_entry_hash()?,
+            LinkTypes::AllInterpreterRegistrations,
+        )?
+        .build(),
+    )?;
+    Ok(links
+        .into_iter()
+        .filter_map(|link| {
+            let language = String::from_utf8(link.tag.into_inner()).ok()?;
+            Some((link.author, language, link.create_link_hash))
+        })
+        .collect())
+}
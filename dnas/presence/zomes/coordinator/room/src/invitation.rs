@@ -0,0 +1,37 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::agent_ban::{ban_agent, BanAgentInput};
+
+/// Invites the agent to the room by signing the hash of this room's DNA along with
+/// the agent's public key, so that the invitation can't be reused for other rooms of
+/// the same progenitor. The agent presents the returned proof, msgpack-encoded, as
+/// its membrane proof when joining. Only the progenitor can invite agents.
+#[hdk_extern]
+pub fn create_invitation(agent: AgentPubKey) -> ExternResult<InvitationProof> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if room_progenitor()?.as_ref() != Some(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the progenitor can invite agents"
+        ))));
+    }
+    let signature = sign(my_pub_key, (dna_info()?.hash, agent))?;
+    Ok(InvitationProof { signature })
+}
+
+/// Revokes the invitation of the agent. Membrane proofs cannot be withdrawn once an
+/// agent has joined, so the agent gets banned from the room instead.
+#[hdk_extern]
+pub fn revoke_invitation(agent: AgentPubKey) -> ExternResult<ActionHash> {
+    ban_agent(BanAgentInput {
+        agent,
+        reason: Some(String::from("Invitation revoked")),
+    })
+}
+
+/// Returns the hash of the invite code, to be set as the invite_code_hash property
+/// of a room that requires the code
+#[hdk_extern]
+pub fn get_invite_code_hash(invite_code: String) -> ExternResult<String> {
+    invite_code_hash(&invite_code)
+}
@@ -1,9 +1,80 @@
+pub mod access_log;
+pub mod activity_summary;
+pub mod admission;
+pub mod agent_ban;
+pub mod agent_status;
 pub mod all_agents;
 pub mod all_attachments;
 pub mod all_descendent_rooms;
+pub mod announcement;
+pub mod api_manifest;
 pub mod attachment;
+pub mod attachment_feed;
+pub mod attendance;
+pub mod block_list;
+#[cfg(feature = "breakouts")]
+pub mod breakout_assignment;
+#[cfg(feature = "breakouts")]
+pub mod breakout_groups;
+#[cfg(feature = "breakouts")]
+pub mod breakout_timer;
+pub mod call_activity;
+#[cfg(feature = "devices")]
+pub mod call_hand_off;
+pub mod call_session_end;
+#[cfg(feature = "captions")]
+pub mod caption_preference;
+#[cfg(feature = "chat")]
+pub mod chat_message;
+#[cfg(feature = "chat")]
+pub mod chat_read_marker;
+#[cfg(feature = "check-ins")]
+pub mod check_in;
+pub mod connection_binding;
+pub mod connectivity;
+pub mod countersigning;
+pub mod debug_level;
+pub mod deprecation_notice;
+#[cfg(feature = "devices")]
+pub mod device_binding;
+pub mod directory_listing;
+pub mod event_subscription;
+#[cfg(feature = "external-references")]
+pub mod external_reference;
+pub mod features;
+pub mod federated_rooms;
+pub mod helper;
+pub mod host_transfer;
+#[cfg(feature = "interpretation")]
+pub mod interpreter_registration;
+pub mod invitation;
+pub mod mailbox;
+pub mod media_key;
+pub mod minutes;
+pub mod moderation_log;
+pub mod ownership_claim;
+pub mod permission_override;
+pub mod pinned_content;
+pub mod presence_timeline;
+pub mod presenter_state;
+pub mod privacy_mode;
+pub mod prompt_deck;
+#[cfg(feature = "questions")]
+pub mod question;
+pub mod reconnect;
 pub mod remote_signals;
+pub mod role_assignment;
 pub mod room_info;
+pub mod room_settings;
+pub mod scheduled_publication;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod search;
+pub mod signal_trace;
+pub mod speaking_grant;
+pub mod storage_footprint;
+pub mod task;
+pub mod updatable_entry;
 use all_agents::add_agent_to_anchor;
 use hdk::prelude::*;
 use room_integrity::*;
@@ -11,7 +82,9 @@ use room_integrity::*;
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
     let mut functions = BTreeSet::new();
-    functions.insert((zome_info()?.name, FunctionName("recv_remote_signal".into())));
+    for function in api_manifest::UNRESTRICTED_FUNCTIONS {
+        functions.insert((zome_info()?.name, FunctionName(function.into())));
+    }
     let cap_grant_entry: CapGrantEntry = CapGrantEntry::new(
         String::from("Receiving remote signals"), // A string by which to later query for saved grants.
         ().into(), // Unrestricted access means any external agent can call the extern
@@ -19,9 +92,16 @@ pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
     );
     create_cap_grant(cap_grant_entry)?;
 
-    // register own public key on global anchor
-    add_agent_to_anchor(())?;
-    Ok(InitCallbackResult::Pass)
+    // register own public key on global anchor. Agents that may not join the room,
+    // e.g. because it is locked, fail init instead of erroring.
+    match add_agent_to_anchor(()) {
+        Ok(_) => Ok(InitCallbackResult::Pass),
+        Err(WasmError {
+            error: WasmErrorInner::Guest(reason),
+            ..
+        }) => Ok(InitCallbackResult::Fail(reason)),
+        Err(err) => Err(err),
+    }
 }
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(tag = "type")]
@@ -48,6 +128,18 @@ pub enum Signal {
         action: SignedActionHashed,
         original_app_entry: EntryTypes,
     },
+    WelcomeMessage {
+        welcome_message: WelcomeMessage,
+    },
+    DeprecationNotice {
+        deprecation_notice: DeprecationNotice,
+    },
+    /// Progress of a long running query such as get_all_attachments
+    ExportProgress {
+        query: String,
+        done: u32,
+        total: u32,
+    },
 }
 #[hdk_extern(infallible)]
 pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
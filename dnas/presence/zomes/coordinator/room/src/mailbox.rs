@@ -0,0 +1,78 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::agent_status::is_do_not_disturb;
+use crate::remote_signals::SignalPayload;
+
+/// Maximum number of signals that are queued during a do-not-disturb period
+pub const MAX_MAILBOX_ITEMS: usize = 200;
+
+/// Maximum number of signals of a single agent that are queued during a
+/// do-not-disturb period
+pub const MAX_MAILBOX_ITEMS_PER_SENDER: usize = 20;
+
+/// Emits the signal to the UI, or queues it into the mailbox if we're in
+/// do-not-disturb mode. Signals beyond the limits of the mailbox are dropped.
+pub fn emit_or_queue(signal_payload: SignalPayload) -> ExternResult<()> {
+    if !is_do_not_disturb()? {
+        return emit_signal(signal_payload);
+    }
+    let from_agent = call_info()?.provenance;
+    let mailbox_items = get_mailbox_items()?;
+    let queued_from_sender = mailbox_items
+        .iter()
+        .filter(|mailbox_item| mailbox_item.from_agent.as_ref() == Some(&from_agent))
+        .count();
+    if mailbox_items.len() >= MAX_MAILBOX_ITEMS
+        || queued_from_sender >= MAX_MAILBOX_ITEMS_PER_SENDER
+    {
+        return Ok(());
+    }
+    let encoded_signal = ExternIO::encode(signal_payload)
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+    create_entry(EntryTypes::MailboxItem(MailboxItem {
+        signal: encoded_signal.0,
+        from_agent: Some(from_agent),
+    }))?;
+    Ok(())
+}
+
+/// Returns the signals that have been queued during the latest do-not-disturb period,
+/// oldest first. Signals that can't be decoded anymore, e.g. because they were
+/// queued by an older version of this zome, are left out.
+#[hdk_extern]
+pub fn get_mailbox(_: ()) -> ExternResult<Vec<SignalPayload>> {
+    Ok(get_mailbox_items()?
+        .into_iter()
+        .filter_map(|mailbox_item| ExternIO(mailbox_item.signal).decode().ok())
+        .collect())
+}
+
+/// Returns the items that have been queued during the latest do-not-disturb period
+fn get_mailbox_items() -> ExternResult<Vec<MailboxItem>> {
+    let Some(status_record) = get_latest_do_not_disturb_record()? else {
+        return Ok(vec![]);
+    };
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::MailboxItem.try_into()?)
+            .include_entries(true),
+    )?;
+    Ok(records
+        .into_iter()
+        .filter(|record| record.action().action_seq() > status_record.action().action_seq())
+        .filter_map(|record| record.entry().to_app_option::<MailboxItem>().ok().flatten())
+        .collect())
+}
+
+fn get_latest_do_not_disturb_record() -> ExternResult<Option<Record>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::AgentStatus.try_into()?)
+            .include_entries(true),
+    )?;
+    Ok(records.into_iter().rev().find_map(|record| {
+        let agent_status = record.entry().to_app_option::<AgentStatus>().ok()??;
+        (agent_status.status == Status::DoNotDisturb).then_some(record)
+    }))
+}
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::presence_timeline::get_call_participants;
+use crate::remote_signals::{encode_signal, SignalPayload};
+use crate::role_assignment::has_role;
+
+/// Maximum number of epochs by which a received media key may be ahead of the latest
+/// key stored for its sender, or of epoch 0 for the first key of a sender. Keeps a
+/// sender from jumping to an epoch close to u32::MAX, after which its keys could no
+/// longer be rotated. Generous, as agents miss the rotations while not in the call.
+pub const MAX_MEDIA_KEY_EPOCH_JUMP: u32 = 1_000;
+
+/// Sends the current media key of this agent to the given agents, e.g. when they
+/// join the call, so that they can decrypt the media frames of this agent. Creates
+/// the key of epoch 0 if this agent doesn't have a key yet.
+#[hdk_extern]
+pub fn share_media_key(to_agents: Vec<AgentPubKey>) -> ExternResult<MediaKeyShare> {
+    let media_key = match get_my_media_key()? {
+        Some(media_key) => media_key,
+        None => create_my_media_key(0)?,
+    };
+    send_media_key(&media_key, to_agents)?;
+    Ok(media_key)
+}
+
+/// Replaces the media key of this agent with the key of a new epoch and sends it to
+/// the given participants, so that agents that have left the call can't decrypt the
+/// media sent from now on. To be called whenever a participant leaves; kick_agent
+/// has all remaining participants call it.
+#[hdk_extern]
+pub fn rotate_media_keys(remaining_participants: Vec<AgentPubKey>) -> ExternResult<MediaKeyShare> {
+    let epoch = match get_my_media_key()? {
+        Some(media_key) => media_key.epoch + 1,
+        None => 0,
+    };
+    let media_key = create_my_media_key(epoch)?;
+    send_media_key(&media_key, remaining_participants)?;
+    Ok(media_key)
+}
+
+/// Returns the latest media key of each agent that shared one with this agent,
+/// including this agent's own key
+#[hdk_extern]
+pub fn get_media_keys(_: ()) -> ExternResult<Vec<MediaKeyShare>> {
+    let mut latest_keys: BTreeMap<AgentPubKey, MediaKeyShare> = BTreeMap::new();
+    for media_key in query_media_keys()? {
+        let is_newer = !latest_keys
+            .get(&media_key.sender)
+            .is_some_and(|latest| latest.epoch >= media_key.epoch);
+        if is_newer {
+            latest_keys.insert(media_key.sender.clone(), media_key);
+        }
+    }
+    Ok(latest_keys.into_values().collect())
+}
+
+/// Returns the latest media key of this agent, if it has created one
+pub fn get_my_media_key() -> ExternResult<Option<MediaKeyShare>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    Ok(query_media_keys()?
+        .into_iter()
+        .filter(|media_key| media_key.sender == my_pub_key)
+        .max_by_key(|media_key| media_key.epoch))
+}
+
+/// Generates a new media key of this agent for the given epoch
+pub fn create_my_media_key(epoch: u32) -> ExternResult<MediaKeyShare> {
+    let media_key = MediaKeyShare {
+        sender: agent_info()?.agent_initial_pubkey,
+        epoch,
+        key: random_bytes(MEDIA_KEY_LENGTH as u32)?.to_vec(),
+    };
+    create_entry(EntryTypes::MediaKeyShare(media_key.clone()))?;
+    Ok(media_key)
+}
+
+/// Sends the media key to each of the given agents, encrypted for that agent
+pub fn send_media_key(media_key: &MediaKeyShare, to_agents: Vec<AgentPubKey>) -> ExternResult<()> {
+    for agent in to_agents {
+        if agent == media_key.sender {
+            continue;
+        }
+        let encrypted_key = ed_25519_x_salsa20_poly1305_encrypt(
+            media_key.sender.clone(),
+            agent.clone(),
+            XSalsa20Poly1305Data::from(media_key.key.clone()),
+        )?;
+        let signal_payload = SignalPayload::MediaKey {
+            from_agent: media_key.sender.clone(),
+            epoch: media_key.epoch,
+            encrypted_key,
+        };
+        let encoded_signal = encode_signal(signal_payload)?;
+        send_remote_signal(encoded_signal, vec![agent])?;
+    }
+    Ok(())
+}
+
+/// Stores a media key shared with this agent. The UI learns about it via the
+/// EntryCreated signal of the new MediaKeyShare. Only keys of hosts and of current
+/// call participants are accepted. Keys that can't be decrypted with the key of
+/// their sender are ignored, as are keys whose epoch is not greater than that of the
+/// latest key stored for their sender, so that replayed keys of earlier epochs can't
+/// replace the current one, or more than MAX_MEDIA_KEY_EPOCH_JUMP greater.
+/// Participants count once they recorded a heartbeat from within the call, so UIs
+/// joining a call record one before sharing their key.
+pub fn receive_media_key(
+    from_agent: AgentPubKey,
+    epoch: u32,
+    encrypted_key: XSalsa20Poly1305EncryptedData,
+) -> ExternResult<()> {
+    let latest_epoch = query_media_keys()?
+        .into_iter()
+        .filter(|media_key| media_key.sender == from_agent)
+        .map(|media_key| media_key.epoch)
+        .max();
+    let is_valid_epoch = match latest_epoch {
+        Some(latest_epoch) => {
+            epoch > latest_epoch && epoch - latest_epoch <= MAX_MEDIA_KEY_EPOCH_JUMP
+        }
+        None => epoch <= MAX_MEDIA_KEY_EPOCH_JUMP,
+    };
+    if !is_valid_epoch {
+        return Ok(());
+    }
+    if !has_role(&from_agent, RoomRole::Host)? && !get_call_participants()?.contains(&from_agent) {
+        return Ok(());
+    }
+    let Ok(key) = ed_25519_x_salsa20_poly1305_decrypt(
+        agent_info()?.agent_initial_pubkey,
+        from_agent.clone(),
+        encrypted_key,
+    ) else {
+        return Ok(());
+    };
+    if key.as_ref().len() != MEDIA_KEY_LENGTH {
+        return Ok(());
+    }
+    create_entry(EntryTypes::MediaKeyShare(MediaKeyShare {
+        sender: from_agent,
+        epoch,
+        key: key.as_ref().to_vec(),
+    }))?;
+    Ok(())
+}
+
+fn query_media_keys() -> ExternResult<Vec<MediaKeyShare>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::MediaKeyShare.try_into()?)
+            .include_entries(true),
+    )?;
+    Ok(records
+        .into_iter()
+        .filter_map(|record| {
+            record
+                .entry()
+                .to_app_option::<MediaKeyShare>()
+                .ok()
+                .flatten()
+        })
+        .collect())
+}
@@ -0,0 +1,175 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::countersigning::{accept_preflight_request, commit_countersigned, countersign_with};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FinalizeMinutesInput {
+    /// The revision of the minutes to approve
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub note_revision: ActionHash,
+    /// The member that approves the minutes together with the calling facilitator
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub co_signer: AgentPubKey,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsentToCoSignMinutesInput {
+    /// The revision of the minutes to approve
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub note_revision: ActionHash,
+    /// The facilitator that is going to finalize the minutes with this agent
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub facilitator: AgentPubKey,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AcceptMinutesFinalizationInput {
+    pub preflight_request: PreflightRequest,
+    pub minutes_finalized: MinutesFinalized,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CommitMinutesFinalizationInput {
+    pub session_data: CounterSigningSessionData,
+    pub minutes_finalized: MinutesFinalized,
+}
+
+/// Approves the given revision of the minutes as their official version. The
+/// calling facilitator and the co-signer countersign the MinutesFinalized entry, so
+/// the co-signer has to be online and must have consented to co-sign this revision
+/// via consent_to_co_sign_minutes.
+#[hdk_extern]
+pub fn finalize_minutes(input: FinalizeMinutesInput) -> ExternResult<ActionHash> {
+    get(input.note_revision.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the revision of the minutes"))
+    ))?;
+    let minutes_finalized = MinutesFinalized {
+        note_revision: input.note_revision.clone(),
+        facilitator: agent_info()?.agent_initial_pubkey,
+        co_signer: input.co_signer.clone(),
+    };
+    let action_hash = countersign_with(
+        input.co_signer,
+        EntryTypes::MinutesFinalized(minutes_finalized.clone()),
+        "accept_minutes_finalization",
+        |preflight_request| AcceptMinutesFinalizationInput {
+            preflight_request,
+            minutes_finalized: minutes_finalized.clone(),
+        },
+        "commit_minutes_finalization",
+        |session_data| CommitMinutesFinalizationInput {
+            session_data,
+            minutes_finalized: minutes_finalized.clone(),
+        },
+    )?;
+    create_link(
+        input.note_revision,
+        action_hash.clone(),
+        LinkTypes::MinutesApprovals,
+        (),
+    )?;
+    Ok(action_hash)
+}
+
+/// Records that this agent approves the given revision of the minutes, so that the
+/// facilitator can finalize them with this agent as the co-signer. To be called by
+/// the UI of the co-signer once its user has reviewed the revision.
+#[hdk_extern]
+pub fn consent_to_co_sign_minutes(input: ConsentToCoSignMinutesInput) -> ExternResult<ActionHash> {
+    create_entry(EntryTypes::MinutesCoSignerConsent(MinutesCoSignerConsent {
+        note_revision: input.note_revision,
+        facilitator: input.facilitator,
+    }))
+}
+
+/// Called remotely by the facilitator to have this agent co-sign the minutes. Only
+/// accepted if this agent has consented to co-sign the revision with this
+/// facilitator and can see the revision itself.
+#[hdk_extern]
+pub fn accept_minutes_finalization(
+    input: AcceptMinutesFinalizationInput,
+) -> ExternResult<PreflightResponse> {
+    if input.minutes_finalized.co_signer != agent_info()?.agent_initial_pubkey {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "This agent has not been asked to co-sign the minutes"
+        ))));
+    }
+    if call_info()?.provenance != input.minutes_finalized.facilitator {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the facilitator can finalize the minutes"
+        ))));
+    }
+    if !has_consented_to_co_sign(&input.minutes_finalized)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "This agent has not consented to co-sign this revision of the minutes"
+        ))));
+    }
+    get(
+        input.minutes_finalized.note_revision.clone(),
+        GetOptions::default(),
+    )?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "Could not find the revision of the minutes to co-sign"
+    ))))?;
+    accept_preflight_request(
+        input.preflight_request,
+        &EntryTypes::MinutesFinalized(input.minutes_finalized),
+    )
+}
+
+/// Called remotely by the facilitator once both agents have accepted the session
+#[hdk_extern]
+pub fn commit_minutes_finalization(
+    input: CommitMinutesFinalizationInput,
+) -> ExternResult<ActionHash> {
+    if call_info()?.provenance != input.minutes_finalized.facilitator {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only the facilitator can finalize the minutes"
+        ))));
+    }
+    commit_countersigned(
+        input.session_data,
+        EntryTypes::MinutesFinalized(input.minutes_finalized),
+    )
+}
+
+/// Returns the MinutesFinalized records approving the given revision of the minutes
+#[hdk_extern]
+pub fn get_minutes_approvals(note_revision: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(note_revision, LinkTypes::MinutesApprovals)?.build(),
+    )?;
+    let mut records = Vec::new();
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        if let Some(record) = get(action_hash, GetOptions::default())? {
+            records.push(record);
+        }
+    }
+    records.sort_by_key(|record| record.action().timestamp());
+    Ok(records)
+}
+
+fn has_consented_to_co_sign(minutes_finalized: &MinutesFinalized) -> ExternResult<bool> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::MinutesCoSignerConsent.try_into()?)
+            .include_entries(true),
+    )?;
+    Ok(records.into_iter().any(|record| {
+        record
+            .entry()
+            .to_app_option::<MinutesCoSignerConsent>()
+            .ok()
+            .flatten()
+            .is_some_and(|consent| {
+                consent.note_revision == minutes_finalized.note_revision
+                    && consent.facilitator == minutes_finalized.facilitator
+            })
+    }))
+}
@@ -0,0 +1,76 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::role_assignment::get_role_proof;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModerationLogEntry {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub moderation_action_hash: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub moderator: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub timestamp: Timestamp,
+    pub moderation_action: ModerationAction,
+}
+
+/// Records a moderation action of this agent in the moderation log of the room
+pub fn log_moderation_action(
+    kind: ModerationActionKind,
+    target: AgentPubKey,
+    reason: Option<String>,
+    subject: Option<ActionHash>,
+) -> ExternResult<ActionHash> {
+    let granted_via = match kind {
+        ModerationActionKind::Unban | ModerationActionKind::RevokeRole { .. } => None,
+        _ => get_role_proof(&[RoomRole::Host, RoomRole::Moderator], "moderate agents")?,
+    };
+    let action_hash = create_entry(EntryTypes::ModerationAction(ModerationAction {
+        kind,
+        target,
+        reason,
+        subject,
+        granted_via,
+    }))?;
+    let path = Path::from(MODERATION_LOG);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::ModerationLog,
+        (),
+    )?;
+    Ok(action_hash)
+}
+
+/// Returns all kicks, bans, force-mutes and role changes of the room, oldest first
+#[hdk_extern]
+pub fn get_moderation_log(_: ()) -> ExternResult<Vec<ModerationLogEntry>> {
+    let path = Path::from(MODERATION_LOG);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::ModerationLog)?.build(),
+    )?;
+    let mut entries = Vec::new();
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get(action_hash.clone(), GetOptions::default())? else {
+            continue;
+        };
+        if let Some(moderation_action) = record
+            .entry()
+            .to_app_option::<ModerationAction>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            entries.push(ModerationLogEntry {
+                moderation_action_hash: action_hash,
+                moderator: record.action().author().clone(),
+                timestamp: record.action().timestamp(),
+                moderation_action,
+            });
+        }
+    }
+    entries.sort_by_key(|entry| entry.timestamp);
+    Ok(entries)
+}
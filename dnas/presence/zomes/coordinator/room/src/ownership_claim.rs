@@ -0,0 +1,159 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::role_assignment::get_room_roles;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OwnershipClaimStatus {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub ownership_claim_hash: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub claimant: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub claimed_at: Timestamp,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub matures_at: Timestamp,
+    pub vetoed: bool,
+    /// Whether the contest window has passed without a veto
+    pub matured: bool,
+}
+
+/// Claims ownership of the room. Fails if a host has been active within the last
+/// OWNERSHIP_CLAIM_INACTIVITY_DAYS days.
+#[hdk_extern]
+pub fn claim_ownership(_: ()) -> ExternResult<ActionHash> {
+    let (last_host_action, host_role_assignment, last_host_action_at) = get_last_host_action()?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "This room has no hosts whose inactivity could be claimed"
+        ))))?;
+    let now = sys_time()?;
+    if now < ownership_claimable_from(last_host_action_at) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The hosts of this room have been active too recently"
+        ))));
+    }
+    let action_hash = create_entry(EntryTypes::OwnershipClaim(OwnershipClaim {
+        last_host_action,
+        host_role_assignment,
+        matures_at: ownership_claim_maturity(now),
+    }))?;
+    let path = Path::from(OWNERSHIP_CLAIMS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllOwnershipClaims,
+        (),
+    )?;
+    Ok(action_hash)
+}
+
+/// Vetoes the ownership claim. Only hosts may do so, and only before the claim
+/// has matured.
+#[hdk_extern]
+pub fn veto_ownership_claim(ownership_claim_hash: ActionHash) -> ExternResult<ActionHash> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let target: AnyLinkableHash = if room_progenitor()?.as_ref() == Some(&my_pub_key) {
+        my_pub_key.into()
+    } else {
+        get_room_roles(())?
+            .into_iter()
+            .find(|holder| holder.role == RoomRole::Host && holder.agent == my_pub_key)
+            .map(|holder| holder.role_assignment_hash)
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Only hosts can veto ownership claims"
+            ))))?
+            .into()
+    };
+    create_link(
+        ownership_claim_hash,
+        target,
+        LinkTypes::OwnershipClaimVetoes,
+        (),
+    )
+}
+
+#[hdk_extern]
+pub fn get_ownership_claims(_: ()) -> ExternResult<Vec<OwnershipClaimStatus>> {
+    let path = Path::from(OWNERSHIP_CLAIMS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllOwnershipClaims)?
+            .build(),
+    )?;
+    let now = sys_time()?;
+    let mut claims = Vec::new();
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get(action_hash.clone(), GetOptions::default())? else {
+            continue;
+        };
+        let Some(ownership_claim) = record
+            .entry()
+            .to_app_option::<OwnershipClaim>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        let vetoes = get_links(
+            GetLinksInputBuilder::try_new(action_hash.clone(), LinkTypes::OwnershipClaimVetoes)?
+                .build(),
+        )?;
+        let vetoed = !vetoes.is_empty();
+        claims.push(OwnershipClaimStatus {
+            ownership_claim_hash: action_hash,
+            claimant: record.action().author().clone(),
+            claimed_at: record.action().timestamp(),
+            matures_at: ownership_claim.matures_at,
+            vetoed,
+            matured: !vetoed && ownership_claim.matures_at <= now,
+        });
+    }
+    claims.sort_by_key(|claim| claim.claimed_at);
+    Ok(claims)
+}
+
+/// Returns the claimant of the earliest matured ownership claim along with the
+/// claim, if any
+pub fn get_room_owner() -> ExternResult<Option<(AgentPubKey, ActionHash)>> {
+    Ok(get_ownership_claims(())?
+        .into_iter()
+        .find(|claim| claim.matured)
+        .map(|claim| (claim.claimant, claim.ownership_claim_hash)))
+}
+
+/// Returns the latest action authored by any host of the room, along with the Host
+/// role assignment of its author and its timestamp
+fn get_last_host_action() -> ExternResult<Option<(ActionHash, Option<ActionHash>, Timestamp)>> {
+    let mut hosts: Vec<(AgentPubKey, Option<ActionHash>)> = get_room_roles(())?
+        .into_iter()
+        .filter(|holder| holder.role == RoomRole::Host)
+        .map(|holder| (holder.agent, Some(holder.role_assignment_hash)))
+        .collect();
+    if let Some(progenitor) = room_progenitor()? {
+        hosts.push((progenitor, None));
+    }
+    let mut last_host_action = None;
+    for (host, host_role_assignment) in hosts {
+        let activity = get_agent_activity(host, ChainQueryFilter::new(), ActivityRequest::Full)?;
+        let Some((_, action_hash)) = activity
+            .valid_activity
+            .into_iter()
+            .max_by_key(|(seq, _)| *seq)
+        else {
+            continue;
+        };
+        let Some(record) = get(action_hash.clone(), GetOptions::default())? else {
+            continue;
+        };
+        let timestamp = record.action().timestamp();
+        let is_later = !last_host_action
+            .as_ref()
+            .is_some_and(|(_, _, last_timestamp)| timestamp <= *last_timestamp);
+        if is_later {
+            last_host_action = Some((action_hash, host_role_assignment, timestamp));
+        }
+    }
+    Ok(last_host_action)
+}
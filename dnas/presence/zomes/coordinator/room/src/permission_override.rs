@@ -0,0 +1,126 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::role_assignment::{get_role_proof, get_roles_for_agent};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SetPermissionOverrideInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub capability: Capability,
+    pub allowed: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ClearPermissionOverrideInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub capability: Capability,
+}
+
+/// Allows or denies the capability to the agent. Only the progenitor, hosts and
+/// moderators may do so. Replaces earlier overrides of the same capability.
+#[hdk_extern]
+pub fn set_permission_override(input: SetPermissionOverrideInput) -> ExternResult<ActionHash> {
+    let granted_via = get_role_proof(
+        &[RoomRole::Host, RoomRole::Moderator],
+        "override permissions",
+    )?;
+    let agent = input.agent.clone();
+    let action_hash = create_entry(EntryTypes::PermissionOverride(PermissionOverride {
+        agent: input.agent,
+        capability: input.capability,
+        allowed: input.allowed,
+        granted_via,
+    }))?;
+    create_link(
+        agent,
+        action_hash.clone(),
+        LinkTypes::AgentToPermissionOverrides,
+        (),
+    )?;
+    Ok(action_hash)
+}
+
+/// Removes the overrides of the capability for the agent that this agent is
+/// allowed to remove, so that the room settings and roles apply again
+#[hdk_extern]
+pub fn clear_permission_override(input: ClearPermissionOverrideInput) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let is_progenitor = room_progenitor()?.as_ref() == Some(&my_pub_key);
+    for (link, record, permission_override) in get_permission_override_records(input.agent)? {
+        let is_issuer = record.action().author() == &my_pub_key;
+        if permission_override.capability != input.capability || !(is_issuer || is_progenitor) {
+            continue;
+        }
+        delete_link(link.create_link_hash)?;
+        delete_entry(record.action_address().clone())?;
+    }
+    Ok(())
+}
+
+/// Returns the overrides in effect for the agent, one per overridden capability
+#[hdk_extern]
+pub fn get_permission_overrides(agent: AgentPubKey) -> ExternResult<Vec<PermissionOverride>> {
+    let mut records = get_permission_override_records(agent)?;
+    records.sort_by_key(|(_, record, _)| record.action().timestamp());
+    let mut permission_overrides: Vec<PermissionOverride> = Vec::new();
+    for (_, _, permission_override) in records.into_iter().rev() {
+        if !permission_overrides
+            .iter()
+            .any(|p| p.capability == permission_override.capability)
+        {
+            permission_overrides.push(permission_override);
+        }
+    }
+    Ok(permission_overrides)
+}
+
+/// Whether the agent may use the capability. The room settings are evaluated
+/// first, then hosts and moderators are exempt from restrictions, and finally a
+/// permission override for the agent takes precedence over both.
+pub fn is_permitted(
+    agent: &AgentPubKey,
+    capability: Capability,
+    allowed_by_room: bool,
+) -> ExternResult<bool> {
+    let permission_override = get_permission_overrides(agent.clone())?
+        .into_iter()
+        .find(|p| p.capability == capability);
+    if let Some(permission_override) = permission_override {
+        return Ok(permission_override.allowed);
+    }
+    if allowed_by_room {
+        return Ok(true);
+    }
+    Ok(get_roles_for_agent(agent.clone())?
+        .iter()
+        .any(|role| matches!(role, RoomRole::Host | RoomRole::Moderator)))
+}
+
+fn get_permission_override_records(
+    agent: AgentPubKey,
+) -> ExternResult<Vec<(Link, Record, PermissionOverride)>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(agent, LinkTypes::AgentToPermissionOverrides)?.build(),
+    )?;
+    let mut records = Vec::new();
+    for link in links {
+        let Some(action_hash) = link.target.clone().into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get(action_hash, GetOptions::default())? else {
+            continue;
+        };
+        if let Some(permission_override) = record
+            .entry()
+            .to_app_option::<PermissionOverride>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            records.push((link, record, permission_override));
+        }
+    }
+    Ok(records)
+}
@@ -0,0 +1,54 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::room_settings::ensure_not_archived;
+
+#[hdk_extern]
+pub fn pin_content(action_hash: ActionHash) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    let path = Path::from(PINNED_CONTENT);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash,
+        LinkTypes::PinnedContent,
+        (),
+    )
+}
+
+/// Removes the pins of this agent from the given content
+#[hdk_extern]
+pub fn unpin_content(action_hash: ActionHash) -> ExternResult<()> {
+    ensure_not_archived()?;
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    for link in get_pinned_content_links()? {
+        if link.author == my_pub_key
+            && link.target.into_action_hash().as_ref() == Some(&action_hash)
+        {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the action hashes of all pinned content, in the order it got pinned
+#[hdk_extern]
+pub fn get_pinned_content(_: ()) -> ExternResult<Vec<ActionHash>> {
+    let mut links = get_pinned_content_links()?;
+    links.sort_by_key(|link| link.timestamp);
+    let mut pinned_content: Vec<ActionHash> = Vec::new();
+    for link in links {
+        if let Some(action_hash) = link.target.into_action_hash() {
+            if !pinned_content.contains(&action_hash) {
+                pinned_content.push(action_hash);
+            }
+        }
+    }
+    Ok(pinned_content)
+}
+
+fn get_pinned_content_links() -> ExternResult<Vec<Link>> {
+    let path = Path::from(PINNED_CONTENT);
+    get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::PinnedContent)?.build(),
+    )
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+
+use hdk::prelude::*;
+use room_integrity::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetPresenceTimelineInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub from: Timestamp,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub until: Timestamp,
+}
+
+/// A span of time during which an agent has been present without interruption
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PresenceInterval {
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub start: Timestamp,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub end: Timestamp,
+    /// Whether the agent participated in a call during the interval
+    pub in_call: bool,
+}
+
+/// Records that this agent is online in the current presence bucket. To be called
+/// by the UI at least every PRESENCE_BUCKET_SECS while it is open. Heartbeats for a
+/// bucket that has already been recorded are skipped. Heartbeats from within a call
+/// are also linked from the bucket's anchor, which get_activity_summary looks at.
+#[hdk_extern]
+pub fn record_presence_heartbeat(in_call: bool) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let bucket = presence_bucket(sys_time()?);
+    let tag = presence_heartbeat_tag(bucket, in_call);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(my_pub_key.clone(), LinkTypes::PresenceHeartbeats)?
+            .tag_prefix(tag.clone())
+            .build(),
+    )?;
+    if !links.is_empty() {
+        return Ok(());
+    }
+    create_link(
+        my_pub_key.clone(),
+        my_pub_key.clone(),
+        LinkTypes::PresenceHeartbeats,
+        tag,
+    )?;
+    if in_call {
+        create_link(
+            call_heartbeats_path(bucket).path_entry_hash()?,
+            my_pub_key,
+            LinkTypes::CallHeartbeats,
+            (),
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns the agents that recorded a heartbeat from within a call during the
+/// current or the previous presence bucket. Heartbeats are recorded once per bucket,
+/// so agents that just joined may be missing and agents that just left may still be
+/// listed.
+pub fn get_call_participants() -> ExternResult<Vec<AgentPubKey>> {
+    let bucket = presence_bucket(sys_time()?);
+    let mut participants: Vec<AgentPubKey> = Vec::new();
+    for bucket in [bucket, bucket - 1] {
+        let links = get_links(
+            GetLinksInputBuilder::try_new(
+                call_heartbeats_path(bucket).path_entry_hash()?,
+                LinkTypes::CallHeartbeats,
+            )?
+            .build(),
+        )?;
+        for link in links {
+            let Some(agent) = link.target.into_agent_pub_key() else {
+                continue;
+            };
+            if !participants.contains(&agent) {
+                participants.push(agent);
+            }
+        }
+    }
+    Ok(participants)
+}
+
+/// Returns the intervals during which the agent has been present between from and
+/// until, oldest first, with a resolution of PRESENCE_BUCKET_SECS. Buckets in which
+/// the agent recorded a heartbeat from within a call count as call participation.
+#[hdk_extern]
+pub fn get_presence_timeline(
+    input: GetPresenceTimelineInput,
+) -> ExternResult<Vec<PresenceInterval>> {
+    let from_bucket = presence_bucket(input.from);
+    let until_bucket = presence_bucket(input.until);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(input.agent.clone(), LinkTypes::PresenceHeartbeats)?.build(),
+    )?;
+    let mut buckets: BTreeMap<i64, bool> = BTreeMap::new();
+    for link in links {
+        if link.author != input.agent {
+            continue;
+        }
+        let Some((bucket, in_call)) = parse_presence_heartbeat_tag(&link.tag) else {
+            continue;
+        };
+        if bucket < from_bucket || bucket > until_bucket {
+            continue;
+        }
+        let bucket_in_call = buckets.entry(bucket).or_insert(false);
+        *bucket_in_call |= in_call;
+    }
+
+    let mut intervals: Vec<(i64, i64, bool)> = Vec::new();
+    for (bucket, in_call) in buckets {
+        match intervals.last_mut() {
+            Some((_, last_bucket, last_in_call))
+                if *last_bucket + 1 == bucket && *last_in_call == in_call =>
+            {
+                *last_bucket = bucket;
+            }
+            _ => intervals.push((bucket, bucket, in_call)),
+        }
+    }
+    Ok(intervals
+        .into_iter()
+        .map(|(first_bucket, last_bucket, in_call)| PresenceInterval {
+            start: presence_bucket_start(first_bucket),
+            end: presence_bucket_start(last_bucket + 1),
+            in_call,
+        })
+        .collect())
+}
@@ -0,0 +1,63 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_agents::get_all_agents;
+use crate::helper::resolve_latest;
+use crate::remote_signals::{encode_signal, SignalPayload};
+use crate::role_assignment::{get_role_proof, has_role};
+use crate::updatable_entry::*;
+
+impl UpdatableEntry for PresenterState {
+    const UPDATES_LINK_TYPE: LinkTypes = LinkTypes::PresenterStateUpdates;
+
+    fn into_entry_types(self) -> EntryTypes {
+        EntryTypes::PresenterState(self)
+    }
+}
+
+impl SingletonEntry for PresenterState {
+    const ANCHOR: &'static str = PRESENTER_STATE;
+}
+
+/// Sets who is presenting, or that nobody is if presenter is None, and notifies
+/// all agents of the room via a PresenterChanged signal. Only hosts may do so.
+#[hdk_extern]
+pub fn set_presenter(presenter: Option<AgentPubKey>) -> ExternResult<ActionHash> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !has_role(&my_pub_key, RoomRole::Host)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only hosts can set the presenter"
+        ))));
+    }
+    let started_at = sys_time()?;
+    let action_hash = set_singleton(PresenterState {
+        presenter: presenter.clone(),
+        started_at,
+        granted_via: get_role_proof(&[RoomRole::Host], "set the presenter")?,
+    })?;
+
+    let signal_payload = SignalPayload::PresenterChanged {
+        from_agent: my_pub_key.clone(),
+        presenter,
+        started_at,
+    };
+    let encoded_signal = encode_signal(signal_payload)?;
+    let agents: Vec<AgentPubKey> = get_all_agents(())?
+        .into_iter()
+        .filter(|agent| agent != &my_pub_key)
+        .collect();
+    send_remote_signal(encoded_signal, agents)?;
+    Ok(action_hash)
+}
+
+/// Returns who is currently presenting, for agents that join after the
+/// PresenterChanged signal has been sent
+#[hdk_extern]
+pub fn get_presenter_state(_: ()) -> ExternResult<Option<PresenterState>> {
+    let path = Path::from(PRESENTER_STATE);
+    resolve_latest(
+        path.path_entry_hash()?,
+        LinkTypes::PresenterStateUpdates,
+        GetOptions::default(),
+    )
+}
@@ -0,0 +1,34 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+/// Turns privacy mode on or off. In privacy mode, Pings from other agents are not
+/// answered, so that they can only see this agent online via PingUi, which the UI
+/// answers while it is open.
+#[hdk_extern]
+pub fn set_privacy_mode(enabled: bool) -> ExternResult<()> {
+    if get_privacy_mode(())? == enabled {
+        return Ok(());
+    }
+    create_entry(EntryTypes::PrivacyModePreference(PrivacyModePreference {
+        enabled,
+    }))?;
+    Ok(())
+}
+
+/// Returns whether this agent has turned on privacy mode, false by default
+#[hdk_extern]
+pub fn get_privacy_mode(_: ()) -> ExternResult<bool> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::PrivacyModePreference.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_record) = records.last() else {
+        return Ok(false);
+    };
+    let preference = latest_record
+        .entry()
+        .to_app_option::<PrivacyModePreference>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(preference.is_some_and(|p| p.enabled))
+}
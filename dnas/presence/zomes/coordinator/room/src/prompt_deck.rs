@@ -0,0 +1,50 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::resolve_latest;
+use crate::role_assignment::{get_role_proof, has_role};
+use crate::updatable_entry::*;
+
+impl UpdatableEntry for PromptDeck {
+    const UPDATES_LINK_TYPE: LinkTypes = LinkTypes::PromptDeckUpdates;
+
+    fn into_entry_types(self) -> EntryTypes {
+        EntryTypes::PromptDeck(self)
+    }
+}
+
+impl SingletonEntry for PromptDeck {
+    const ANCHOR: &'static str = PROMPT_DECK;
+}
+
+/// Replaces the prompts of the room's prompt deck. Only hosts may do so.
+#[hdk_extern]
+pub fn set_prompt_deck(prompts: Vec<String>) -> ExternResult<ActionHash> {
+    if !has_role(&agent_info()?.agent_initial_pubkey, RoomRole::Host)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only hosts can manage the prompt deck"
+        ))));
+    }
+    set_singleton(PromptDeck {
+        prompts,
+        granted_via: get_role_proof(&[RoomRole::Host], "manage the prompt deck")?,
+    })
+}
+
+#[hdk_extern]
+pub fn get_prompt_deck(_: ()) -> ExternResult<Option<PromptDeck>> {
+    let path = Path::from(PROMPT_DECK);
+    resolve_latest(
+        path.path_entry_hash()?,
+        LinkTypes::PromptDeckUpdates,
+        GetOptions::default(),
+    )
+}
+
+/// Returns the prompt of the session with the given seed, e.g. the id of the call,
+/// or None if the deck is empty or hasn't been set. Every client that has synced the
+/// latest deck draws the same prompt, without any need to coordinate.
+#[hdk_extern]
+pub fn draw_prompt(session_seed: String) -> ExternResult<Option<String>> {
+    Ok(get_prompt_deck(())?.and_then(|prompt_deck| prompt_deck.draw(&session_seed).cloned()))
+}
@@ -0,0 +1,149 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::emit_progress;
+use crate::room_settings::ensure_not_archived;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueuedQuestion {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub question_hash: ActionHash,
+    pub question: Question,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub author: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub asked_at: Timestamp,
+    pub upvotes: u32,
+    pub upvoted_by_me: bool,
+    pub answered: bool,
+}
+
+#[hdk_extern]
+pub fn ask_question(question: Question) -> ExternResult<Record> {
+    ensure_not_archived()?;
+    let question_hash = create_entry(&EntryTypes::Question(question))?;
+    let record = get(question_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly created Question"))
+    ))?;
+    let path = Path::from(ALL_QUESTIONS);
+    create_link(
+        path.path_entry_hash()?,
+        question_hash,
+        LinkTypes::AllQuestions,
+        (),
+    )?;
+    Ok(record)
+}
+
+/// Upvotes the question unless this agent has already upvoted it
+#[hdk_extern]
+pub fn upvote_question(question_hash: ActionHash) -> ExternResult<()> {
+    ensure_not_archived()?;
+    if get_my_links(question_hash.clone(), LinkTypes::QuestionUpvotes)?.is_empty() {
+        create_link(
+            question_hash,
+            agent_info()?.agent_initial_pubkey,
+            LinkTypes::QuestionUpvotes,
+            (),
+        )?;
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn withdraw_question_upvote(question_hash: ActionHash) -> ExternResult<()> {
+    ensure_not_archived()?;
+    for link in get_my_links(question_hash, LinkTypes::QuestionUpvotes)? {
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
+/// Moves the question to the end of the queue. Until rooms have hosts, only the
+/// author of a question may mark it as answered.
+#[hdk_extern]
+pub fn mark_answered(question_hash: ActionHash) -> ExternResult<()> {
+    ensure_not_archived()?;
+    if get_my_links(question_hash.clone(), LinkTypes::AnsweredQuestions)?.is_empty() {
+        create_link(
+            question_hash,
+            agent_info()?.agent_initial_pubkey,
+            LinkTypes::AnsweredQuestions,
+            (),
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns all questions of the room, the unanswered ones first. Questions are
+/// ordered by their number of upvotes and then by the time they were asked.
+#[hdk_extern]
+pub fn get_question_queue(_: ()) -> ExternResult<Vec<QueuedQuestion>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let path = Path::from(ALL_QUESTIONS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllQuestions)?.build(),
+    )?;
+    let total = links.len();
+    let mut queue = Vec::new();
+    for (i, link) in links.into_iter().enumerate() {
+        if let Ok(question_hash) = ActionHash::try_from(link.target) {
+            if let Some(record) = get(question_hash.clone(), GetOptions::default())? {
+                if let Some(question) = record
+                    .entry()
+                    .to_app_option::<Question>()
+                    .map_err(|e| wasm_error!(e))?
+                {
+                    let voters =
+                        get_link_authors(question_hash.clone(), LinkTypes::QuestionUpvotes)?;
+                    let answered =
+                        !get_link_authors(question_hash.clone(), LinkTypes::AnsweredQuestions)?
+                            .is_empty();
+                    queue.push(QueuedQuestion {
+                        question_hash,
+                        question,
+                        author: record.action().author().clone(),
+                        asked_at: record.action().timestamp(),
+                        upvotes: voters.len() as u32,
+                        upvoted_by_me: voters.contains(&my_pub_key),
+                        answered,
+                    });
+                }
+            }
+        }
+        emit_progress("get_question_queue", i + 1, total)?;
+    }
+    queue.sort_by_key(|queued| {
+        (
+            queued.answered,
+            std::cmp::Reverse(queued.upvotes),
+            queued.asked_at,
+        )
+    });
+    Ok(queue)
+}
+
+fn get_my_links(question_hash: ActionHash, link_type: LinkTypes) -> ExternResult<Vec<Link>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let links = get_links(GetLinksInputBuilder::try_new(question_hash, link_type)?.build())?;
+    Ok(links
+        .into_iter()
+        .filter(|link| link.author == my_pub_key)
+        .collect())
+}
+
+/// Distinct authors of the links, so that duplicate upvotes only count once
+fn get_link_authors(
+    question_hash: ActionHash,
+    link_type: LinkTypes,
+) -> ExternResult<Vec<AgentPubKey>> {
+    let links = get_links(GetLinksInputBuilder::try_new(question_hash, link_type)?.build())?;
+    let mut authors: Vec<AgentPubKey> = Vec::new();
+    for link in links {
+        if !authors.contains(&link.author) {
+            authors.push(link.author);
+        }
+    }
+    Ok(authors)
+}
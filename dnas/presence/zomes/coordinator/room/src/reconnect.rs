@@ -0,0 +1,34 @@
+use hdk::prelude::*;
+
+use crate::connection_binding::is_bound_to;
+use crate::remote_signals::{encode_signal, SignalPayload};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReconnectRequestInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_agent: AgentPubKey,
+    pub previous_connection_id: String,
+}
+
+/// Asks the peer to resume a connection that was set up before this agent's
+/// conductor restarted. The peer knows this agent is the same participant because
+/// signals are signed by their sender and the connection is bound to this agent.
+/// Fails if the connection isn't bound to the peer anymore, in which case a new
+/// connection has to be initiated.
+#[hdk_extern]
+pub fn send_reconnect_request(input: ReconnectRequestInput) -> ExternResult<()> {
+    if !is_bound_to(&input.previous_connection_id, &input.to_agent)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "This connection can't be resumed anymore"
+        ))));
+    }
+    let signal_payload = SignalPayload::ReconnectRequest {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        previous_connection_id: input.previous_connection_id,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.to_agent])
+}
@@ -1,69 +1,912 @@
 use hdk::prelude::*;
+use room_integrity::{
+    Capability, DebugLevel, ModerationActionKind, RoomRole, RoomSettings, SignalTraceEvent,
+};
+
+#[cfg(feature = "interpretation")]
+use room_integrity::interpretation_language;
+
+use crate::access_log::receive_attachment_access;
+use crate::admission::{handle_join_request, is_admitted};
+use crate::agent_ban::is_banned;
+use crate::all_agents::{get_all_agents, get_joined_at};
+use crate::block_list::is_blocked;
+#[cfg(feature = "devices")]
+use crate::call_activity::handle_device_call_active;
+#[cfg(feature = "devices")]
+use crate::call_hand_off::handle_hand_off;
+use crate::call_session_end::create_call_session_end;
+use crate::connection_binding::{bind_connection, bind_incoming_connection, is_bound_to};
+use crate::debug_level::is_debug_enabled;
+#[cfg(feature = "devices")]
+use crate::device_binding::get_my_sibling_devices;
+use crate::event_subscription::RoomEvent;
+#[cfg(feature = "interpretation")]
+use crate::interpreter_registration::is_registered_interpreter;
+use crate::mailbox::emit_or_queue;
+use crate::media_key::{receive_media_key, rotate_media_keys};
+use crate::moderation_log::log_moderation_action;
+use crate::permission_override::is_permitted;
+use crate::privacy_mode::get_privacy_mode;
+use crate::role_assignment::{get_other_hosts, has_role};
+use crate::room_settings::{
+    ensure_not_archived, get_latest_room_settings, get_local_room_settings, get_locked_since,
+};
+use crate::signal_trace::{acknowledge_signal, record_signal_trace};
+
+pub const SCREEN_SHARE_CONNECTION_TYPE: &str = "screen";
+/// Connection type of video streams that are sent separately from the main
+/// connection of a call
+pub const VIDEO_CONNECTION_TYPE: &str = "video";
+
+/// Version of the API of this zome towards the UI and other agents. To be increased
+/// with every change that breaks compatibility with clients of the previous version.
+pub const ZOME_API_VERSION: u32 = 2;
+
+/// Seconds after which remote signals are dropped as stale, unless the room
+/// settings configure otherwise
+pub const DEFAULT_SIGNAL_MAX_AGE_SECS: u32 = 60;
+
+/// Seconds that the clock of the sender of a remote signal may be ahead of ours
+pub const SIGNAL_CLOCK_SKEW_TOLERANCE_SECS: i64 = 30;
+
+/// Maximum length in bytes of the note of an Emergency signal
+pub const MAX_EMERGENCY_NOTE_LENGTH: usize = 500;
+
+/// Maximum number of simulcast layers that can be advertised for a stream
+pub const MAX_SIMULCAST_LAYERS: usize = 4;
+
+/// Number of agents that a ping is dispatched to per remote signal call
+pub const PING_CHUNK_SIZE: usize = 10;
 
 #[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum SignalPayload {
     Ping {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
         from_agent: AgentPubKey,
     },
     Pong {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
         from_agent: AgentPubKey,
     },
     PingUi {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
         from_agent: AgentPubKey,
     },
     PongUi {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
         from_agent: AgentPubKey,
     },
     InitRequest {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
         from_agent: AgentPubKey,
         connection_id: String,
         connection_type: Option<String>,
+        #[serde(default)]
+        trace_id: Option<String>,
     },
     InitAccept {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
         from_agent: AgentPubKey,
         connection_id: String,
         connection_type: Option<String>,
+        #[serde(default)]
+        trace_id: Option<String>,
     },
     SdpData {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
         from_agent: AgentPubKey,
         connection_id: String,
         data: String,
+        #[serde(default)]
+        trace_id: Option<String>,
     },
+    /// SdpData whose data is encrypted for the recipient, so that the nodes relaying
+    /// the signal can't read it. Decrypted into SdpData before it reaches the UI.
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    EncryptedSdpData {
+        from_agent: AgentPubKey,
+        connection_id: String,
+        encrypted_data: XSalsa20Poly1305EncryptedData,
+        #[serde(default)]
+        trace_id: Option<String>,
+    },
+    /// The sender's conductor has restarted and it asks to resume the connection
+    /// instead of joining as a new participant
+    ReconnectRequest {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        previous_connection_id: String,
+    },
+    /// The sender declines the InitRequest of the connection, e.g. because it is busy
+    /// or has no camera
+    RejectInit {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        connection_id: String,
+        reason: Option<String>,
+    },
+    /// Acknowledges a traced InitRequest, InitAccept or SdpData signal
+    SignalAck {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        connection_id: String,
+        trace_id: String,
+        event: SignalTraceEvent,
+    },
+    /// The sender has torn down the connection, e.g. because it left the call or
+    /// stopped sharing its screen. Peers are to close it right away instead of
+    /// waiting for it to time out.
+    CloseConnection {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        connection_id: String,
+        reason: Option<String>,
+    },
+    /// The sender wants to add or remove tracks of the connection, e.g. because it
+    /// turned on its camera, and asks the peer to renegotiate it
+    RenegotiationNeeded {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        connection_id: String,
+    },
+    /// Quality of the connection as measured by the sender, for the UI to show
+    /// connection indicators per participant
+    ConnectionStats {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        connection_id: String,
+        rtt_ms: u32,
+        /// Fraction of packets lost, from 0 to 1
+        packet_loss: f32,
+        /// In milliseconds
+        jitter: f32,
+    },
+    /// The sender needs the help of a host right away, e.g. in a safeguarding
+    /// situation. Always shown to hosts, even in do-not-disturb mode.
+    Emergency {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        note: Option<String>,
+    },
+    /// The sender's connection can't keep up and it asks the peer to send it less.
+    /// Limits that are None are lifted.
+    RequestQualityChange {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        connection_id: String,
+        /// In kbit/s
+        max_bitrate: Option<u32>,
+        /// Height of the video in pixels, e.g. 360
+        max_resolution: Option<u32>,
+    },
+    /// The simulcast layers that the sender encodes its stream of the connection in
+    SimulcastLayers {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        connection_id: String,
+        layers: Vec<SimulcastLayer>,
+    },
+    /// The sender asks the peer to forward only the given simulcast layer on the
+    /// connection, e.g. a thumbnail of an agent that is not speaking
+    RequestSimulcastLayer {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        connection_id: String,
+        /// The rid of the layer
+        rid: String,
+    },
+    Reaction {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        reaction: String,
+    },
+    CaptionPreference {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        needs_captions: bool,
+        language: Option<String>,
+    },
+    DeviceCallActive {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+    },
+    DeviceConflict {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+    },
+    ChatMessage {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        chat_message_hash: ActionHash,
+    },
+    ClientHello {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        app_version: String,
+        zome_api_version: u32,
+    },
+    AttachmentPublished {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        attachment_hash: ActionHash,
+    },
+    AnnouncementPublished {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        announcement_hash: ActionHash,
+    },
+    /// The sender has opened an audited attachment and discloses it to the hosts
+    AttachmentAccessed {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        attachment_hash: ActionHash,
+        #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+        accessed_at: Timestamp,
+    },
+    RoomEvent {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        event: RoomEvent,
+    },
+    /// The sender hands its call off to another device of the same person. Peers
+    /// are to replace the given connections with connections to to_device_key.
+    HandOff {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        to_device_key: AgentPubKey,
+        connection_ids: Vec<String>,
+        /// Only set in the signal to the device taking over the call
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+        peers: Vec<AgentPubKey>,
+    },
+    /// The sender removes the recipient from the call
+    Kick {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        reason: Option<String>,
+    },
+    /// The sender asks the recipient to mute its microphone
+    MuteRequest {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+    },
+    /// The sender mutes the microphone of the recipient
+    ForceMute {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+    },
+    /// The sender, a host, ends the call for everyone
+    EndCallForAll {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        /// The CallSessionEnd entry persisting the end of the call
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        call_session_end_hash: ActionHash,
+    },
+    /// The sender asks to be admitted to the call of a room with a waiting room
+    JoinRequest {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+    },
+    /// A host or moderator has admitted or denied the recipient
+    AdmitResponse {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        admitted: bool,
+    },
+    /// The breakout round started by the sender ends soon
+    BreakoutWarning {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        round: u32,
+        #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+        ends_at: Timestamp,
+    },
+    /// The breakout round started by the sender has ended and its participants are
+    /// to return to the main room
+    RecallBreakouts {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        round: u32,
+    },
+    /// The sender shares the key it encrypts its media frames with, encrypted for
+    /// the recipient. Stored as a MediaKeyShare instead of reaching the UI.
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    MediaKey {
+        from_agent: AgentPubKey,
+        epoch: u32,
+        encrypted_key: XSalsa20Poly1305EncryptedData,
+    },
+    /// The sender, a host or moderator, has removed departed_agent from the call and
+    /// asks the recipient to rotate its media key among the remaining participants
+    RotateMediaKeys {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        departed_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+        participants: Vec<AgentPubKey>,
+    },
+    /// The sender has joined the call of the room. Clients chime according to the
+    /// chime_policy of the room settings.
+    JoinedCall {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        chime: bool,
+    },
+    /// The sender has left the call of the room
+    LeftCall {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        chime: bool,
+    },
+    /// The sender, a host, has changed who is presenting. None if nobody is.
+    PresenterChanged {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+        presenter: Option<AgentPubKey>,
+        #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+        started_at: Timestamp,
+    },
+    /// The sender has assigned the task to the recipient
+    TaskAssigned {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        task_hash: ActionHash,
+    },
+    /// The due date of a task assigned to the recipient has passed without the task
+    /// being done
+    TaskDue {
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        from_agent: AgentPubKey,
+        #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+        task_hash: ActionHash,
+    },
+}
+
+/// Wire format of remote signals. The time of sending lets recipients drop signals
+/// that a relay captured and replays later.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+pub struct SignalEnvelope {
+    pub sent_at: Timestamp,
+    pub payload: SignalPayload,
+}
+
+/// Wraps the payload into a SignalEnvelope sent now and encodes it for
+/// send_remote_signal
+pub fn encode_signal(payload: SignalPayload) -> ExternResult<ExternIO> {
+    ExternIO::encode(SignalEnvelope {
+        sent_at: sys_time()?,
+        payload,
+    })
+    .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))
 }
 
 #[hdk_extern]
 pub fn recv_remote_signal(signal: ExternIO) -> ExternResult<()> {
-    let signal_payload: SignalPayload = signal
+    let envelope: SignalEnvelope = signal
         .decode()
         .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
 
-    debug!("### GOT REMOTE SIGNAL ###");
+    if is_debug_enabled(DebugLevel::Verbose)? {
+        debug!("### GOT REMOTE SIGNAL ### {:?}", envelope);
+    }
+    // Read once from the local store, as this runs for every signal. Admission of
+    // calls still reads the latest settings in is_init_request_accepted, since
+    // falling back to the defaults there would unlock locked rooms.
+    let room_settings = get_local_room_settings()?;
+    if !is_fresh(&room_settings, envelope.sent_at)? {
+        return Ok(());
+    }
+    let signal_payload = envelope.payload;
+    let signal_payload = match signal_payload {
+        SignalPayload::EncryptedSdpData {
+            from_agent,
+            connection_id,
+            encrypted_data,
+            trace_id,
+        } => {
+            // Data that can't be decrypted with the key of the claimed sender is
+            // dropped like any other signal that doesn't come from its sender
+            let Some(data) = decrypt_from(&from_agent, encrypted_data)? else {
+                return Ok(());
+            };
+            SignalPayload::SdpData {
+                from_agent,
+                connection_id,
+                data,
+                trace_id,
+            }
+        }
+        signal_payload => signal_payload,
+    };
     match signal_payload.clone() {
-        SignalPayload::Ping { from_agent } => pong(from_agent),
+        SignalPayload::Ping { from_agent } => {
+            if !is_pong_allowed(&from_agent)? {
+                return Ok(());
+            }
+            pong(from_agent)
+        }
         SignalPayload::Pong { .. } => emit_signal(signal_payload),
         SignalPayload::PingUi { .. } => emit_signal(signal_payload),
         SignalPayload::PongUi { .. } => emit_signal(signal_payload),
-        SignalPayload::InitRequest { .. } => emit_signal(signal_payload),
-        SignalPayload::InitAccept { .. } => emit_signal(signal_payload),
-        SignalPayload::SdpData { .. } => emit_signal(signal_payload),
+        SignalPayload::InitRequest {
+            from_agent,
+            connection_id,
+            connection_type,
+            trace_id,
+        } => {
+            if !is_init_request_accepted(connection_type.as_deref(), &from_agent)? {
+                return Ok(());
+            }
+            // Ignore requests reusing a connection id of another peer, and peers that
+            // have requested too many connections recently
+            if !is_from_sender(&from_agent)?
+                || !bind_incoming_connection(connection_id.clone(), from_agent.clone())?
+            {
+                return Ok(());
+            }
+            acknowledge_signal(
+                trace_id,
+                SignalTraceEvent::InitRequest,
+                connection_id,
+                from_agent,
+            )?;
+            emit_signal(signal_payload)
+        }
+        SignalPayload::InitAccept {
+            from_agent,
+            connection_id,
+            trace_id,
+            ..
+        }
+        | SignalPayload::SdpData {
+            from_agent,
+            connection_id,
+            trace_id,
+            ..
+        } => {
+            // Peers may only send data for connections that they are party to, and
+            // agents that we have blocked or that got banned since the connection got
+            // bound are cut off
+            if !is_from_sender(&from_agent)?
+                || !is_bound_to(&connection_id, &from_agent)?
+                || is_blocked(&from_agent)?
+                || is_banned(&from_agent)?
+            {
+                return Ok(());
+            }
+            let event = match signal_payload {
+                SignalPayload::InitAccept { .. } => SignalTraceEvent::InitAccept,
+                _ => SignalTraceEvent::SdpData,
+            };
+            acknowledge_signal(trace_id, event, connection_id, from_agent)?;
+            emit_signal(signal_payload)
+        }
+        SignalPayload::EncryptedSdpData { .. } => Ok(()),
+        SignalPayload::ReconnectRequest {
+            from_agent,
+            previous_connection_id,
+        } => {
+            if !is_from_sender(&from_agent)?
+                || !is_bound_to(&previous_connection_id, &from_agent)?
+                || is_blocked(&from_agent)?
+                || is_banned(&from_agent)?
+            {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        SignalPayload::RejectInit {
+            from_agent,
+            connection_id,
+            ..
+        }
+        | SignalPayload::CloseConnection {
+            from_agent,
+            connection_id,
+            ..
+        }
+        | SignalPayload::RenegotiationNeeded {
+            from_agent,
+            connection_id,
+        }
+        | SignalPayload::ConnectionStats {
+            from_agent,
+            connection_id,
+            ..
+        }
+        | SignalPayload::RequestQualityChange {
+            from_agent,
+            connection_id,
+            ..
+        }
+        | SignalPayload::SimulcastLayers {
+            from_agent,
+            connection_id,
+            ..
+        }
+        | SignalPayload::RequestSimulcastLayer {
+            from_agent,
+            connection_id,
+            ..
+        } => {
+            if !is_from_sender(&from_agent)?
+                || !is_bound_to(&connection_id, &from_agent)?
+                || is_banned(&from_agent)?
+            {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        SignalPayload::SignalAck {
+            from_agent,
+            connection_id,
+            trace_id,
+            ..
+        } => {
+            if !is_from_sender(&from_agent)? || !is_bound_to(&connection_id, &from_agent)? {
+                return Ok(());
+            }
+            record_signal_trace(
+                Some(&trace_id),
+                SignalTraceEvent::Ack,
+                &connection_id,
+                &from_agent,
+                false,
+            )?;
+            emit_signal(signal_payload)
+        }
+        SignalPayload::Reaction {
+            from_agent,
+            reaction,
+        } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            // Drop reactions that are not part of this room's reaction palette
+            if room_settings.is_reaction_allowed(&reaction) {
+                emit_or_queue(signal_payload)
+            } else {
+                Ok(())
+            }
+        }
+        SignalPayload::CaptionPreference { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        #[cfg(feature = "devices")]
+        SignalPayload::DeviceCallActive { from_agent } => handle_device_call_active(from_agent),
+        #[cfg(not(feature = "devices"))]
+        SignalPayload::DeviceCallActive { .. } => Ok(()),
+        #[cfg(feature = "devices")]
+        SignalPayload::DeviceConflict { from_agent } => {
+            if !is_from_sender(&from_agent)? || !get_my_sibling_devices()?.contains(&from_agent) {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        #[cfg(not(feature = "devices"))]
+        SignalPayload::DeviceConflict { .. } => Ok(()),
+        SignalPayload::ChatMessage { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_or_queue(signal_payload)
+        }
+        SignalPayload::ClientHello { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        SignalPayload::AttachmentPublished { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_or_queue(signal_payload)
+        }
+        SignalPayload::AnnouncementPublished { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_or_queue(signal_payload)
+        }
+        SignalPayload::AttachmentAccessed {
+            from_agent,
+            attachment_hash,
+            accessed_at,
+        } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            receive_attachment_access(from_agent, attachment_hash, accessed_at)
+        }
+        SignalPayload::RoomEvent { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        #[cfg(feature = "devices")]
+        SignalPayload::HandOff { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            handle_hand_off(signal_payload)
+        }
+        #[cfg(not(feature = "devices"))]
+        SignalPayload::HandOff { .. } => Ok(()),
+        SignalPayload::Kick { from_agent, .. }
+        | SignalPayload::MuteRequest { from_agent }
+        | SignalPayload::ForceMute { from_agent } => {
+            // Only hosts and moderators may remove or mute others
+            if !is_from_sender(&from_agent)? || !is_moderating(&from_agent)? {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        SignalPayload::MediaKey {
+            from_agent,
+            epoch,
+            encrypted_key,
+        } => {
+            if !is_from_sender(&from_agent)? || is_blocked(&from_agent)? {
+                return Ok(());
+            }
+            receive_media_key(from_agent, epoch, encrypted_key)
+        }
+        SignalPayload::RotateMediaKeys {
+            from_agent,
+            departed_agent,
+            participants,
+        } => {
+            if !is_from_sender(&from_agent)? || !is_moderating(&from_agent)? {
+                return Ok(());
+            }
+            let remaining_participants = participants
+                .into_iter()
+                .filter(|agent| agent != &departed_agent)
+                .collect();
+            rotate_media_keys(remaining_participants)?;
+            Ok(())
+        }
+        SignalPayload::EndCallForAll { from_agent, .. }
+        | SignalPayload::PresenterChanged { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? || !has_role(&from_agent, RoomRole::Host)? {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        SignalPayload::JoinRequest { from_agent } => {
+            if !is_from_sender(&from_agent)? || !handle_join_request(from_agent)? {
+                return Ok(());
+            }
+            emit_or_queue(signal_payload)
+        }
+        SignalPayload::AdmitResponse { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? || !is_moderating(&from_agent)? {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        SignalPayload::BreakoutWarning { from_agent, .. }
+        | SignalPayload::RecallBreakouts { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        SignalPayload::Emergency { from_agent, note } => {
+            // Not subject to the block list or do-not-disturb mode, so that nobody
+            // can be kept from reaching the hosts in an emergency. Notes longer than
+            // send_emergency allows only come from modified clients.
+            if !is_from_sender(&from_agent)?
+                || note
+                    .as_ref()
+                    .is_some_and(|note| note.len() > MAX_EMERGENCY_NOTE_LENGTH)
+                || !has_role(&agent_info()?.agent_initial_pubkey, RoomRole::Host)?
+            {
+                return Ok(());
+            }
+            emit_signal(signal_payload)
+        }
+        SignalPayload::JoinedCall { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_signal(SignalPayload::JoinedCall {
+                from_agent,
+                chime: room_settings.chime_policy.plays_chime(true),
+            })
+        }
+        SignalPayload::LeftCall { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_signal(SignalPayload::LeftCall {
+                from_agent,
+                chime: room_settings.chime_policy.plays_chime(false),
+            })
+        }
+        SignalPayload::TaskAssigned { from_agent, .. }
+        | SignalPayload::TaskDue { from_agent, .. } => {
+            if !is_from_sender(&from_agent)? {
+                return Ok(());
+            }
+            emit_or_queue(signal_payload)
+        }
+    }
+}
+
+/// Whether a signal sent at the given time is recent enough to be processed. Signals
+/// sent up to SIGNAL_CLOCK_SKEW_TOLERANCE_SECS in the future are accepted, as the
+/// clocks of the sender and this agent may differ.
+fn is_fresh(room_settings: &RoomSettings, sent_at: Timestamp) -> ExternResult<bool> {
+    let max_age_secs = room_settings
+        .signal_max_age_secs
+        .unwrap_or(DEFAULT_SIGNAL_MAX_AGE_SECS);
+    let age_micros = sys_time()?.as_micros() - sent_at.as_micros();
+    Ok(age_micros <= i64::from(max_age_secs) * 1_000_000
+        && age_micros >= -(SIGNAL_CLOCK_SKEW_TOLERANCE_SECS * 1_000_000))
+}
+
+/// Decrypts data that the given agent encrypted for this agent. Returns None if the
+/// data has not been encrypted by that agent or is not valid UTF-8.
+fn decrypt_from(
+    sender: &AgentPubKey,
+    encrypted_data: XSalsa20Poly1305EncryptedData,
+) -> ExternResult<Option<String>> {
+    let Ok(data) = ed_25519_x_salsa20_poly1305_decrypt(
+        agent_info()?.agent_initial_pubkey,
+        sender.clone(),
+        encrypted_data,
+    ) else {
+        return Ok(None);
+    };
+    Ok(String::from_utf8(data.as_ref().to_vec()).ok())
+}
+
+/// Whether the claimed from_agent of a signal is the agent that actually sent it
+fn is_from_sender(from_agent: &AgentPubKey) -> ExternResult<bool> {
+    Ok(&call_info()?.provenance == from_agent)
+}
+
+/// Whether the agent is a host or a moderator of the room
+fn is_moderating(agent: &AgentPubKey) -> ExternResult<bool> {
+    Ok(has_role(agent, RoomRole::Host)? || has_role(agent, RoomRole::Moderator)?)
+}
+
+/// Only members of the room that we haven't blocked may find out that we're online,
+/// and only if we're not in privacy mode. Note that agents that joined very recently
+/// may not be known as members yet.
+fn is_pong_allowed(from_agent: &AgentPubKey) -> ExternResult<bool> {
+    if get_privacy_mode(())? || is_blocked(from_agent)? {
+        return Ok(false);
+    }
+    Ok(get_all_agents(())?.contains(from_agent))
+}
+
+fn is_init_request_accepted(
+    connection_type: Option<&str>,
+    from_agent: &AgentPubKey,
+) -> ExternResult<bool> {
+    if is_blocked(from_agent)? || is_banned(from_agent)? {
+        return Ok(false);
+    }
+    let room_settings = get_latest_room_settings()?;
+    // Archived rooms are read-only, calls included
+    if room_settings.archived {
+        return Ok(false);
+    }
+    // Locked rooms only accept calls from agents that joined before the lock
+    if room_settings.locked {
+        let joined_before_lock = match (get_joined_at(from_agent)?, get_locked_since()?) {
+            (Some(joined_at), Some(locked_since)) => joined_at < locked_since,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if !joined_before_lock {
+            return Ok(false);
+        }
+    }
+    if !is_admitted(from_agent)? {
+        return Ok(false);
+    }
+    if connection_type_rejection(connection_type, from_agent, &room_settings)?.is_some() {
+        return Ok(false);
+    }
+    is_interpretation_request_accepted(connection_type)
+}
+
+/// Returns why the agent may not open connections of the given type in this room,
+/// if it may not. Permission overrides of the agent take precedence over the room
+/// settings.
+fn connection_type_rejection(
+    connection_type: Option<&str>,
+    agent: &AgentPubKey,
+    room_settings: &RoomSettings,
+) -> ExternResult<Option<&'static str>> {
+    let (capability, allowed_by_room) = match connection_type {
+        Some(SCREEN_SHARE_CONNECTION_TYPE) => (
+            Capability::ScreenShare,
+            room_settings.screen_share_enabled && !room_settings.audio_only,
+        ),
+        Some(VIDEO_CONNECTION_TYPE) => (Capability::Video, !room_settings.audio_only),
+        _ => return Ok(None),
+    };
+    if is_permitted(agent, capability, allowed_by_room)? {
+        return Ok(None);
+    }
+    Ok(Some(if room_settings.audio_only {
+        "This room is audio-only"
+    } else if capability == Capability::ScreenShare && !room_settings.screen_share_enabled {
+        "Screen sharing is disabled in this room"
+    } else {
+        "This agent is not allowed to open connections of this type"
+    }))
+}
+
+/// Listeners can only opt into interpretation channels that we actually interpret
+#[cfg(feature = "interpretation")]
+fn is_interpretation_request_accepted(connection_type: Option<&str>) -> ExternResult<bool> {
+    match connection_type.and_then(interpretation_language) {
+        Some(language) => is_registered_interpreter(language),
+        None => Ok(true),
     }
 }
 
+#[cfg(not(feature = "interpretation"))]
+fn is_interpretation_request_accepted(_connection_type: Option<&str>) -> ExternResult<bool> {
+    Ok(true)
+}
+
 /// Send a remote signal to the given users to check whether they are online
 /// After this ping is sent, a pong is expected as soon as the agents receive the signal
 /// NOTE: The pong to this ping is automatically emitted in the backend, independent
 /// of whether the UI for that cell is currently running
+/// The ping is dispatched in chunks of PING_CHUNK_SIZE agents so that a failure to
+/// reach some agents doesn't prevent the others from being pinged
 #[hdk_extern]
-pub fn ping(agents_pub_keys: Vec<AgentPubKey>) -> ExternResult<()> {
+pub fn ping(agents_pub_keys: Vec<AgentPubKey>) -> ExternResult<Vec<PingDispatchResult>> {
     let signal_payload = SignalPayload::Ping {
         from_agent: agent_info()?.agent_initial_pubkey,
     };
 
-    let encoded_signal = ExternIO::encode(signal_payload)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+    let encoded_signal = encode_signal(signal_payload)?;
 
-    send_remote_signal(encoded_signal, agents_pub_keys)
+    let mut results = Vec::new();
+    for chunk in agents_pub_keys.chunks(PING_CHUNK_SIZE) {
+        let error = send_remote_signal(encoded_signal.clone(), chunk.to_vec())
+            .err()
+            .map(|err| err.to_string());
+        results.extend(chunk.iter().map(|agent| PingDispatchResult {
+            agent: agent.clone(),
+            error: error.clone(),
+        }));
+    }
+    Ok(results)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PingDispatchResult {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    /// Why the ping could not be dispatched to the agent, if it couldn't
+    pub error: Option<String>,
 }
 
 fn pong(from_agent: AgentPubKey) -> ExternResult<()> {
@@ -71,8 +914,7 @@ fn pong(from_agent: AgentPubKey) -> ExternResult<()> {
         from_agent: agent_info()?.agent_initial_pubkey,
     };
 
-    let encoded_signal = ExternIO::encode(signal_payload)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+    let encoded_signal = encode_signal(signal_payload)?;
 
     send_remote_signal(encoded_signal, vec![from_agent])
 }
@@ -85,8 +927,7 @@ pub fn ping_ui(agents_pub_keys: Vec<AgentPubKey>) -> ExternResult<()> {
         from_agent: agent_info()?.agent_initial_pubkey,
     };
 
-    let encoded_signal = ExternIO::encode(signal_payload)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+    let encoded_signal = encode_signal(signal_payload)?;
 
     send_remote_signal(encoded_signal, agents_pub_keys)
 }
@@ -98,71 +939,572 @@ pub fn pong_ui(agent_pub_key: AgentPubKey) -> ExternResult<()> {
         from_agent: agent_info()?.agent_initial_pubkey,
     };
 
-    let encoded_signal = ExternIO::encode(signal_payload)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+    let encoded_signal = encode_signal(signal_payload)?;
 
     send_remote_signal(encoded_signal, vec![agent_pub_key])
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct InitRequestInput {
     pub connection_type: Option<String>, // e.g. "screen" for screen sharing
     pub connection_id: String,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
     pub to_agent: AgentPubKey,
+    /// Set to trace the handshake of this connection on both peers
+    #[serde(default)]
+    pub trace_id: Option<String>,
 }
 
 #[hdk_extern]
 pub fn send_init_request(input: InitRequestInput) -> ExternResult<()> {
+    ensure_not_archived()?;
+    if !is_admitted(&agent_info()?.agent_initial_pubkey)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "You have to be admitted to the call first"
+        ))));
+    }
+    if let Some(reason) = connection_type_rejection(
+        input.connection_type.as_deref(),
+        &agent_info()?.agent_initial_pubkey,
+        &get_latest_room_settings()?,
+    )? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(reason))));
+    }
+
+    #[cfg(feature = "interpretation")]
+    if let Some(language) = input
+        .connection_type
+        .as_deref()
+        .and_then(interpretation_language)
+    {
+        if language.is_empty() {
+            return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Interpretation channels must specify a language"
+            ))));
+        }
+    }
+
+    if !bind_connection(input.connection_id.clone(), input.to_agent.clone())? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Connection id is already used for a connection to another agent"
+        ))));
+    }
+
     let signal_payload = SignalPayload::InitRequest {
         from_agent: agent_info()?.agent_initial_pubkey,
-        connection_id: input.connection_id,
+        connection_id: input.connection_id.clone(),
         connection_type: input.connection_type,
+        trace_id: input.trace_id.clone(),
     };
 
-    let encoded_signal = ExternIO::encode(signal_payload)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+    let encoded_signal = encode_signal(signal_payload)?;
 
-    send_remote_signal(encoded_signal, vec![input.to_agent])
+    send_remote_signal(encoded_signal, vec![input.to_agent.clone()])?;
+    record_signal_trace(
+        input.trace_id.as_deref(),
+        SignalTraceEvent::InitRequest,
+        &input.connection_id,
+        &input.to_agent,
+        true,
+    )
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct InitAcceptInput {
     pub connection_type: Option<String>, // e.g. "screen" for screen sharing
     pub connection_id: String,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
     pub to_agent: AgentPubKey,
+    /// The trace id of the InitRequest that is being accepted, if any
+    #[serde(default)]
+    pub trace_id: Option<String>,
 }
 
 #[hdk_extern]
 pub fn send_init_accept(input: InitAcceptInput) -> ExternResult<()> {
     let signal_payload = SignalPayload::InitAccept {
         from_agent: agent_info()?.agent_initial_pubkey,
-        connection_id: input.connection_id,
+        connection_id: input.connection_id.clone(),
         connection_type: input.connection_type,
+        trace_id: input.trace_id.clone(),
     };
 
-    let encoded_signal = ExternIO::encode(signal_payload)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+    let encoded_signal = encode_signal(signal_payload)?;
 
-    send_remote_signal(encoded_signal, vec![input.to_agent])
+    send_remote_signal(encoded_signal, vec![input.to_agent.clone()])?;
+    record_signal_trace(
+        input.trace_id.as_deref(),
+        SignalTraceEvent::InitAccept,
+        &input.connection_id,
+        &input.to_agent,
+        true,
+    )
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SdpDataInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
     pub to_agent: AgentPubKey,
     pub connection_id: String,
     pub data: String,
+    /// The trace id of the handshake this data belongs to, if any
+    #[serde(default)]
+    pub trace_id: Option<String>,
 }
 
+/// Sends the data encrypted for the recipient, which receives it as SdpData
 #[hdk_extern]
 pub fn send_sdp_data(input: SdpDataInput) -> ExternResult<()> {
-    let signal_payload = SignalPayload::SdpData {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let encrypted_data = ed_25519_x_salsa20_poly1305_encrypt(
+        my_pub_key.clone(),
+        input.to_agent.clone(),
+        XSalsa20Poly1305Data::from(input.data.into_bytes()),
+    )?;
+    let signal_payload = SignalPayload::EncryptedSdpData {
+        from_agent: my_pub_key,
+        connection_id: input.connection_id.clone(),
+        encrypted_data,
+        trace_id: input.trace_id.clone(),
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.to_agent.clone()])?;
+    record_signal_trace(
+        input.trace_id.as_deref(),
+        SignalTraceEvent::SdpData,
+        &input.connection_id,
+        &input.to_agent,
+        true,
+    )
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RejectInitInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_agent: AgentPubKey,
+    pub connection_id: String,
+    pub reason: Option<String>, // e.g. "busy", "do_not_disturb" or "no_camera"
+}
+
+/// Declines an InitRequest, so that its sender doesn't wait for an InitAccept that
+/// never comes
+#[hdk_extern]
+pub fn send_reject_init(input: RejectInitInput) -> ExternResult<()> {
+    let signal_payload = SignalPayload::RejectInit {
         from_agent: agent_info()?.agent_initial_pubkey,
         connection_id: input.connection_id,
-        data: input.data,
+        reason: input.reason,
     };
 
-    let encoded_signal = ExternIO::encode(signal_payload)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+    let encoded_signal = encode_signal(signal_payload)?;
 
     send_remote_signal(encoded_signal, vec![input.to_agent])
 }
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CloseConnectionInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_agent: AgentPubKey,
+    pub connection_id: String,
+    pub reason: Option<String>, // e.g. "left" or "screen_share_stopped"
+}
+
+/// Tells the peer of the connection that this agent has torn it down
+#[hdk_extern]
+pub fn send_close_connection(input: CloseConnectionInput) -> ExternResult<()> {
+    let signal_payload = SignalPayload::CloseConnection {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        connection_id: input.connection_id,
+        reason: input.reason,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.to_agent])
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RenegotiationNeededInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_agent: AgentPubKey,
+    pub connection_id: String,
+}
+
+/// Asks the peer of the connection to renegotiate it, so that tracks can be added
+/// or removed without setting up a new connection
+#[hdk_extern]
+pub fn send_renegotiation_needed(input: RenegotiationNeededInput) -> ExternResult<()> {
+    let signal_payload = SignalPayload::RenegotiationNeeded {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        connection_id: input.connection_id,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.to_agent])
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConnectionStatsInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_agent: AgentPubKey,
+    pub connection_id: String,
+    pub rtt_ms: u32,
+    /// Fraction of packets lost, from 0 to 1
+    pub packet_loss: f32,
+    /// In milliseconds
+    pub jitter: f32,
+}
+
+/// Sends the quality of the connection as measured by this agent to its peer
+#[hdk_extern]
+pub fn report_connection_stats(input: ConnectionStatsInput) -> ExternResult<()> {
+    if !(0.0..=1.0).contains(&input.packet_loss) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Packet loss must be between 0 and 1"
+        ))));
+    }
+    if !input.jitter.is_finite() || input.jitter < 0.0 {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Jitter must not be negative"
+        ))));
+    }
+    let signal_payload = SignalPayload::ConnectionStats {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        connection_id: input.connection_id,
+        rtt_ms: input.rtt_ms,
+        packet_loss: input.packet_loss,
+        jitter: input.jitter,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.to_agent])
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RequestQualityChangeInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_agent: AgentPubKey,
+    pub connection_id: String,
+    /// In kbit/s
+    pub max_bitrate: Option<u32>,
+    /// Height of the video in pixels, e.g. 360
+    pub max_resolution: Option<u32>,
+}
+
+/// Asks the peer of the connection to lower the quality of what it sends, e.g.
+/// because this agent is on a weak connection, instead of letting the call freeze
+#[hdk_extern]
+pub fn send_quality_change_request(input: RequestQualityChangeInput) -> ExternResult<()> {
+    let signal_payload = SignalPayload::RequestQualityChange {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        connection_id: input.connection_id,
+        max_bitrate: input.max_bitrate,
+        max_resolution: input.max_resolution,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.to_agent])
+}
+
+/// An encoding of a stream that a peer can choose to receive instead of the others
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SimulcastLayer {
+    /// The RTP stream id that identifies the layer, e.g. "q", "h" or "f"
+    pub rid: String,
+    /// In kbit/s
+    pub max_bitrate: u32,
+    /// Height of the video in pixels, e.g. 180
+    pub resolution: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SimulcastLayersInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_agent: AgentPubKey,
+    pub connection_id: String,
+    pub layers: Vec<SimulcastLayer>,
+}
+
+/// Tells the peer of the connection which simulcast layers this agent sends, so
+/// that it can pick one with send_simulcast_layer_request
+#[hdk_extern]
+pub fn advertise_simulcast_layers(input: SimulcastLayersInput) -> ExternResult<()> {
+    if input.layers.len() > MAX_SIMULCAST_LAYERS {
+        return Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "A stream must not have more than {MAX_SIMULCAST_LAYERS} simulcast layers"
+        ))));
+    }
+    for (i, layer) in input.layers.iter().enumerate() {
+        if layer.rid.is_empty() || input.layers[..i].iter().any(|l| l.rid == layer.rid) {
+            return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Every simulcast layer must have a distinct, non-empty rid"
+            ))));
+        }
+    }
+    let signal_payload = SignalPayload::SimulcastLayers {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        connection_id: input.connection_id,
+        layers: input.layers,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.to_agent])
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RequestSimulcastLayerInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_agent: AgentPubKey,
+    pub connection_id: String,
+    pub rid: String,
+}
+
+/// Asks the peer of the connection to forward only the simulcast layer with the
+/// given rid, so that large rooms can show non-active speakers as thumbnails
+#[hdk_extern]
+pub fn send_simulcast_layer_request(input: RequestSimulcastLayerInput) -> ExternResult<()> {
+    let signal_payload = SignalPayload::RequestSimulcastLayer {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        connection_id: input.connection_id,
+        rid: input.rid,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.to_agent])
+}
+
+/// Discreetly alerts the hosts of the room that this agent needs help. Fails if
+/// the room has no other hosts to alert.
+#[hdk_extern]
+pub fn send_emergency(note: Option<String>) -> ExternResult<()> {
+    if note
+        .as_ref()
+        .is_some_and(|note| note.len() > MAX_EMERGENCY_NOTE_LENGTH)
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "The note must not be longer than {MAX_EMERGENCY_NOTE_LENGTH} bytes"
+        ))));
+    }
+    let hosts = get_other_hosts()?;
+    if hosts.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "There are no hosts to alert"
+        ))));
+    }
+    let signal_payload = SignalPayload::Emergency {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        note,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, hosts)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SendReactionInput {
+    pub reaction: String,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub to_agents: Vec<AgentPubKey>,
+}
+
+/// Send a reaction to the given agents. Fails if the reaction is not part of the
+/// reaction palette configured in the room settings.
+#[hdk_extern]
+pub fn send_reaction(input: SendReactionInput) -> ExternResult<()> {
+    if !get_latest_room_settings()?.is_reaction_allowed(&input.reaction) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Reaction is not part of this room's reaction palette"
+        ))));
+    }
+
+    let signal_payload = SignalPayload::Reaction {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        reaction: input.reaction,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, input.to_agents)
+}
+
+#[hdk_extern]
+pub fn get_zome_api_version(_: ()) -> ExternResult<u32> {
+    Ok(ZOME_API_VERSION)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SendClientHelloInput {
+    /// Version of the UI
+    pub app_version: String,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub to_agents: Vec<AgentPubKey>,
+}
+
+/// To be sent on first contact with other agents, so that their UI can ask the
+/// user to update if the versions are incompatible
+#[hdk_extern]
+pub fn send_client_hello(input: SendClientHelloInput) -> ExternResult<()> {
+    let signal_payload = SignalPayload::ClientHello {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        app_version: input.app_version,
+        zome_api_version: ZOME_API_VERSION,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, input.to_agents)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KickAgentInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub reason: Option<String>,
+    /// The participants that remain in the call. They rotate their media keys, so
+    /// that the kicked agent can't decrypt their media anymore.
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub remaining_participants: Vec<AgentPubKey>,
+}
+
+/// Removes the agent from the call. Only hosts and moderators may do so, and the
+/// kicked agent checks this as well before its UI leaves the call.
+#[hdk_extern]
+pub fn kick_agent(input: KickAgentInput) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !is_moderating(&my_pub_key)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only hosts and moderators can kick participants"
+        ))));
+    }
+
+    log_moderation_action(
+        ModerationActionKind::Kick,
+        input.agent.clone(),
+        input.reason.clone(),
+        None,
+    )?;
+
+    let signal_payload = SignalPayload::Kick {
+        from_agent: my_pub_key.clone(),
+        reason: input.reason,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.agent.clone()])?;
+
+    let remaining_participants: Vec<AgentPubKey> = input
+        .remaining_participants
+        .into_iter()
+        .filter(|agent| agent != &input.agent)
+        .collect();
+    if remaining_participants.is_empty() {
+        return Ok(());
+    }
+    rotate_media_keys(remaining_participants.clone())?;
+    let signal_payload = SignalPayload::RotateMediaKeys {
+        from_agent: my_pub_key.clone(),
+        departed_agent: input.agent,
+        participants: remaining_participants.clone(),
+    };
+    let encoded_signal = encode_signal(signal_payload)?;
+    let recipients = remaining_participants
+        .into_iter()
+        .filter(|agent| agent != &my_pub_key)
+        .collect();
+    send_remote_signal(encoded_signal, recipients)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MuteAgentInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    /// Whether to mute the agent right away instead of asking it to mute itself
+    pub force: bool,
+}
+
+/// Asks the agent to mute its microphone, or mutes it if force is set. Only hosts
+/// and moderators may do so, which the muted agent checks as well.
+#[hdk_extern]
+pub fn mute_agent(input: MuteAgentInput) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !is_moderating(&my_pub_key)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only hosts and moderators can mute participants"
+        ))));
+    }
+
+    if input.force {
+        log_moderation_action(
+            ModerationActionKind::ForceMute,
+            input.agent.clone(),
+            None,
+            None,
+        )?;
+    }
+
+    let signal_payload = if input.force {
+        SignalPayload::ForceMute {
+            from_agent: my_pub_key,
+        }
+    } else {
+        SignalPayload::MuteRequest {
+            from_agent: my_pub_key,
+        }
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![input.agent])
+}
+
+/// Ends the call for all agents of the room and persists the end of the call
+/// session. Only hosts may do so, which the receiving agents check as well.
+#[hdk_extern]
+pub fn end_call_for_all(_: ()) -> ExternResult<ActionHash> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !has_role(&my_pub_key, RoomRole::Host)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only hosts can end the call for everyone"
+        ))));
+    }
+    let call_session_end_hash = create_call_session_end()?;
+
+    let signal_payload = SignalPayload::EndCallForAll {
+        from_agent: my_pub_key.clone(),
+        call_session_end_hash: call_session_end_hash.clone(),
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    let agents: Vec<AgentPubKey> = get_all_agents(())?
+        .into_iter()
+        .filter(|agent| agent != &my_pub_key)
+        .collect();
+    send_remote_signal(encoded_signal, agents)?;
+    Ok(call_session_end_hash)
+}
@@ -0,0 +1,246 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::host_transfer::get_current_host;
+use crate::moderation_log::log_moderation_action;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RoleInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub role: RoomRole,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RoleHolder {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub role: RoomRole,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub granted_by: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub role_assignment_hash: ActionHash,
+}
+
+/// Grants the role to the agent. Only the progenitor of the room and moderators
+/// may do so.
+#[hdk_extern]
+pub fn assign_role(input: RoleInput) -> ExternResult<ActionHash> {
+    if get_roles_for_agent(input.agent.clone())?.contains(&input.role) {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "The agent already holds this role"
+        ))));
+    }
+    let granted_via = get_moderator_proof("assign roles")?;
+    let agent = input.agent.clone();
+    let action_hash = create_entry(EntryTypes::RoleAssignment(RoleAssignment {
+        agent: input.agent,
+        role: input.role.clone(),
+        granted_via,
+        attended: Vec::new(),
+    }))?;
+    let path = Path::from(ROLE_ASSIGNMENTS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllRoleAssignments,
+        (),
+    )?;
+    create_link(
+        agent.clone(),
+        action_hash.clone(),
+        LinkTypes::AgentToRoleAssignments,
+        (),
+    )?;
+    log_moderation_action(
+        ModerationActionKind::AssignRole { role: input.role },
+        agent,
+        None,
+        Some(action_hash.clone()),
+    )?;
+    Ok(action_hash)
+}
+
+/// Revokes the role from the agent. Roles can be revoked by whoever granted them,
+/// by the progenitor, and given up by the agents holding them.
+#[hdk_extern]
+pub fn revoke_role(input: RoleInput) -> ExternResult<()> {
+    let assignment_hashes: Vec<ActionHash> = get_role_holders_for_agent(input.agent.clone())?
+        .into_iter()
+        .filter(|holder| holder.role == input.role)
+        .map(|holder| holder.role_assignment_hash)
+        .collect();
+    if assignment_hashes.is_empty() {
+        return Ok(());
+    }
+
+    let links = get_links(
+        GetLinksInputBuilder::try_new(input.agent.clone(), LinkTypes::AgentToRoleAssignments)?
+            .build(),
+    )?
+    .into_iter()
+    .chain(get_links(
+        GetLinksInputBuilder::try_new(
+            Path::from(ROLE_ASSIGNMENTS).path_entry_hash()?,
+            LinkTypes::AllRoleAssignments,
+        )?
+        .build(),
+    )?);
+    for link in links {
+        if let Some(target) = link.target.into_action_hash() {
+            if assignment_hashes.contains(&target) {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+    for assignment_hash in assignment_hashes {
+        let delete_hash = delete_entry(assignment_hash)?;
+        log_moderation_action(
+            ModerationActionKind::RevokeRole {
+                role: input.role.clone(),
+            },
+            input.agent.clone(),
+            None,
+            Some(delete_hash),
+        )?;
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_my_roles(_: ()) -> ExternResult<Vec<RoomRole>> {
+    get_roles_for_agent(agent_info()?.agent_initial_pubkey)
+}
+
+/// Returns all role assignments of the room that haven't been revoked
+#[hdk_extern]
+pub fn get_room_roles(_: ()) -> ExternResult<Vec<RoleHolder>> {
+    let path = Path::from(ROLE_ASSIGNMENTS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllRoleAssignments)?
+            .build(),
+    )?;
+    get_role_holders_for_links(links)
+}
+
+/// Returns the roles the agent holds. The progenitor and the agent the hosting has
+/// last been transferred to implicitly hold all roles.
+pub fn get_roles_for_agent(agent: AgentPubKey) -> ExternResult<Vec<RoomRole>> {
+    if room_progenitor()?.as_ref() == Some(&agent) {
+        return Ok(vec![
+            RoomRole::Host,
+            RoomRole::Moderator,
+            RoomRole::Contributor,
+        ]);
+    }
+    if get_current_host()?.is_some_and(|(host, _)| host == agent) {
+        return Ok(vec![
+            RoomRole::Host,
+            RoomRole::Moderator,
+            RoomRole::Contributor,
+        ]);
+    }
+    let mut roles: Vec<RoomRole> = Vec::new();
+    for holder in get_role_holders_for_agent(agent)? {
+        if !roles.contains(&holder.role) {
+            roles.push(holder.role);
+        }
+    }
+    Ok(roles)
+}
+
+/// Whether the agent holds the role. Nobody holds roles in rooms without a
+/// progenitor.
+pub fn has_role(agent: &AgentPubKey, role: RoomRole) -> ExternResult<bool> {
+    Ok(get_roles_for_agent(agent.clone())?.contains(&role))
+}
+
+/// Returns the agents that host the room: the progenitor, the agent the hosting has
+/// last been transferred to and the holders of a Host role assignment
+pub fn get_room_hosts() -> ExternResult<Vec<AgentPubKey>> {
+    let mut hosts: Vec<AgentPubKey> = get_room_roles(())?
+        .into_iter()
+        .filter(|holder| holder.role == RoomRole::Host)
+        .map(|holder| holder.agent)
+        .collect();
+    hosts.extend(room_progenitor()?);
+    hosts.extend(get_current_host()?.map(|(host, _)| host));
+    hosts.sort();
+    hosts.dedup();
+    Ok(hosts)
+}
+
+/// Returns the agents that host the room, except for this agent
+pub fn get_other_hosts() -> ExternResult<Vec<AgentPubKey>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let mut hosts = get_room_hosts()?;
+    hosts.retain(|host| host != &my_pub_key);
+    Ok(hosts)
+}
+
+/// Returns the Moderator role assignment of this agent that entries requiring
+/// moderator privileges have to reference, or None if this agent is the progenitor
+pub fn get_moderator_proof(privilege: &str) -> ExternResult<Option<ActionHash>> {
+    get_role_proof(&[RoomRole::Moderator], privilege)
+}
+
+/// Same as get_moderator_proof but returns a role assignment of any of the given
+/// roles. The current host proves its roles with the HostTransfer to it.
+pub fn get_role_proof(
+    accepted_roles: &[RoomRole],
+    privilege: &str,
+) -> ExternResult<Option<ActionHash>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if let Some((host, host_transfer_hash)) = get_current_host()? {
+        if host == my_pub_key {
+            return Ok(Some(host_transfer_hash));
+        }
+    }
+    match room_progenitor()? {
+        Some(progenitor) if progenitor == my_pub_key => Ok(None),
+        None => Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "Nobody can {privilege} in rooms without a progenitor"
+        )))),
+        Some(_) => get_role_holders_for_agent(my_pub_key)?
+            .into_iter()
+            .find(|holder| accepted_roles.contains(&holder.role))
+            .map(|holder| Some(holder.role_assignment_hash))
+            .ok_or(wasm_error!(WasmErrorInner::Guest(format!(
+                "Only the progenitor and agents with the required role can {privilege}"
+            )))),
+    }
+}
+
+fn get_role_holders_for_agent(agent: AgentPubKey) -> ExternResult<Vec<RoleHolder>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(agent, LinkTypes::AgentToRoleAssignments)?.build(),
+    )?;
+    get_role_holders_for_links(links)
+}
+
+fn get_role_holders_for_links(links: Vec<Link>) -> ExternResult<Vec<RoleHolder>> {
+    let mut holders = Vec::new();
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get(action_hash.clone(), GetOptions::default())? else {
+            continue;
+        };
+        if let Some(role_assignment) = record
+            .entry()
+            .to_app_option::<RoleAssignment>()
+            .map_err(|e| wasm_error!(e))?
+        {
+            holders.push(RoleHolder {
+                agent: role_assignment.agent,
+                role: role_assignment.role,
+                granted_by: record.action().author().clone(),
+                role_assignment_hash: action_hash,
+            });
+        }
+    }
+    Ok(holders)
+}
@@ -1,46 +1,89 @@
 use hdk::prelude::*;
 use room_integrity::*;
 
+use crate::all_agents::get_all_agents;
+use crate::role_assignment::get_moderator_proof;
+use crate::room_settings::get_latest_room_settings;
+use crate::updatable_entry::*;
+
+impl UpdatableEntry for RoomInfo {
+    const UPDATES_LINK_TYPE: LinkTypes = LinkTypes::RoomInfoUpdates;
+
+    fn into_entry_types(self) -> EntryTypes {
+        EntryTypes::RoomInfo(self)
+    }
+}
+
+impl SingletonEntry for RoomInfo {
+    const ANCHOR: &'static str = ROOM_INFO;
+}
+
 // If this function returns None, it means that we haven't synced up yet
 #[hdk_extern]
 pub fn get_room_info(_: ()) -> ExternResult<Option<Record>> {
-    let path = Path::from(ROOM_INFO);
-
-    let links = get_links(
-        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::RoomInfoUpdates)?.build(),
-    )?;
-
-    let latest_room_info_link = links
-        .into_iter()
-        .max_by(|link_a, link_b| link_b.timestamp.cmp(&link_a.timestamp));
-
-    match latest_room_info_link {
-        None => Ok(None),
-        Some(link) => {
-            let record = get(
-                // ActionHash::from(link.target),
-                ActionHash::try_from(link.target)
-                    .map_err(|e| wasm_error!(WasmErrorInner::from(e)))?,
-                GetOptions::default(),
-            )?;
-
-            Ok(record)
-        }
-    }
+    get_latest_singleton::<RoomInfo>()
 }
 
+/// Changes the name, icon and metadata of the room. Only the progenitor of the room
+/// and moderators may do so, or anyone in rooms without a progenitor.
 #[hdk_extern]
 pub fn set_room_info(room_info: RoomInfo) -> ExternResult<()> {
-    let path = Path::from(ROOM_INFO);
+    let granted_via = match room_progenitor()? {
+        Some(_) => get_moderator_proof("change the room info")?,
+        None => None,
+    };
+    set_singleton(RoomInfo {
+        granted_via,
+        ..room_info
+    })?;
+    Ok(())
+}
 
-    let action_hash = create_entry(EntryTypes::RoomInfo(room_info))?;
+/// Returns the structured metadata of the latest RoomInfo, parsing legacy meta_data
+/// strings if the RoomInfo was written by an older client
+#[hdk_extern]
+pub fn get_room_metadata(_: ()) -> ExternResult<Option<RoomMetadata>> {
+    let Some(record) = get_room_info(())? else {
+        return Ok(None);
+    };
+    let room_info = record
+        .entry()
+        .to_app_option::<RoomInfo>()
+        .map_err(|e| wasm_error!(e))?;
+    Ok(room_info.and_then(|room_info| room_info.room_metadata()))
+}
 
-    create_link(
-        path.path_entry_hash()?,
-        action_hash,
-        LinkTypes::RoomInfoUpdates,
-        (),
-    )?;
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RoomPreview {
+    /// None if the RoomInfo hasn't been synced yet
+    pub name: Option<String>,
+    pub icon_src: Option<String>,
+    pub member_count: u32,
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub next_meeting_at: Option<Timestamp>,
+}
 
-    Ok(())
+/// Returns what invite landing pages may show about the room. Deliberately limited
+/// to information that is safe to show to agents that haven't been admitted yet,
+/// so no attachments, chat messages or member identities.
+#[hdk_extern]
+pub fn get_public_room_preview(_: ()) -> ExternResult<RoomPreview> {
+    let room_info = match get_room_info(())? {
+        Some(record) => record
+            .entry()
+            .to_app_option::<RoomInfo>()
+            .map_err(|e| wasm_error!(e))?,
+        None => None,
+    };
+    let (name, icon_src) = match room_info {
+        Some(room_info) => (Some(room_info.name), room_info.icon_src),
+        None => (None, None),
+    };
+    Ok(RoomPreview {
+        name,
+        icon_src,
+        member_count: get_all_agents(())?.len() as u32,
+        next_meeting_at: get_latest_room_settings()?.next_meeting_at,
+    })
 }
@@ -0,0 +1,124 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::resolve_latest;
+use crate::role_assignment::get_role_proof;
+use crate::updatable_entry::*;
+
+impl UpdatableEntry for RoomSettings {
+    const UPDATES_LINK_TYPE: LinkTypes = LinkTypes::RoomSettingsUpdates;
+
+    fn into_entry_types(self) -> EntryTypes {
+        EntryTypes::RoomSettings(self)
+    }
+}
+
+impl SingletonEntry for RoomSettings {
+    const ANCHOR: &'static str = ROOM_SETTINGS;
+}
+
+// If this function returns None, it means that no settings have been set yet or
+// that we haven't synced up yet
+#[hdk_extern]
+pub fn get_room_settings(_: ()) -> ExternResult<Option<Record>> {
+    get_latest_singleton::<RoomSettings>()
+}
+
+/// Changes the settings of the room. Only the progenitor of the room, hosts and
+/// moderators may do so, or anyone in rooms without a progenitor.
+#[hdk_extern]
+pub fn set_room_settings(room_settings: RoomSettings) -> ExternResult<()> {
+    let granted_via = match room_progenitor()? {
+        Some(_) => get_role_proof(
+            &[RoomRole::Host, RoomRole::Moderator],
+            "change the room settings",
+        )?,
+        None => None,
+    };
+    set_singleton(RoomSettings {
+        granted_via,
+        ..room_settings
+    })?;
+    Ok(())
+}
+
+/// Returns the latest room settings or the default settings if none have been set yet
+pub fn get_latest_room_settings() -> ExternResult<RoomSettings> {
+    let path = Path::from(ROOM_SETTINGS);
+    Ok(resolve_latest::<RoomSettings>(
+        path.path_entry_hash()?,
+        LinkTypes::RoomSettingsUpdates,
+        GetOptions::default(),
+    )?
+    .unwrap_or_default())
+}
+
+/// Same as get_latest_room_settings but only looks at what this agent has stored
+/// locally, so that it can be called for every incoming signal without going to
+/// the network. Falls back to the default settings if none are stored locally.
+pub fn get_local_room_settings() -> ExternResult<RoomSettings> {
+    let path = Path::from(ROOM_SETTINGS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::RoomSettingsUpdates)?
+            .get_options(GetStrategy::Local)
+            .build(),
+    )?;
+    let Some(link) = links.into_iter().max_by_key(|link| link.timestamp) else {
+        return Ok(RoomSettings::default());
+    };
+    let Some(action_hash) = link.target.into_action_hash() else {
+        return Ok(RoomSettings::default());
+    };
+    let Some(record) = get(action_hash, GetOptions::local())? else {
+        return Ok(RoomSettings::default());
+    };
+    Ok(record
+        .entry()
+        .to_app_option::<RoomSettings>()
+        .map_err(|e| wasm_error!(e))?
+        .unwrap_or_default())
+}
+
+/// Fails if the room has been archived. To be called by all zome functions that
+/// write to the room, except for the room settings themselves so that an archive
+/// can be reopened.
+pub fn ensure_not_archived() -> ExternResult<()> {
+    if get_latest_room_settings()?.archived {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "This room has been archived and is read-only"
+        ))));
+    }
+    Ok(())
+}
+
+/// Returns when the room has been locked, i.e. the time of the oldest revision of
+/// the room settings since which it has stayed locked, or None if it is unlocked
+pub fn get_locked_since() -> ExternResult<Option<Timestamp>> {
+    let path = Path::from(ROOM_SETTINGS);
+    let mut links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::RoomSettingsUpdates)?
+            .build(),
+    )?;
+    links.sort_by_key(|link| std::cmp::Reverse(link.timestamp));
+    let mut locked_since = None;
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get(action_hash, GetOptions::default())? else {
+            continue;
+        };
+        let Some(room_settings) = record
+            .entry()
+            .to_app_option::<RoomSettings>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        if !room_settings.locked {
+            break;
+        }
+        locked_since = Some(record.action().timestamp());
+    }
+    Ok(locked_since)
+}
@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_agents::get_all_agents;
+use crate::event_subscription::{notify_event_subscribers, RoomEvent};
+use crate::helper::get_original_action_hash;
+use crate::remote_signals::{encode_signal, SignalPayload};
+
+/// Interval in which this agent's scheduled attachments are checked for having been
+/// published while any of them is still pending
+pub const PUBLICATION_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Makes sure that the members of the room get notified once the attachment gets
+/// published, if it is scheduled for later
+pub fn schedule_publication(attachment: &Attachment) -> ExternResult<()> {
+    if attachment.is_published_at(sys_time()?) {
+        return Ok(());
+    }
+    schedule("publish_scheduled_attachments")
+}
+
+/// Sends an AttachmentPublished signal to the members of the room for each of this
+/// agent's attachments that got published since the previous check. Notifications
+/// are best effort: they get lost if the conductor is offline at publication time,
+/// in which case the attachment only shows up in get_all_attachments.
+#[hdk_extern(infallible)]
+pub fn publish_scheduled_attachments(_: Option<Schedule>) -> Option<Schedule> {
+    match notify_published_attachments() {
+        Ok(false) => None,
+        Ok(true) => Some(Schedule::Ephemeral(PUBLICATION_CHECK_INTERVAL)),
+        Err(err) => {
+            error!("Failed to notify about published attachments: {err:?}");
+            Some(Schedule::Ephemeral(PUBLICATION_CHECK_INTERVAL))
+        }
+    }
+}
+
+/// Makes sure that the members of the room get notified once the announcement gets
+/// published, if it is scheduled for later
+pub fn schedule_announcement_publication(announcement: &Announcement) -> ExternResult<()> {
+    if announcement.is_published_at(sys_time()?) {
+        return Ok(());
+    }
+    schedule("publish_scheduled_announcements")
+}
+
+/// Sends an AnnouncementPublished signal to the members of the room for each of this
+/// agent's announcements that got published since the previous check. Notifications
+/// are best effort, like those of publish_scheduled_attachments.
+#[hdk_extern(infallible)]
+pub fn publish_scheduled_announcements(_: Option<Schedule>) -> Option<Schedule> {
+    match notify_published_announcements() {
+        Ok(false) => None,
+        Ok(true) => Some(Schedule::Ephemeral(PUBLICATION_CHECK_INTERVAL)),
+        Err(err) => {
+            error!("Failed to notify about published announcements: {err:?}");
+            Some(Schedule::Ephemeral(PUBLICATION_CHECK_INTERVAL))
+        }
+    }
+}
+
+/// Returns whether any attachments are still waiting to be published
+fn notify_published_attachments() -> ExternResult<bool> {
+    let (published, pending) =
+        get_newly_published::<Attachment>(UnitEntryTypes::Attachment, |attachment| {
+            attachment.publish_at
+        })?;
+    let recipients = get_other_agents()?;
+    if recipients.is_empty() {
+        return Ok(pending);
+    }
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    for action_hash in published {
+        let attachment_hash = get_original_action_hash(action_hash)?;
+        notify_event_subscribers(RoomEvent::AttachmentCreated {
+            attachment_hash: attachment_hash.clone(),
+        })?;
+        let signal_payload = SignalPayload::AttachmentPublished {
+            from_agent: my_pub_key.clone(),
+            attachment_hash,
+        };
+        let encoded_signal = encode_signal(signal_payload)?;
+        send_remote_signal(encoded_signal, recipients.clone())?;
+    }
+    Ok(pending)
+}
+
+/// Returns whether any announcements are still waiting to be published
+fn notify_published_announcements() -> ExternResult<bool> {
+    let (published, pending) =
+        get_newly_published::<Announcement>(UnitEntryTypes::Announcement, |announcement| {
+            announcement.publish_at
+        })?;
+    let recipients = get_other_agents()?;
+    if recipients.is_empty() {
+        return Ok(pending);
+    }
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    for announcement_hash in published {
+        let signal_payload = SignalPayload::AnnouncementPublished {
+            from_agent: my_pub_key.clone(),
+            announcement_hash,
+        };
+        let encoded_signal = encode_signal(signal_payload)?;
+        send_remote_signal(encoded_signal, recipients.clone())?;
+    }
+    Ok(pending)
+}
+
+/// Returns the actions of this agent's entries of the given type that got published
+/// since the previous check, and whether any of them are still waiting to be published
+fn get_newly_published<T>(
+    entry_type: UnitEntryTypes,
+    publish_at: fn(&T) -> Option<Timestamp>,
+) -> ExternResult<(Vec<ActionHash>, bool)>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    let now = sys_time()?;
+    let last_check =
+        Timestamp::from_micros(now.as_micros() - PUBLICATION_CHECK_INTERVAL.as_micros() as i64);
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(entry_type.try_into()?)
+            .include_entries(true),
+    )?;
+    let mut published = Vec::new();
+    let mut pending = false;
+    for record in records {
+        let Some(publish_at) = record
+            .entry()
+            .to_app_option::<T>()
+            .map_err(|e| wasm_error!(e))?
+            .and_then(|entry| publish_at(&entry))
+        else {
+            continue;
+        };
+        if publish_at > now {
+            pending = true;
+        } else if publish_at > last_check {
+            published.push(record.action_address().clone());
+        }
+    }
+    Ok((published, pending))
+}
+
+fn get_other_agents() -> ExternResult<Vec<AgentPubKey>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    Ok(get_all_agents(())?
+        .into_iter()
+        .filter(|agent| agent != &my_pub_key)
+        .collect())
+}
@@ -0,0 +1,168 @@
+//! JSON schema of the remote signal payloads and of the inputs and outputs of this
+//! zome's functions, for the UI to generate its TypeScript types from. Holo hashes
+//! and signatures are described as byte arrays, which is how they reach the UI.
+//!
+//! The schema can be exported at build time with
+//! `cargo run --example export_schema --features schema` or fetched from a running
+//! conductor via `get_signal_schema`.
+use hdk::prelude::*;
+use room_integrity::*;
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::RootSchema;
+
+use crate::access_log::RecordAttachmentAccessInput;
+use crate::activity_summary::ActivitySummary;
+use crate::admission::RespondToJoinRequestInput;
+use crate::agent_ban::{BanAgentInput, BannedAgent};
+use crate::all_agents::{AgentDetails, JoinRoomOutput};
+use crate::all_descendent_rooms::DeriveNetworkSeedInput;
+use crate::announcement::CreateAnnouncementInput;
+use crate::api_manifest::ApiManifest;
+use crate::attachment::UpdateAttachmentInput;
+use crate::attachment_feed::ExportAttachmentFeedInput;
+use crate::attendance::AttestAttendanceInput;
+use crate::connectivity::{ConnectivityCheckInput, ConnectivityReport};
+use crate::federated_rooms::FederatedRoomsOutput;
+use crate::minutes::{ConsentToCoSignMinutesInput, FinalizeMinutesInput};
+use crate::moderation_log::ModerationLogEntry;
+use crate::ownership_claim::OwnershipClaimStatus;
+use crate::permission_override::{ClearPermissionOverrideInput, SetPermissionOverrideInput};
+use crate::presence_timeline::{GetPresenceTimelineInput, PresenceInterval};
+use crate::reconnect::ReconnectRequestInput;
+use crate::remote_signals::*;
+use crate::role_assignment::{RoleHolder, RoleInput};
+use crate::room_info::RoomPreview;
+use crate::signal_trace::SignalTraceRecord;
+use crate::speaking_grant::{GrantSpeakingTurnInput, ParticipationSummary, SpeakerStatistics};
+use crate::storage_footprint::{AgentStorageFootprint, StorageFootprint};
+use crate::task::{TaskFilter, TaskWithHash, UpdateTaskInput};
+
+/// Returns a schema with SignalPayload at its root and all zome function input and
+/// output types in its definitions
+pub fn signal_schema() -> RootSchema {
+    let mut generator = SchemaGenerator::new(SchemaSettings::draft07());
+
+    generator.subschema_for::<Admission>();
+    generator.subschema_for::<AdmissionRequest>();
+    generator.subschema_for::<AgentBan>();
+    generator.subschema_for::<Announcement>();
+    generator.subschema_for::<Attachment>();
+    generator.subschema_for::<AttachmentAccess>();
+    generator.subschema_for::<AttendanceAttestation>();
+    generator.subschema_for::<CallHandOff>();
+    generator.subschema_for::<CallSessionEnd>();
+    generator.subschema_for::<Capability>();
+    generator.subschema_for::<CaptionPreference>();
+    generator.subschema_for::<ChatMessage>();
+    generator.subschema_for::<CheckIn>();
+    generator.subschema_for::<DebugLevel>();
+    generator.subschema_for::<DeprecationNotice>();
+    generator.subschema_for::<DescendentRoom>();
+    generator.subschema_for::<DirectoryListing>();
+    generator.subschema_for::<EventSubscription>();
+    generator.subschema_for::<HostTransfer>();
+    generator.subschema_for::<HostTransferConsent>();
+    generator.subschema_for::<InvitationProof>();
+    generator.subschema_for::<MediaKeyShare>();
+    generator.subschema_for::<MinutesCoSignerConsent>();
+    generator.subschema_for::<MinutesFinalized>();
+    generator.subschema_for::<ModerationAction>();
+    generator.subschema_for::<OwnershipClaim>();
+    generator.subschema_for::<PermissionOverride>();
+    generator.subschema_for::<PresenterState>();
+    generator.subschema_for::<PromptDeck>();
+    generator.subschema_for::<Question>();
+    generator.subschema_for::<RoomRole>();
+    generator.subschema_for::<RoomInfo>();
+    generator.subschema_for::<RoomSettings>();
+    generator.subschema_for::<SignalTrace>();
+    generator.subschema_for::<SpeakingGrant>();
+    generator.subschema_for::<Status>();
+    generator.subschema_for::<Task>();
+
+    generator.subschema_for::<ActivitySummary>();
+    generator.subschema_for::<AgentDetails>();
+    generator.subschema_for::<AgentStorageFootprint>();
+    generator.subschema_for::<ApiManifest>();
+    generator.subschema_for::<BanAgentInput>();
+    generator.subschema_for::<BannedAgent>();
+    generator.subschema_for::<ClearPermissionOverrideInput>();
+    generator.subschema_for::<CloseConnectionInput>();
+    generator.subschema_for::<ConnectionStatsInput>();
+    generator.subschema_for::<ConnectivityCheckInput>();
+    generator.subschema_for::<ConnectivityReport>();
+    generator.subschema_for::<CreateAnnouncementInput>();
+    generator.subschema_for::<DeriveNetworkSeedInput>();
+    generator.subschema_for::<ExportAttachmentFeedInput>();
+    generator.subschema_for::<FederatedRoomsOutput>();
+    generator.subschema_for::<FinalizeMinutesInput>();
+    generator.subschema_for::<ConsentToCoSignMinutesInput>();
+    generator.subschema_for::<GrantSpeakingTurnInput>();
+    generator.subschema_for::<GetPresenceTimelineInput>();
+    generator.subschema_for::<InitAcceptInput>();
+    generator.subschema_for::<InitRequestInput>();
+    generator.subschema_for::<JoinRoomOutput>();
+    generator.subschema_for::<KickAgentInput>();
+    generator.subschema_for::<ModerationLogEntry>();
+    generator.subschema_for::<MuteAgentInput>();
+    generator.subschema_for::<OwnershipClaimStatus>();
+    generator.subschema_for::<ParticipationSummary>();
+    generator.subschema_for::<PingDispatchResult>();
+    generator.subschema_for::<PresenceInterval>();
+    generator.subschema_for::<RecordAttachmentAccessInput>();
+    generator.subschema_for::<AttestAttendanceInput>();
+    generator.subschema_for::<RoleHolder>();
+    generator.subschema_for::<RoleInput>();
+    generator.subschema_for::<RoomPreview>();
+    generator.subschema_for::<SdpDataInput>();
+    generator.subschema_for::<ReconnectRequestInput>();
+    generator.subschema_for::<RejectInitInput>();
+    generator.subschema_for::<RenegotiationNeededInput>();
+    generator.subschema_for::<RequestQualityChangeInput>();
+    generator.subschema_for::<RequestSimulcastLayerInput>();
+    generator.subschema_for::<RespondToJoinRequestInput>();
+    generator.subschema_for::<SendClientHelloInput>();
+    generator.subschema_for::<SendReactionInput>();
+    generator.subschema_for::<SetPermissionOverrideInput>();
+    generator.subschema_for::<SignalTraceRecord>();
+    generator.subschema_for::<SimulcastLayer>();
+    generator.subschema_for::<SimulcastLayersInput>();
+    generator.subschema_for::<SpeakerStatistics>();
+    generator.subschema_for::<StorageFootprint>();
+    generator.subschema_for::<TaskFilter>();
+    generator.subschema_for::<TaskWithHash>();
+    generator.subschema_for::<UpdateAttachmentInput>();
+    generator.subschema_for::<UpdateTaskInput>();
+    #[cfg(feature = "breakouts")]
+    {
+        generator.subschema_for::<crate::breakout_assignment::GetBreakoutAssignmentInput>();
+        generator.subschema_for::<crate::breakout_groups::BreakoutGroups>();
+        generator.subschema_for::<crate::breakout_groups::GenerateBreakoutGroupsInput>();
+        generator.subschema_for::<crate::breakout_timer::StartBreakoutsInput>();
+    }
+    #[cfg(feature = "captions")]
+    generator.subschema_for::<crate::caption_preference::SetCaptionPreferenceInput>();
+    #[cfg(feature = "chat")]
+    {
+        generator.subschema_for::<crate::chat_message::CreateChatMessageInput>();
+        generator.subschema_for::<crate::chat_message::UpdateChatMessageInput>();
+    }
+    #[cfg(feature = "check-ins")]
+    generator.subschema_for::<crate::check_in::CheckInSummary>();
+    #[cfg(feature = "devices")]
+    {
+        generator.subschema_for::<crate::call_hand_off::HandOffInput>();
+        generator.subschema_for::<crate::device_binding::BindDeviceInput>();
+        generator.subschema_for::<crate::device_binding::Participant>();
+    }
+    #[cfg(feature = "questions")]
+    generator.subschema_for::<crate::question::QueuedQuestion>();
+
+    generator.into_root_schema_for::<SignalPayload>()
+}
+
+#[hdk_extern]
+pub fn get_signal_schema(_: ()) -> ExternResult<String> {
+    serde_json::to_string(&signal_schema())
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.to_string())))
+}
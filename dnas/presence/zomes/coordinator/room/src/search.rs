@@ -0,0 +1,89 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_attachments::get_all_attachments;
+use crate::helper::get_latest_link;
+
+/// Maximum number of records returned by a search
+pub const MAX_SEARCH_RESULTS: usize = 100;
+
+/// Returns the latest revisions of the chat messages and attachments of the room
+/// that contain all words of the query, ignoring case. Chat messages come first,
+/// newest first, followed by the attachments in their usual order.
+#[hdk_extern]
+pub fn search_room_content(query: String) -> ExternResult<Vec<Record>> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if terms.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut results = Vec::new();
+    #[cfg(feature = "chat")]
+    {
+        let path = Path::from(ALL_CHAT_MESSAGES);
+        let mut links = get_links(
+            GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllChatMessages)?
+                .build(),
+        )?;
+        links.sort_by(|link_a, link_b| link_b.timestamp.cmp(&link_a.timestamp));
+        for link in links {
+            let Some(original_hash) = link.target.into_action_hash() else {
+                continue;
+            };
+            let Some(record) =
+                get_latest_revision_local_first(original_hash, LinkTypes::ChatMessageUpdates)?
+            else {
+                continue;
+            };
+            let chat_message = record
+                .entry()
+                .to_app_option::<ChatMessage>()
+                .map_err(|e| wasm_error!(e))?;
+            if chat_message.is_some_and(|chat_message| matches_terms(&chat_message.content, &terms))
+            {
+                results.push(record);
+            }
+        }
+    }
+
+    for original_record in get_all_attachments(())? {
+        let Some(record) = get_latest_revision_local_first(
+            original_record.action_address().clone(),
+            LinkTypes::AttachmentUpdates,
+        )?
+        else {
+            continue;
+        };
+        let attachment = record
+            .entry()
+            .to_app_option::<Attachment>()
+            .map_err(|e| wasm_error!(e))?;
+        if attachment.is_some_and(|attachment| matches_terms(&attachment.wal, &terms)) {
+            results.push(record);
+        }
+    }
+
+    results.truncate(MAX_SEARCH_RESULTS);
+    Ok(results)
+}
+
+fn matches_terms(text: &str, terms: &[String]) -> bool {
+    let text = text.to_lowercase();
+    terms.iter().all(|term| text.contains(term.as_str()))
+}
+
+/// Looks the latest revision up in the local cache first, so that searching
+/// content that has been seen before doesn't hit the network
+fn get_latest_revision_local_first(
+    original_hash: ActionHash,
+    updates_link_type: LinkTypes,
+) -> ExternResult<Option<Record>> {
+    let latest_hash = match get_latest_link(original_hash.clone(), updates_link_type)? {
+        Some(link) => link.target.into_action_hash().unwrap_or(original_hash),
+        None => original_hash,
+    };
+    if let Some(record) = get(latest_hash.clone(), GetOptions::local())? {
+        return Ok(Some(record));
+    }
+    get(latest_hash, GetOptions::default())
+}
@@ -0,0 +1,105 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::debug_level::is_debug_enabled;
+use crate::remote_signals::{encode_signal, SignalPayload};
+
+/// Number of traces returned by get_recent_signal_traces
+pub const MAX_SIGNAL_TRACES: usize = 100;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SignalTraceRecord {
+    pub signal_trace: SignalTrace,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub timestamp: Timestamp,
+}
+
+/// Logs and records a signaling message sent to or received from the peer, if the
+/// message carries a trace id. Traces are only recorded while debugging is enabled,
+/// so that calls don't write to the source chain for every signaling message.
+pub fn record_signal_trace(
+    trace_id: Option<&str>,
+    event: SignalTraceEvent,
+    connection_id: &str,
+    peer: &AgentPubKey,
+    outgoing: bool,
+) -> ExternResult<()> {
+    let Some(trace_id) = trace_id else {
+        return Ok(());
+    };
+    if !is_debug_enabled(DebugLevel::Basic)? {
+        return Ok(());
+    }
+    debug!(
+        "[signaling] trace={} event={:?} direction={} connection={} peer={}",
+        trace_id,
+        event,
+        if outgoing { "out" } else { "in" },
+        connection_id,
+        peer
+    );
+    create_entry(EntryTypes::SignalTrace(SignalTrace {
+        trace_id: trace_id.into(),
+        connection_id: connection_id.into(),
+        peer: peer.clone(),
+        event,
+        outgoing,
+    }))?;
+    Ok(())
+}
+
+/// Records a traced signaling message received from the peer and acknowledges it,
+/// so that the peer's trace shows which messages arrived
+pub fn acknowledge_signal(
+    trace_id: Option<String>,
+    event: SignalTraceEvent,
+    connection_id: String,
+    peer: AgentPubKey,
+) -> ExternResult<()> {
+    let Some(trace_id) = trace_id else {
+        return Ok(());
+    };
+    record_signal_trace(Some(&trace_id), event, &connection_id, &peer, false)?;
+
+    let signal_payload = SignalPayload::SignalAck {
+        from_agent: agent_info()?.agent_initial_pubkey,
+        connection_id: connection_id.clone(),
+        trace_id: trace_id.clone(),
+        event,
+    };
+
+    let encoded_signal = encode_signal(signal_payload)?;
+
+    send_remote_signal(encoded_signal, vec![peer.clone()])?;
+    record_signal_trace(
+        Some(&trace_id),
+        SignalTraceEvent::Ack,
+        &connection_id,
+        &peer,
+        true,
+    )
+}
+
+/// Returns the latest MAX_SIGNAL_TRACES signaling messages this agent has traced,
+/// newest first
+#[hdk_extern]
+pub fn get_recent_signal_traces(_: ()) -> ExternResult<Vec<SignalTraceRecord>> {
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::SignalTrace.try_into()?)
+            .include_entries(true),
+    )?;
+    Ok(records
+        .into_iter()
+        .rev()
+        .filter_map(|record| {
+            let signal_trace = record.entry().to_app_option::<SignalTrace>().ok()??;
+            Some(SignalTraceRecord {
+                signal_trace,
+                timestamp: record.action().timestamp(),
+            })
+        })
+        .take(MAX_SIGNAL_TRACES)
+        .collect())
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::role_assignment::{get_role_proof, has_role};
+use crate::room_settings::ensure_not_archived;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GrantSpeakingTurnInput {
+    pub session: String,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub speaker: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub raised_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SpeakerStatistics {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub times_spoken: u32,
+    /// Average time between raising the hand and getting the floor
+    pub average_wait_secs: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ParticipationSummary {
+    pub session: String,
+    /// The agents that got the floor, the ones that spoke the least first
+    pub speakers: Vec<SpeakerStatistics>,
+}
+
+/// Records that this agent gives the floor to the speaker, who raised its hand at
+/// raised_at. Only hosts and moderators may do so.
+#[hdk_extern]
+pub fn grant_speaking_turn(input: GrantSpeakingTurnInput) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !has_role(&my_pub_key, RoomRole::Host)? && !has_role(&my_pub_key, RoomRole::Moderator)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only hosts and moderators can grant the floor"
+        ))));
+    }
+    let tag = speaking_session_tag(&input.session);
+    let action_hash = create_entry(EntryTypes::SpeakingGrant(SpeakingGrant {
+        session: input.session,
+        speaker: input.speaker,
+        raised_at: input.raised_at,
+        granted_via: get_role_proof(&[RoomRole::Host, RoomRole::Moderator], "grant the floor")?,
+    }))?;
+    let path = Path::from(SPEAKING_GRANTS);
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllSpeakingGrants,
+        tag,
+    )?;
+    Ok(action_hash)
+}
+
+/// Aggregates the speaking grants of a session per speaker, so that facilitators
+/// can see whether quieter members got their share of the floor
+#[hdk_extern]
+pub fn get_participation_summary(session: String) -> ExternResult<ParticipationSummary> {
+    let tag = speaking_session_tag(&session);
+    let path = Path::from(SPEAKING_GRANTS);
+    let mut links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllSpeakingGrants)?
+            .tag_prefix(tag.clone())
+            .build(),
+    )?;
+    // The tag is a prefix of the tags of longer session names
+    links.retain(|link| link.tag == tag);
+
+    // Times spoken and total wait in microseconds of each speaker
+    let mut statistics: BTreeMap<AgentPubKey, (u32, i64)> = BTreeMap::new();
+    for link in links {
+        let Ok(action_hash) = ActionHash::try_from(link.target) else {
+            continue;
+        };
+        let Some(record) = get(action_hash, GetOptions::default())? else {
+            continue;
+        };
+        let Some(speaking_grant) = record
+            .entry()
+            .to_app_option::<SpeakingGrant>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        let wait = record.action().timestamp().as_micros() - speaking_grant.raised_at.as_micros();
+        let (times_spoken, total_wait) = statistics.entry(speaking_grant.speaker).or_default();
+        *times_spoken += 1;
+        *total_wait += wait;
+    }
+
+    let mut speakers: Vec<SpeakerStatistics> = statistics
+        .into_iter()
+        .map(|(agent, (times_spoken, total_wait))| SpeakerStatistics {
+            agent,
+            times_spoken,
+            average_wait_secs: total_wait as f32 / times_spoken as f32 / 1_000_000.0,
+        })
+        .collect();
+    speakers.sort_by_key(|speaker| speaker.times_spoken);
+    Ok(ParticipationSummary { session, speakers })
+}
@@ -0,0 +1,114 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::all_agents::get_all_agents;
+use crate::role_assignment::has_role;
+use crate::room_settings::get_latest_room_settings;
+
+/// Byte sizes of the public entries an agent has authored in this room
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StorageFootprint {
+    pub attachments_bytes: u64,
+    pub chat_messages_bytes: u64,
+    pub other_bytes: u64,
+    pub total_bytes: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AgentStorageFootprint {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub footprint: StorageFootprint,
+}
+
+/// Returns the byte size of the public entries this agent has authored in this
+/// room. Private entries are left out as they don't take up space on the DHT.
+#[hdk_extern]
+pub fn get_my_storage_footprint(_: ()) -> ExternResult<StorageFootprint> {
+    let records = query(ChainQueryFilter::new().include_entries(true))?;
+    footprint_of_records(records.iter())
+}
+
+/// Returns the storage footprint of every member of the room, largest first. Only
+/// hosts may do so, as this fetches the source chains of all members from the DHT.
+#[hdk_extern]
+pub fn get_room_storage_footprint(_: ()) -> ExternResult<Vec<AgentStorageFootprint>> {
+    if !has_role(&agent_info()?.agent_initial_pubkey, RoomRole::Host)? {
+        return Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Only hosts can see the storage footprint of the room"
+        ))));
+    }
+    let mut footprints = Vec::new();
+    for agent in get_all_agents(())? {
+        let activity = get_agent_activity(
+            agent.clone(),
+            ChainQueryFilter::new(),
+            ActivityRequest::Full,
+        )?;
+        let get_input: Vec<GetInput> = activity
+            .valid_activity
+            .into_iter()
+            .map(|(_, action_hash)| GetInput::new(action_hash.into(), GetOptions::default()))
+            .collect();
+        let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+        footprints.push(AgentStorageFootprint {
+            agent,
+            footprint: footprint_of_records(records.iter().flatten())?,
+        });
+    }
+    footprints.sort_by(|a, b| b.footprint.total_bytes.cmp(&a.footprint.total_bytes));
+    Ok(footprints)
+}
+
+/// Fails if authoring the given entry would take this agent's storage footprint
+/// beyond the per-agent quota of the room, if it has one
+pub fn ensure_within_quota(entry: &Entry) -> ExternResult<()> {
+    let Some(quota) = get_latest_room_settings()?.authored_bytes_quota else {
+        return Ok(());
+    };
+    let footprint = get_my_storage_footprint(())?.total_bytes;
+    if footprint + entry_size(entry)? > quota {
+        return Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "This would exceed your storage quota of {quota} bytes in this room"
+        ))));
+    }
+    Ok(())
+}
+
+fn entry_size(entry: &Entry) -> ExternResult<u64> {
+    Ok(SerializedBytes::try_from(entry.clone())
+        .map_err(|e| wasm_error!(e))?
+        .bytes()
+        .len() as u64)
+}
+
+fn footprint_of_records<'a>(
+    records: impl Iterator<Item = &'a Record>,
+) -> ExternResult<StorageFootprint> {
+    let attachment_type: EntryType = UnitEntryTypes::Attachment.try_into()?;
+    let chat_message_type: EntryType = UnitEntryTypes::ChatMessage.try_into()?;
+    let mut footprint = StorageFootprint::default();
+    for record in records {
+        let Some(entry_type) = record.action().entry_type() else {
+            continue;
+        };
+        if *entry_type.visibility() != EntryVisibility::Public {
+            continue;
+        }
+        let Some(entry) = record.entry().as_option() else {
+            continue;
+        };
+        let bytes = entry_size(entry)?;
+        if entry_type == &attachment_type {
+            footprint.attachments_bytes += bytes;
+        } else if entry_type == &chat_message_type {
+            footprint.chat_messages_bytes += bytes;
+        } else {
+            footprint.other_bytes += bytes;
+        }
+        footprint.total_bytes += bytes;
+    }
+    Ok(footprint)
+}
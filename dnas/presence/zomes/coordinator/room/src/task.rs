@@ -0,0 +1,244 @@
+use std::time::Duration;
+
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::get_original_action_hash;
+use crate::mailbox::emit_or_queue;
+use crate::remote_signals::{encode_signal, SignalPayload};
+use crate::room_settings::ensure_not_archived;
+use crate::updatable_entry::*;
+
+impl UpdatableEntry for Task {
+    const UPDATES_LINK_TYPE: LinkTypes = LinkTypes::TaskUpdates;
+
+    fn into_entry_types(self) -> EntryTypes {
+        EntryTypes::Task(self)
+    }
+
+    fn index() -> Option<(Path, LinkTypes)> {
+        Some((Path::from(ALL_TASKS), LinkTypes::AllTasks))
+    }
+}
+
+#[hdk_extern]
+pub fn create_task(task: Task) -> ExternResult<Record> {
+    ensure_not_archived()?;
+    let assignee = task.assignee.clone();
+    schedule_due_check(&task)?;
+    let record = create_updatable(task)?;
+    if let Some(assignee) = assignee {
+        notify_assignee(record.action_address().clone(), assignee)?;
+    }
+    Ok(record)
+}
+#[hdk_extern]
+pub fn get_latest_task(original_task_hash: ActionHash) -> ExternResult<Option<Record>> {
+    get_latest_updatable::<Task>(original_task_hash)
+}
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UpdateTaskInput {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub original_task_hash: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub previous_task_hash: ActionHash,
+    pub updated_task: Task,
+}
+#[hdk_extern]
+pub fn update_task(input: UpdateTaskInput) -> ExternResult<Record> {
+    ensure_not_archived()?;
+    let previous_assignee = get(input.previous_task_hash.clone(), GetOptions::default())?
+        .and_then(|record| record.entry().to_app_option::<Task>().ok().flatten())
+        .and_then(|task| task.assignee);
+    let assignee = input.updated_task.assignee.clone();
+    schedule_due_check(&input.updated_task)?;
+    let record = update_updatable(
+        input.original_task_hash.clone(),
+        input.previous_task_hash,
+        input.updated_task,
+    )?;
+    if let Some(assignee) = assignee {
+        if previous_assignee.as_ref() != Some(&assignee) {
+            notify_assignee(input.original_task_hash, assignee)?;
+        }
+    }
+    Ok(record)
+}
+#[hdk_extern]
+pub fn delete_task(original_task_hash: ActionHash) -> ExternResult<ActionHash> {
+    ensure_not_archived()?;
+    delete_updatable::<Task>(original_task_hash)
+}
+
+/// Criteria a task must meet to be returned by get_tasks. Criteria that are not set
+/// match every task.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TaskFilter {
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub assignee: Option<AgentPubKey>,
+    #[serde(default)]
+    pub done: Option<bool>,
+    /// Only tasks that are due before this time
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub due_before: Option<Timestamp>,
+}
+
+impl TaskFilter {
+    fn matches(&self, task: &Task) -> bool {
+        if self.assignee.is_some() && task.assignee != self.assignee {
+            return false;
+        }
+        if self.done.is_some_and(|done| task.done != done) {
+            return false;
+        }
+        if let Some(due_before) = self.due_before {
+            if !task.due_at.is_some_and(|due_at| due_at < due_before) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TaskWithHash {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub original_task_hash: ActionHash,
+    /// The latest revision of the task
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub task_hash: ActionHash,
+    pub task: Task,
+}
+
+/// Returns the latest revision of each task of the room matching the filter, the
+/// ones due first at the top and tasks without a due date last
+#[hdk_extern]
+pub fn get_tasks(filter: TaskFilter) -> ExternResult<Vec<TaskWithHash>> {
+    let path = Path::from(ALL_TASKS);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllTasks)?.build(),
+    )?;
+    let mut tasks: Vec<TaskWithHash> = Vec::new();
+    for link in links {
+        let Some(original_task_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get_latest_updatable::<Task>(original_task_hash.clone())? else {
+            continue;
+        };
+        let Some(task) = record.entry().to_app_option::<Task>().ok().flatten() else {
+            continue;
+        };
+        if filter.matches(&task) {
+            tasks.push(TaskWithHash {
+                original_task_hash,
+                task_hash: record.action_address().clone(),
+                task,
+            });
+        }
+    }
+    tasks.sort_by_key(|task| (task.task.due_at.is_none(), task.task.due_at));
+    Ok(tasks)
+}
+
+/// Interval in which the tasks this agent has written are checked for having become
+/// due while any of them is still pending
+pub const DUE_TASK_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Sends a TaskAssigned signal to the assignee, unless this agent assigned the task
+/// to itself
+fn notify_assignee(task_hash: ActionHash, assignee: AgentPubKey) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if assignee == my_pub_key {
+        return Ok(());
+    }
+    let signal_payload = SignalPayload::TaskAssigned {
+        from_agent: my_pub_key,
+        task_hash,
+    };
+    let encoded_signal = encode_signal(signal_payload)?;
+    send_remote_signal(encoded_signal, vec![assignee])
+}
+
+/// Makes sure that the assignee gets notified once the task becomes due
+fn schedule_due_check(task: &Task) -> ExternResult<()> {
+    match task.due_at {
+        Some(due_at) if task.assignee.is_some() && !task.done && due_at > sys_time()? => {
+            schedule("notify_due_tasks")
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Sends a TaskDue signal to the assignee of each task that became due since the
+/// previous check and that is still open. Only the agent that wrote the latest
+/// revision of a task notifies about it. Like notifications about scheduled
+/// attachments, these are best effort and get lost if the conductor is offline when
+/// the task becomes due.
+#[hdk_extern(infallible)]
+pub fn notify_due_tasks(_: Option<Schedule>) -> Option<Schedule> {
+    match notify_assignees_of_due_tasks() {
+        Ok(false) => None,
+        Ok(true) => Some(Schedule::Ephemeral(DUE_TASK_CHECK_INTERVAL)),
+        Err(err) => {
+            error!("Failed to notify about due tasks: {err:?}");
+            Some(Schedule::Ephemeral(DUE_TASK_CHECK_INTERVAL))
+        }
+    }
+}
+
+/// Returns whether any tasks are still waiting to become due
+fn notify_assignees_of_due_tasks() -> ExternResult<bool> {
+    let now = sys_time()?;
+    let last_check =
+        Timestamp::from_micros(now.as_micros() - DUE_TASK_CHECK_INTERVAL.as_micros() as i64);
+    let records = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::Task.try_into()?)
+            .include_entries(true),
+    )?;
+
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let mut pending = false;
+    for record in records {
+        let Some(task) = record
+            .entry()
+            .to_app_option::<Task>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        let (Some(due_at), Some(assignee)) = (task.due_at, task.assignee) else {
+            continue;
+        };
+        if task.done || due_at <= last_check {
+            continue;
+        }
+        if due_at > now {
+            pending = true;
+            continue;
+        }
+        let task_hash = get_original_action_hash(record.action_address().clone())?;
+        let is_latest_revision = get_latest_updatable::<Task>(task_hash.clone())?
+            .is_some_and(|latest| latest.action_address() == record.action_address());
+        if !is_latest_revision {
+            continue;
+        }
+        let signal_payload = SignalPayload::TaskDue {
+            from_agent: my_pub_key.clone(),
+            task_hash,
+        };
+        if assignee == my_pub_key {
+            emit_or_queue(signal_payload)?;
+        } else {
+            let encoded_signal = encode_signal(signal_payload)?;
+            send_remote_signal(encoded_signal, vec![assignee])?;
+        }
+    }
+    Ok(pending)
+}
@@ -0,0 +1,193 @@
+use hdk::prelude::*;
+use room_integrity::*;
+
+use crate::helper::{get_latest_link, resolve_latest_record};
+
+/// An entry type whose revisions are tracked via links of UPDATES_LINK_TYPE from
+/// the original action to each updating action
+pub trait UpdatableEntry: TryFrom<SerializedBytes, Error = SerializedBytesError> {
+    const UPDATES_LINK_TYPE: LinkTypes;
+
+    fn into_entry_types(self) -> EntryTypes;
+
+    /// Anchor and link type under which the original actions of all entries of this
+    /// type are indexed, if any
+    fn index() -> Option<(Path, LinkTypes)> {
+        None
+    }
+}
+
+/// An updatable entry type of which only a single instance exists per room. As
+/// entries of these types may not be updated, each revision is created anew and
+/// linked from ANCHOR instead of from an original action.
+pub trait SingletonEntry: UpdatableEntry {
+    const ANCHOR: &'static str;
+}
+
+pub fn create_updatable<T: UpdatableEntry>(entry: T) -> ExternResult<Record> {
+    let action_hash = create_entry(entry.into_entry_types())?;
+    let record = get(action_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly created entry"))
+    ))?;
+    if let Some((path, link_type)) = T::index() {
+        create_link(path.path_entry_hash()?, action_hash, link_type, ())?;
+    }
+    Ok(record)
+}
+
+pub fn update_updatable<T: UpdatableEntry>(
+    original_action_hash: ActionHash,
+    previous_action_hash: ActionHash,
+    updated_entry: T,
+) -> ExternResult<Record> {
+    let updated_action_hash = update_entry(previous_action_hash, updated_entry.into_entry_types())?;
+    create_link(
+        original_action_hash,
+        updated_action_hash.clone(),
+        T::UPDATES_LINK_TYPE,
+        (),
+    )?;
+    let record = get(updated_action_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly updated entry"))
+    ))?;
+    Ok(record)
+}
+
+/// Returns the latest revision of the entry, or the original if it has never been updated
+pub fn get_latest_updatable<T: UpdatableEntry>(
+    original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+    let latest_action_hash =
+        match get_latest_link(original_action_hash.clone(), T::UPDATES_LINK_TYPE)? {
+            Some(link) => {
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                        "No action hash associated with link"
+                    ))))?
+            }
+            None => original_action_hash,
+        };
+    get(latest_action_hash, GetOptions::default())
+}
+
+pub fn get_original_updatable(original_action_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let Some(details) = get_details(original_action_hash, GetOptions::default())? else {
+        return Ok(None);
+    };
+    match details {
+        Details::Record(details) => Ok(Some(details.record)),
+        _ => Err(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Malformed get details response"
+        )))),
+    }
+}
+
+/// Returns the original record followed by all of its revisions
+pub fn get_updatable_revisions<T: UpdatableEntry>(
+    original_action_hash: ActionHash,
+) -> ExternResult<Vec<Record>> {
+    let Some(original_record) = get_original_updatable(original_action_hash.clone())? else {
+        return Ok(vec![]);
+    };
+    let mut records = get_linked_records(original_action_hash, T::UPDATES_LINK_TYPE)?;
+    records.insert(0, original_record);
+    Ok(records)
+}
+
+/// Deletes the original action and removes it from the index of its type
+pub fn delete_updatable<T: UpdatableEntry>(
+    original_action_hash: ActionHash,
+) -> ExternResult<ActionHash> {
+    get_original_updatable(original_action_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Entry to delete not found"))
+    ))?;
+    if let Some((path, link_type)) = T::index() {
+        let links =
+            get_links(GetLinksInputBuilder::try_new(path.path_entry_hash()?, link_type)?.build())?;
+        for link in links {
+            if let Some(hash) = link.target.into_action_hash() {
+                if hash.eq(&original_action_hash) {
+                    delete_link(link.create_link_hash)?;
+                }
+            }
+        }
+    }
+    delete_entry(original_action_hash)
+}
+
+pub fn get_all_deletes_for_updatable(
+    original_action_hash: ActionHash,
+) -> ExternResult<Option<Vec<SignedActionHashed>>> {
+    let Some(details) = get_details(original_action_hash, GetOptions::default())? else {
+        return Ok(None);
+    };
+    match details {
+        Details::Entry(_) => Err(wasm_error!(WasmErrorInner::Guest(
+            "Malformed details".into()
+        ))),
+        Details::Record(record_details) => Ok(Some(record_details.deletes)),
+    }
+}
+
+pub fn get_oldest_delete_for_updatable(
+    original_action_hash: ActionHash,
+) -> ExternResult<Option<SignedActionHashed>> {
+    let Some(mut deletes) = get_all_deletes_for_updatable(original_action_hash)? else {
+        return Ok(None);
+    };
+    deletes.sort_by(|delete_a, delete_b| {
+        delete_a
+            .action()
+            .timestamp()
+            .cmp(&delete_b.action().timestamp())
+    });
+    Ok(deletes.first().cloned())
+}
+
+/// Creates a new revision of a singleton entry and links it from its anchor
+pub fn set_singleton<T: SingletonEntry>(entry: T) -> ExternResult<ActionHash> {
+    let path = Path::from(T::ANCHOR);
+    let action_hash = create_entry(entry.into_entry_types())?;
+    create_link(
+        path.path_entry_hash()?,
+        action_hash.clone(),
+        T::UPDATES_LINK_TYPE,
+        (),
+    )?;
+    Ok(action_hash)
+}
+
+// If this function returns None, it means that the entry hasn't been set yet or
+// that we haven't synced up yet
+pub fn get_latest_singleton<T: SingletonEntry>() -> ExternResult<Option<Record>> {
+    let path = Path::from(T::ANCHOR);
+    resolve_latest_record(
+        path.path_entry_hash()?,
+        T::UPDATES_LINK_TYPE,
+        GetOptions::default(),
+    )
+}
+
+fn get_linked_records(
+    base: impl Into<AnyLinkableHash>,
+    link_type: LinkTypes,
+) -> ExternResult<Vec<Record>> {
+    let links = get_links(GetLinksInputBuilder::try_new(base, link_type)?.build())?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                        "No action hash associated with link"
+                    ))))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(records.into_iter().flatten().collect())
+}
@@ -0,0 +1,145 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority, RoomRole};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AdmissionStatus {
+    Pending,
+    Admitted,
+    Denied,
+}
+
+/// Private record of a host or moderator about an agent asking to be admitted to
+/// the call. The latest AdmissionRequest per agent on the author's source chain is
+/// the one in effect.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AdmissionRequest {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub status: AdmissionStatus,
+}
+pub fn validate_create_admission_request(
+    _action: EntryCreationAction,
+    _admission_request: AdmissionRequest,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_admission_request(
+    _action: Update,
+    _admission_request: AdmissionRequest,
+    _original_action: EntryCreationAction,
+    _original_admission_request: AdmissionRequest,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an AdmissionRequest entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_admission_request(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_admission_request: AdmissionRequest,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AdmissionRequests cannot be deleted",
+    )))
+}
+
+/// Admits an agent to the calls of a room that has its waiting room enabled
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Admission {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    /// The Host or Moderator role assignment of the admitting agent. Not needed if
+    /// the progenitor admits the agent.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+pub fn validate_create_admission(
+    action: EntryCreationAction,
+    admission: Admission,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_role_authority(
+        &action,
+        admission.granted_via,
+        &[RoomRole::Host, RoomRole::Moderator],
+        "admit agents",
+    )
+}
+pub fn validate_update_admission(
+    _action: Update,
+    _admission: Admission,
+    _original_action: EntryCreationAction,
+    _original_admission: Admission,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an Admission entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_admission(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_admission: Admission,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the admitting agent can revoke an admission.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_agent_to_admissions(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let Ok(agent) = AgentPubKey::try_from(base_address) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToAdmissions link base is not an agent public key.".into(),
+        ));
+    };
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to Admission entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let admission: crate::Admission = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to an Admission entry"
+        ))))?;
+    if admission.agent != agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToAdmissions links must have the admitted agent as their base.".into(),
+        ));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Admissions can only be linked by the admitting agent.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_admissions(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the admitting agent can revoke an admission.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,111 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{room_progenitor, validate_moderator_authority};
+
+pub const BANNED_AGENTS: &str = "BANNED_AGENTS";
+pub const MAX_BAN_REASON_LENGTH: usize = 500;
+
+/// Bans an agent from the room
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AgentBan {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub reason: Option<String>,
+    /// The Moderator role assignment of the banning agent. Not needed if the
+    /// progenitor bans the agent.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+pub fn validate_create_agent_ban(
+    action: EntryCreationAction,
+    agent_ban: AgentBan,
+) -> ExternResult<ValidateCallbackResult> {
+    if &agent_ban.agent == action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Agents cannot ban themselves.".into(),
+        ));
+    }
+    if let Some(reason) = &agent_ban.reason {
+        if reason.len() > MAX_BAN_REASON_LENGTH {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "The reason of a ban must not be longer than {MAX_BAN_REASON_LENGTH} bytes."
+            )));
+        }
+    }
+    validate_moderator_authority(&action, agent_ban.granted_via, "ban agents")
+}
+pub fn validate_update_agent_ban(
+    _action: Update,
+    _agent_ban: AgentBan,
+    _original_action: EntryCreationAction,
+    _original_agent_ban: AgentBan,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an AgentBan entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_agent_ban(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_agent_ban: AgentBan,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_lifting(&action.author, original_action.author())
+}
+pub fn validate_create_link_banned_agents(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(BANNED_AGENTS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "BannedAgents links must have the BANNED_AGENTS anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to AgentBan entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _agent_ban: crate::AgentBan = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to an AgentBan entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Bans can only be linked by the agent that issued them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_banned_agents(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_lifting(&action.author, &original_action.author)
+}
+
+/// A ban can be lifted by the agent that issued it or by the progenitor
+fn validate_lifting(
+    lifter: &AgentPubKey,
+    issuer: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+    if lifter == issuer || room_progenitor()?.as_ref() == Some(lifter) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Only the issuing agent or the progenitor can lift a ban.".into(),
+    ))
+}
@@ -0,0 +1,43 @@
+use hdi::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Status {
+    #[default]
+    Available,
+    Away,
+    DoNotDisturb,
+}
+
+/// Private record of the author's status. The latest AgentStatus on the author's
+/// source chain is the one in effect.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct AgentStatus {
+    pub status: Status,
+}
+pub fn validate_create_agent_status(
+    _action: EntryCreationAction,
+    _agent_status: AgentStatus,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_agent_status(
+    _action: Update,
+    _agent_status: AgentStatus,
+    _original_action: EntryCreationAction,
+    _original_agent_status: AgentStatus,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an AgentStatus entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_agent_status(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_agent_status: AgentStatus,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AgentStatuses cannot be deleted",
+    )))
+}
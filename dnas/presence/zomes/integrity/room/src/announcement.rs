@@ -0,0 +1,158 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority, RoomRole};
+
+pub const ANNOUNCEMENTS: &str = "ANNOUNCEMENTS";
+
+/// Maximum length in bytes of the message of an announcement
+pub const MAX_ANNOUNCEMENT_LENGTH: usize = 2000;
+
+/// Notice published to all members of the room. Bilingual communities publish one
+/// announcement per locale, so that clients can show the one matching their user.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Announcement {
+    pub message: String,
+    /// BCP 47 language tag of the message, e.g. "en" or "de-CH". Announcements
+    /// without a locale are shown to everyone.
+    pub locale: Option<String>,
+    /// Time from which on the announcement is shown to everyone but its author.
+    /// Shown immediately if not set.
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub publish_at: Option<Timestamp>,
+    /// The Host, Moderator or Contributor role assignment of the author. Not needed
+    /// if the progenitor announces.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+impl Announcement {
+    pub fn is_published_at(&self, now: Timestamp) -> bool {
+        match self.publish_at {
+            Some(publish_at) => publish_at <= now,
+            None => true,
+        }
+    }
+}
+
+/// Whether an announcement in the given locale is meant for a reader of the
+/// requested locale. Announcements without a locale are meant for everyone, and an
+/// announcement in a language without a region, e.g. "de", also matches readers of
+/// any region of it, e.g. "de-CH".
+pub fn locale_matches(announcement_locale: Option<&str>, requested_locale: &str) -> bool {
+    let Some(announcement_locale) = announcement_locale else {
+        return true;
+    };
+    let announcement_locale = announcement_locale.to_ascii_lowercase();
+    let requested_locale = requested_locale.to_ascii_lowercase();
+    requested_locale == announcement_locale
+        || requested_locale.starts_with(&format!("{announcement_locale}-"))
+}
+
+pub fn validate_create_announcement(
+    action: EntryCreationAction,
+    announcement: Announcement,
+) -> ExternResult<ValidateCallbackResult> {
+    if announcement.message.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The message of an announcement must not be empty.".into(),
+        ));
+    }
+    if announcement.message.len() > MAX_ANNOUNCEMENT_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The message of an announcement must not be longer than {MAX_ANNOUNCEMENT_LENGTH} bytes."
+        )));
+    }
+    if let Some(locale) = &announcement.locale {
+        if locale.trim().is_empty() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The locale of an announcement must not be empty.".into(),
+            ));
+        }
+    }
+    validate_role_authority(
+        &action,
+        announcement.granted_via,
+        &[RoomRole::Host, RoomRole::Moderator, RoomRole::Contributor],
+        "publish announcements",
+    )
+}
+pub fn validate_update_announcement(
+    _action: Update,
+    _announcement: Announcement,
+    _original_action: EntryCreationAction,
+    _original_announcement: Announcement,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an Announcement entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_announcement(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_announcement: Announcement,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that published an announcement can withdraw it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_all_announcements(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(ANNOUNCEMENTS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllAnnouncements links must have the ANNOUNCEMENTS anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to Announcement entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let announcement: crate::Announcement = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to an Announcement entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Announcements can only be linked by the agent that published them.".into(),
+        ));
+    }
+    // The tag holds the locale so that announcements can be filtered without
+    // fetching them
+    let locale = announcement.locale.unwrap_or_default();
+    if tag.into_inner() != locale.into_bytes() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllAnnouncements links must have the locale of the announcement as their tag.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_announcements(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that published an announcement can withdraw it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -1,8 +1,31 @@
 use hdi::prelude::*;
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Attachment {
     pub wal: String,
+    /// Position of the attachment when presenting the attachments of the room.
+    /// Attachments without a sort key come last, in the order they were added.
+    #[serde(default)]
+    pub sort_key: Option<u32>,
+    /// Time from which on the attachment is shown to everyone but its author, e.g. to
+    /// pre-load workshop materials. Shown immediately if not set.
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub publish_at: Option<Timestamp>,
+    /// Whether members of the room are expected to record each access to the
+    /// attachment in their access log, e.g. for case files
+    #[serde(default)]
+    pub audited: bool,
+}
+
+impl Attachment {
+    pub fn is_published_at(&self, now: Timestamp) -> bool {
+        match self.publish_at {
+            Some(publish_at) => publish_at <= now,
+            None => true,
+        }
+    }
 }
 pub fn validate_create_attachment(
     _action: EntryCreationAction,
@@ -105,3 +128,43 @@ pub fn validate_delete_link_all_attachments(
     // TODO: add the appropriate validation rules
     Ok(ValidateCallbackResult::Valid)
 }
+pub const TRASHED_ATTACHMENTS: &str = "TRASHED_ATTACHMENTS";
+pub fn validate_create_link_trashed_attachments(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(TRASHED_ATTACHMENTS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "TrashedAttachments links must have the TrashedAttachments anchor as their base."
+                .into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "No action hash associated with link"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _attachment: crate::Attachment = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference an entry"
+        ))))?;
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Deleting a TrashedAttachments link restores the attachment
+pub fn validate_delete_link_trashed_attachments(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
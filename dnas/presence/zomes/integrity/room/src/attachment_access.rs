@@ -0,0 +1,46 @@
+use hdi::prelude::*;
+
+/// Private record of an access to an audited attachment. Agents record their own
+/// accesses, and hosts additionally record the accesses disclosed to them.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AttachmentAccess {
+    /// The original action of the attachment
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub attachment_hash: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub accessed_by: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub accessed_at: Timestamp,
+}
+pub fn validate_create_attachment_access(
+    action: EntryCreationAction,
+    attachment_access: AttachmentAccess,
+) -> ExternResult<ValidateCallbackResult> {
+    if attachment_access.accessed_at > *action.timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An access cannot be recorded before it happened.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_attachment_access(
+    _action: Update,
+    _attachment_access: AttachmentAccess,
+    _original_action: EntryCreationAction,
+    _original_attachment_access: AttachmentAccess,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an AttachmentAccess entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_attachment_access(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_attachment_access: AttachmentAccess,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AttachmentAccesses cannot be deleted",
+    )))
+}
@@ -0,0 +1,122 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority, RoomRole};
+
+/// Maximum number of agents a single attendance attestation may list
+pub const MAX_ATTESTED_AGENTS: usize = 500;
+
+/// Records that a host saw the given agents attend a call session of the room, so
+/// that they can earn a role through the attendance promotion rule of the room
+/// settings. Attestations are made by hosts, as agents could claim attendance of
+/// any session on their own.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AttendanceAttestation {
+    /// The CallSessionEnd that ended the attended session
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub session: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub agents: Vec<AgentPubKey>,
+    /// The Host role assignment of the author. Not needed if the progenitor attests
+    /// the attendance.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+pub fn validate_create_attendance_attestation(
+    action: EntryCreationAction,
+    attendance_attestation: AttendanceAttestation,
+) -> ExternResult<ValidateCallbackResult> {
+    if attendance_attestation.agents.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An attendance attestation must list at least one agent.".into(),
+        ));
+    }
+    if attendance_attestation.agents.len() > MAX_ATTESTED_AGENTS {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "An attendance attestation must not list more than {MAX_ATTESTED_AGENTS} agents."
+        )));
+    }
+    let record = must_get_valid_record(attendance_attestation.session)?;
+    let call_session_end: Option<crate::CallSessionEnd> =
+        record.entry().to_app_option().map_err(|e| wasm_error!(e))?;
+    if call_session_end.is_none() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The session of an attendance attestation must be a CallSessionEnd.".into(),
+        ));
+    }
+    validate_role_authority(
+        &action,
+        attendance_attestation.granted_via,
+        &[RoomRole::Host],
+        "attest attendance",
+    )
+}
+pub fn validate_update_attendance_attestation(
+    _action: Update,
+    _attendance_attestation: AttendanceAttestation,
+    _original_action: EntryCreationAction,
+    _original_attendance_attestation: AttendanceAttestation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an AttendanceAttestation entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_attendance_attestation(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_attendance_attestation: AttendanceAttestation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AttendanceAttestations cannot be deleted",
+    )))
+}
+pub fn validate_create_link_agent_to_attendance_attestations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let Ok(agent) = AgentPubKey::try_from(base_address) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToAttendanceAttestations link base is not an agent public key.".into(),
+        ));
+    };
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to AttendanceAttestation entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let attendance_attestation: crate::AttendanceAttestation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to an AttendanceAttestation entry"
+        ))))?;
+    if !attendance_attestation.agents.contains(&agent) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToAttendanceAttestations links must have an attested agent as their base.".into(),
+        ));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Attendance attestations can only be linked by the agent that attested them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_attendance_attestations(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AgentToAttendanceAttestations links cannot be deleted",
+    )))
+}
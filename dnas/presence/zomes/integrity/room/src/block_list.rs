@@ -0,0 +1,34 @@
+use hdi::prelude::*;
+
+/// Private list of agents the author doesn't want to interact with. The latest
+/// BlockList on the author's source chain is the one in effect.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct BlockList {
+    pub agents: Vec<AgentPubKey>,
+}
+pub fn validate_create_block_list(
+    _action: EntryCreationAction,
+    _block_list: BlockList,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_block_list(
+    _action: Update,
+    _block_list: BlockList,
+    _original_action: EntryCreationAction,
+    _original_block_list: BlockList,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a BlockList entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_block_list(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_block_list: BlockList,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "BlockLists cannot be deleted",
+    )))
+}
@@ -0,0 +1,145 @@
+use hdi::prelude::*;
+
+pub const BREAKOUT_ASSIGNMENTS: &str = "BREAKOUT_ASSIGNMENTS";
+
+/// Assigns an agent to the breakout room with the given network seed appendix
+/// for a given breakout round
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct BreakoutAssignment {
+    pub agent: AgentPubKey,
+    pub network_seed_appendix: String,
+    pub round: u32,
+    /// The seed generate_breakout_groups shuffled the roster with, so that the
+    /// groups of the round can be audited by shuffling the roster again
+    #[serde(default)]
+    pub seed: Option<Vec<u8>>,
+}
+
+/// The link tag used for links pointing to BreakoutAssignment entries in order
+/// to be able to filter them by round
+pub fn breakout_round_tag(round: u32) -> LinkTag {
+    LinkTag::new(round.to_be_bytes().to_vec())
+}
+
+pub fn validate_create_breakout_assignment(
+    _action: EntryCreationAction,
+    _breakout_assignment: BreakoutAssignment,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_breakout_assignment(
+    _action: Update,
+    _breakout_assignment: BreakoutAssignment,
+    _original_action: EntryCreationAction,
+    _original_breakout_assignment: BreakoutAssignment,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a BreakoutAssignment entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_breakout_assignment(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_breakout_assignment: BreakoutAssignment,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "BreakoutAssignments cannot be deleted",
+    )))
+}
+fn breakout_assignment_from_target(
+    target_address: AnyLinkableHash,
+) -> ExternResult<BreakoutAssignment> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to BreakoutAssignment entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a BreakoutAssignment entry"
+        ))))
+}
+pub fn validate_create_link_all_breakout_assignments(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(BREAKOUT_ASSIGNMENTS);
+    let base_entry_hash = match EntryHash::try_from(base_address) {
+        Ok(eh) => eh,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Base address of an AllBreakoutAssignments link must be an entry hash.".into(),
+            ))
+        }
+    };
+    if base_entry_hash != path.path_entry_hash()? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllBreakoutAssignments links must have the BREAKOUT_ASSIGNMENTS anchor as their base."
+                .into(),
+        ));
+    }
+    let breakout_assignment = breakout_assignment_from_target(target_address)?;
+    if tag != breakout_round_tag(breakout_assignment.round) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllBreakoutAssignments link tag must contain the round of the assignment.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_breakout_assignments(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AllBreakoutAssignments links cannot be deleted",
+    )))
+}
+pub fn validate_create_link_agent_to_breakout_assignments(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let agent = match AgentPubKey::try_from(base_address) {
+        Ok(pubkey) => pubkey,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "AgentToBreakoutAssignments link base is not an agent public key.".into(),
+            ))
+        }
+    };
+    let breakout_assignment = breakout_assignment_from_target(target_address)?;
+    if breakout_assignment.agent != agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToBreakoutAssignments links must have the assigned agent as their base.".into(),
+        ));
+    }
+    if tag != breakout_round_tag(breakout_assignment.round) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToBreakoutAssignments link tag must contain the round of the assignment.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_breakout_assignments(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AgentToBreakoutAssignments links cannot be deleted",
+    )))
+}
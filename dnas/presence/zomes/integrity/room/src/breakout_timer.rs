@@ -0,0 +1,35 @@
+use hdi::prelude::*;
+
+/// Private record of the breakout round the author has started and when it ends.
+/// The latest BreakoutTimer on the author's source chain is the one in effect.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct BreakoutTimer {
+    pub round: u32,
+    pub ends_at: Timestamp,
+}
+pub fn validate_create_breakout_timer(
+    _action: EntryCreationAction,
+    _breakout_timer: BreakoutTimer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_breakout_timer(
+    _action: Update,
+    _breakout_timer: BreakoutTimer,
+    _original_action: EntryCreationAction,
+    _original_breakout_timer: BreakoutTimer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a BreakoutTimer entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_breakout_timer(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_breakout_timer: BreakoutTimer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "BreakoutTimers cannot be deleted",
+    )))
+}
@@ -0,0 +1,33 @@
+use hdi::prelude::*;
+
+/// Private record of whether the author's device is currently active in a call
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct CallActivity {
+    pub active: bool,
+}
+pub fn validate_create_call_activity(
+    _action: EntryCreationAction,
+    _call_activity: CallActivity,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_call_activity(
+    _action: Update,
+    _call_activity: CallActivity,
+    _original_action: EntryCreationAction,
+    _original_call_activity: CallActivity,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a CallActivity entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_call_activity(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_call_activity: CallActivity,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CallActivities cannot be deleted",
+    )))
+}
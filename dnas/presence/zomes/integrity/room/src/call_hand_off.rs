@@ -0,0 +1,46 @@
+use hdi::prelude::*;
+
+/// Private record of a call that another device of the same person has handed off
+/// to the author's device, kept until the author's device joins the call
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CallHandOff {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub from_device: AgentPubKey,
+    /// Peers of the call that the author's device needs to connect to
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub peers: Vec<AgentPubKey>,
+    /// Connections of the handing-off device that the peers are going to close
+    pub connection_ids: Vec<String>,
+}
+pub fn validate_create_call_hand_off(
+    action: EntryCreationAction,
+    call_hand_off: CallHandOff,
+) -> ExternResult<ValidateCallbackResult> {
+    if &call_hand_off.from_device == action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A device cannot hand off a call to itself.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_call_hand_off(
+    _action: Update,
+    _call_hand_off: CallHandOff,
+    _original_action: EntryCreationAction,
+    _original_call_hand_off: CallHandOff,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a CallHandOff entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_call_hand_off(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_call_hand_off: CallHandOff,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CallHandOffs cannot be deleted",
+    )))
+}
@@ -0,0 +1,92 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority, RoomRole};
+
+pub const CALL_SESSION_ENDS: &str = "CALL_SESSION_ENDS";
+
+/// Marks the end of the call session of the room by a host. Clients that missed the
+/// EndCallForAll signal tear down connections to peers that joined before it.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CallSessionEnd {
+    /// The Host role assignment of the ending agent. Not needed if the progenitor
+    /// ends the call.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+pub fn validate_create_call_session_end(
+    action: EntryCreationAction,
+    call_session_end: CallSessionEnd,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_role_authority(
+        &action,
+        call_session_end.granted_via,
+        &[RoomRole::Host],
+        "end the call for everyone",
+    )
+}
+pub fn validate_update_call_session_end(
+    _action: Update,
+    _call_session_end: CallSessionEnd,
+    _original_action: EntryCreationAction,
+    _original_call_session_end: CallSessionEnd,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a CallSessionEnd entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_call_session_end(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_call_session_end: CallSessionEnd,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CallSessionEnds cannot be deleted",
+    )))
+}
+pub fn validate_create_link_all_call_session_ends(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(CALL_SESSION_ENDS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllCallSessionEnds links must have the CALL_SESSION_ENDS anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to CallSessionEnd entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _call_session_end: crate::CallSessionEnd = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a CallSessionEnd entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Call session ends can only be linked by the agent that ended the call.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_call_session_ends(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "AllCallSessionEnds links cannot be deleted.".into(),
+    ))
+}
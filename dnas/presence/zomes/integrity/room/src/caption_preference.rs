@@ -0,0 +1,88 @@
+use hdi::prelude::*;
+
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CaptionPreference {
+    pub needs_captions: bool,
+    /// Language of the captions, e.g. "en" or "de-CH"
+    pub language: Option<String>,
+}
+pub fn validate_create_caption_preference(
+    _action: EntryCreationAction,
+    caption_preference: CaptionPreference,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(language) = caption_preference.language {
+        if language.trim().is_empty() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The language of a CaptionPreference must not be empty.".into(),
+            ));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_caption_preference(
+    _action: Update,
+    _caption_preference: CaptionPreference,
+    _original_action: EntryCreationAction,
+    _original_caption_preference: CaptionPreference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a CaptionPreference entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_caption_preference(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_caption_preference: CaptionPreference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CaptionPreferences cannot be deleted",
+    )))
+}
+pub fn validate_create_link_agent_to_caption_preferences(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let base_pubkey = match AgentPubKey::try_from(base_address) {
+        Ok(pubkey) => pubkey,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "AgentToCaptionPreferences link base is not an agent public key.".into(),
+            ))
+        }
+    };
+    if action.author != base_pubkey {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToCaptionPreferences links can only be created for oneself.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to CaptionPreference entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _caption_preference: crate::CaptionPreference = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a CaptionPreference entry"
+        ))))?;
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_caption_preferences(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AgentToCaptionPreferences links cannot be deleted",
+    )))
+}
@@ -0,0 +1,220 @@
+use hdi::prelude::*;
+
+pub const ALL_CHAT_MESSAGES: &str = "ALL_CHAT_MESSAGES";
+
+/// Maximum length in bytes of the content of a chat message
+pub const MAX_CHAT_MESSAGE_LENGTH: usize = 4000;
+
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChatMessage {
+    pub content: String,
+    /// Chat message that this message replies to, starting a thread
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub reply_to: Option<ActionHash>,
+}
+pub fn validate_create_chat_message(
+    _action: EntryCreationAction,
+    chat_message: ChatMessage,
+) -> ExternResult<ValidateCallbackResult> {
+    if chat_message.content.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A chat message must not be empty.".into(),
+        ));
+    }
+    if chat_message.content.len() > MAX_CHAT_MESSAGE_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "A chat message must not be longer than {MAX_CHAT_MESSAGE_LENGTH} bytes."
+        )));
+    }
+    if let Some(reply_to) = chat_message.reply_to {
+        let record = must_get_valid_record(reply_to)?;
+        let replied_to: Option<crate::ChatMessage> =
+            record.entry().to_app_option().map_err(|e| wasm_error!(e))?;
+        if replied_to.is_none() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "A chat message can only reply to another chat message.".into(),
+            ));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_chat_message(
+    action: Update,
+    chat_message: ChatMessage,
+    original_action: EntryCreationAction,
+    original_chat_message: ChatMessage,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a chat message can edit it.".into(),
+        ));
+    }
+    if chat_message.reply_to != original_chat_message.reply_to {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Editing a chat message must not move it to another thread.".into(),
+        ));
+    }
+    validate_create_chat_message(EntryCreationAction::Update(action), chat_message)
+}
+pub fn validate_delete_chat_message(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_chat_message: ChatMessage,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a chat message can delete it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_all_chat_messages(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(ALL_CHAT_MESSAGES);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllChatMessages links must have the AllChatMessages anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "No action hash associated with link"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _chat_message: crate::ChatMessage = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference an entry"
+        ))))?;
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Links get deleted along with the chat message, by its author
+pub fn validate_delete_link_all_chat_messages(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a chat message can remove it from the chat.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_chat_message_updates(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = base_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "No action hash associated with link"
+        ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a chat message can link revisions to it.".into(),
+        ));
+    }
+    let _chat_message: crate::ChatMessage = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference an entry"
+        ))))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "No action hash associated with link"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _chat_message: crate::ChatMessage = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference an entry"
+        ))))?;
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_chat_message_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "ChatMessageUpdates links cannot be deleted",
+    )))
+}
+/// Links a chat message to each of the replies to it
+pub fn validate_create_link_chat_message_replies(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(base_action_hash) = base_address.into_action_hash() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "ChatMessageReplies links must have a chat message as their base.".into(),
+        ));
+    };
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "No action hash associated with link"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let reply: crate::ChatMessage = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference an entry"
+        ))))?;
+    if reply.reply_to != Some(base_action_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "ChatMessageReplies links must point to a reply to their base.".into(),
+        ));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a reply can add it to its thread.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Replies get removed from their thread when they are deleted, by their author
+pub fn validate_delete_link_chat_message_replies(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a reply can remove it from its thread.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
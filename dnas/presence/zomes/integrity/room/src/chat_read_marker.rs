@@ -0,0 +1,35 @@
+use hdi::prelude::*;
+
+/// Private record of how far the author has read the chat of the room. The latest
+/// ChatReadMarker on the author's source chain is the one in effect.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ChatReadMarker {
+    /// Chat messages sent up to and including this time have been read
+    pub read_up_to: Timestamp,
+}
+pub fn validate_create_chat_read_marker(
+    _action: EntryCreationAction,
+    _chat_read_marker: ChatReadMarker,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_chat_read_marker(
+    _action: Update,
+    _chat_read_marker: ChatReadMarker,
+    _original_action: EntryCreationAction,
+    _original_chat_read_marker: ChatReadMarker,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a ChatReadMarker entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_chat_read_marker(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_chat_read_marker: ChatReadMarker,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "ChatReadMarkers cannot be deleted",
+    )))
+}
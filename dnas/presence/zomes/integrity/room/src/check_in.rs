@@ -0,0 +1,144 @@
+use hdi::prelude::*;
+
+pub const CHECK_INS: &str = "CHECK_INS";
+
+/// Maximum length in bytes of the name of a check-in session
+pub const MAX_CHECK_IN_SESSION_LENGTH: usize = 100;
+/// Maximum length in bytes of the emoji of a check-in, leaving room for sequences
+/// like flags or skin tones
+pub const MAX_CHECK_IN_EMOJI_LENGTH: usize = 32;
+/// Maximum length in bytes of the word of a check-in
+pub const MAX_CHECK_IN_WORD_LENGTH: usize = 40;
+pub const MIN_CHECK_IN_SCORE: u8 = 1;
+pub const MAX_CHECK_IN_SCORE: u8 = 5;
+
+/// How an agent feels at the opening or closing round of a session, given as an
+/// emoji, a score from MIN_CHECK_IN_SCORE to MAX_CHECK_IN_SCORE or both
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CheckIn {
+    /// Name of the session chosen by its facilitator, e.g. "2024-05-14 opening"
+    pub session: String,
+    pub emoji: Option<String>,
+    pub score: Option<u8>,
+    /// A single word describing the agent's mood
+    pub word: Option<String>,
+}
+
+/// The link tag used for links pointing to CheckIn entries in order to be able to
+/// filter them by session
+pub fn check_in_session_tag(session: &str) -> LinkTag {
+    LinkTag::new(session.as_bytes().to_vec())
+}
+
+pub fn validate_create_check_in(
+    _action: EntryCreationAction,
+    check_in: CheckIn,
+) -> ExternResult<ValidateCallbackResult> {
+    if check_in.session.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The session of a check-in must not be empty.".into(),
+        ));
+    }
+    if check_in.session.len() > MAX_CHECK_IN_SESSION_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The session of a check-in must not be longer than {MAX_CHECK_IN_SESSION_LENGTH} bytes."
+        )));
+    }
+    if check_in.emoji.is_none() && check_in.score.is_none() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A check-in must contain an emoji or a score.".into(),
+        ));
+    }
+    if let Some(emoji) = &check_in.emoji {
+        if emoji.trim().is_empty() || emoji.len() > MAX_CHECK_IN_EMOJI_LENGTH {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "The emoji of a check-in must not be empty or longer than {MAX_CHECK_IN_EMOJI_LENGTH} bytes."
+            )));
+        }
+    }
+    if let Some(score) = check_in.score {
+        if !(MIN_CHECK_IN_SCORE..=MAX_CHECK_IN_SCORE).contains(&score) {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "The score of a check-in must be between {MIN_CHECK_IN_SCORE} and {MAX_CHECK_IN_SCORE}."
+            )));
+        }
+    }
+    if let Some(word) = &check_in.word {
+        if word.is_empty() || word.contains(char::is_whitespace) {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The word of a check-in must be a single word.".into(),
+            ));
+        }
+        if word.len() > MAX_CHECK_IN_WORD_LENGTH {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "The word of a check-in must not be longer than {MAX_CHECK_IN_WORD_LENGTH} bytes."
+            )));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_check_in(
+    _action: Update,
+    _check_in: CheckIn,
+    _original_action: EntryCreationAction,
+    _original_check_in: CheckIn,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a CheckIn entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_check_in(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_check_in: CheckIn,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CheckIns cannot be deleted",
+    )))
+}
+pub fn validate_create_link_all_check_ins(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(CHECK_INS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllCheckIns links must have the CHECK_INS anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to CheckIn entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let check_in: crate::CheckIn = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a CheckIn entry"
+        ))))?;
+    if tag != check_in_session_tag(&check_in.session) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllCheckIns link tag must contain the session of the check-in.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_check_ins(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AllCheckIns links cannot be deleted",
+    )))
+}
@@ -0,0 +1,40 @@
+use hdi::prelude::*;
+
+/// Private record binding a WebRTC connection id to the peer on the other end of
+/// the connection
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ConnectionBinding {
+    pub connection_id: String,
+    pub peer: AgentPubKey,
+}
+pub fn validate_create_connection_binding(
+    _action: EntryCreationAction,
+    connection_binding: ConnectionBinding,
+) -> ExternResult<ValidateCallbackResult> {
+    if connection_binding.connection_id.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The connection id of a ConnectionBinding must not be empty.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_connection_binding(
+    _action: Update,
+    _connection_binding: ConnectionBinding,
+    _original_action: EntryCreationAction,
+    _original_connection_binding: ConnectionBinding,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a ConnectionBinding entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_connection_binding(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_connection_binding: ConnectionBinding,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "ConnectionBindings cannot be deleted",
+    )))
+}
@@ -0,0 +1,46 @@
+use hdi::prelude::*;
+
+/// How much of the zome's activity is written to the conductor logs
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DebugLevel {
+    #[default]
+    Off,
+    /// Traced signaling messages
+    Basic,
+    /// Every received remote signal, including agent public keys
+    Verbose,
+}
+
+/// Private record of the debug level the author has chosen. The latest
+/// DebugLevelPreference on the author's source chain is the one in effect.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct DebugLevelPreference {
+    pub level: DebugLevel,
+}
+pub fn validate_create_debug_level_preference(
+    _action: EntryCreationAction,
+    _debug_level_preference: DebugLevelPreference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_debug_level_preference(
+    _action: Update,
+    _debug_level_preference: DebugLevelPreference,
+    _original_action: EntryCreationAction,
+    _original_debug_level_preference: DebugLevelPreference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a DebugLevelPreference entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_debug_level_preference(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_debug_level_preference: DebugLevelPreference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "DebugLevelPreferences cannot be deleted",
+    )))
+}
@@ -0,0 +1,129 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority, RoomRole};
+
+pub const DEPRECATION_NOTICE: &str = "DEPRECATION_NOTICE";
+
+/// Maximum length in bytes of the message of a deprecation notice
+pub const MAX_DEPRECATION_NOTICE_LENGTH: usize = 2000;
+
+/// Notice that this room is about to be abandoned, e.g. in favour of a room with a
+/// newer DNA version
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DeprecationNotice {
+    pub message: String,
+    /// Where the community moves to, e.g. the WAL or invite link of the new room
+    pub successor: Option<String>,
+    /// When the room is going to be abandoned
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub effective_at: Option<Timestamp>,
+    /// The Host role assignment of the author. Not needed if the progenitor writes
+    /// the notice.
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+    /// Whether this revision withdraws the previous notice, in which case the other
+    /// fields are ignored
+    #[serde(default)]
+    pub withdrawn: bool,
+}
+
+pub fn validate_create_deprecation_notice(
+    action: EntryCreationAction,
+    deprecation_notice: DeprecationNotice,
+) -> ExternResult<ValidateCallbackResult> {
+    if !deprecation_notice.withdrawn && deprecation_notice.message.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The message of a deprecation notice must not be empty.".into(),
+        ));
+    }
+    if deprecation_notice.message.len() > MAX_DEPRECATION_NOTICE_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The message of a deprecation notice must not be longer than {MAX_DEPRECATION_NOTICE_LENGTH} bytes."
+        )));
+    }
+    validate_role_authority(
+        &action,
+        deprecation_notice.granted_via,
+        &[RoomRole::Host],
+        "write the deprecation notice",
+    )
+}
+pub fn validate_update_deprecation_notice(
+    _action: Update,
+    _deprecation_notice: DeprecationNotice,
+    _original_action: EntryCreationAction,
+    _original_deprecation_notice: DeprecationNotice,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a DeprecationNotice entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_deprecation_notice(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_deprecation_notice: DeprecationNotice,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Deprecation Notices cannot be deleted",
+    )))
+}
+pub fn validate_create_link_deprecation_notice_updates(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(DEPRECATION_NOTICE);
+    let path_entry_hash = path.path_entry_hash()?;
+    let base_entry_hash = match EntryHash::try_from(base_address) {
+        Ok(eh) => eh,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Base address of a DeprecationNoticeUpdates link must be an entry hash.".into(),
+            ))
+        }
+    };
+    if base_entry_hash != path_entry_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "DeprecationNoticeUpdates links must have the DeprecationNotice anchor as their base."
+                .into(),
+        ));
+    }
+
+    let deprecation_notice_action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to DeprecationNotice entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(deprecation_notice_action_hash)?;
+    let _deprecation_notice: crate::DeprecationNotice = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a DeprecationNotice entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Deprecation notices can only be linked by the agent that wrote them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Deprecation notices are withdrawn with a new revision, which unlike deleting the
+/// links can be restricted to hosts
+pub fn validate_delete_link_deprecation_notice_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "DeprecationNoticeUpdates links cannot be deleted",
+    )))
+}
@@ -1,19 +1,50 @@
 use hdi::prelude::*;
 
+use crate::metadata::validate_meta_data;
+use crate::room_info::RoomMetadata;
+
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DescendentRoom {
     pub network_seed_appendix: String,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
     pub dna_hash: DnaHash,
     pub name: String,
     pub icon_src: Option<String>,
+    /// Metadata of the descendent room as encoded by encode_metadata
     pub meta_data: Option<String>,
 }
+/// Separates the parts of the network seed of a descendent room
+pub const NETWORK_SEED_SEPARATOR: char = '#';
+
+/// Derives the full network seed of a descendent room as
+/// `groupRoom#<parent network seed>#<network seed appendix>`. This is the scheme
+/// that clients have been deriving seeds with, so that existing descendent rooms
+/// keep their networks. As appendixes can't contain the separator, different
+/// appendixes of the same parent never result in the same seed.
+pub fn descendent_room_network_seed(
+    parent_network_seed: &str,
+    network_seed_appendix: &str,
+) -> String {
+    format!(
+        "groupRoom{NETWORK_SEED_SEPARATOR}{parent_network_seed}{NETWORK_SEED_SEPARATOR}{network_seed_appendix}"
+    )
+}
+
 pub fn validate_create_descendent_room(
     _action: EntryCreationAction,
-    _descendent_room: DescendentRoom,
+    descendent_room: DescendentRoom,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    let appendix = &descendent_room.network_seed_appendix;
+    if appendix.is_empty() || appendix.contains(NETWORK_SEED_SEPARATOR) {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The network seed appendix of a descendent room must not be empty or contain '{NETWORK_SEED_SEPARATOR}'."
+        )));
+    }
+    Ok(validate_meta_data::<RoomMetadata>(
+        descendent_room.meta_data.as_deref(),
+    ))
 }
 pub fn validate_update_descendent_room(
     _action: Update,
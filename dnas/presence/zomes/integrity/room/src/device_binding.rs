@@ -0,0 +1,112 @@
+use hdi::prelude::*;
+
+/// Binds an additional device key to the primary key of the same person. The binding
+/// is authored by the device and carries the primary key's signature over the
+/// device key, so that it can only be created with the consent of both keys.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct DeviceBinding {
+    pub primary_agent: AgentPubKey,
+    pub device_agent: AgentPubKey,
+    /// Signature of the primary_agent over the device_agent
+    pub signature: Signature,
+}
+pub fn validate_create_device_binding(
+    action: EntryCreationAction,
+    device_binding: DeviceBinding,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author() != &device_binding.device_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A DeviceBinding can only be created by the bound device.".into(),
+        ));
+    }
+    if device_binding.primary_agent == device_binding.device_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A device cannot be bound to itself.".into(),
+        ));
+    }
+    if !verify_signature(
+        device_binding.primary_agent,
+        device_binding.signature,
+        device_binding.device_agent,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "DeviceBinding signature of the primary agent is invalid.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_device_binding(
+    _action: Update,
+    _device_binding: DeviceBinding,
+    _original_action: EntryCreationAction,
+    _original_device_binding: DeviceBinding,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a DeviceBinding entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_device_binding(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_device_binding: DeviceBinding,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "DeviceBindings cannot be deleted",
+    )))
+}
+fn device_binding_from_target(target_address: AnyLinkableHash) -> ExternResult<DeviceBinding> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to DeviceBinding entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a DeviceBinding entry"
+        ))))
+}
+pub fn validate_create_link_agent_to_device_bindings(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let base_pubkey = match AgentPubKey::try_from(base_address) {
+        Ok(pubkey) => pubkey,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "AgentToDeviceBindings link base is not an agent public key.".into(),
+            ))
+        }
+    };
+    let device_binding = device_binding_from_target(target_address)?;
+    if base_pubkey != device_binding.primary_agent && base_pubkey != device_binding.device_agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToDeviceBindings link base must be one of the bound agents.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_device_bindings(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    // Either of the bound agents may dissolve the binding
+    let device_binding = device_binding_from_target(original_action.target_address)?;
+    if action.author != device_binding.primary_agent && action.author != device_binding.device_agent
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToDeviceBindings links can only be deleted by one of the bound agents.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,110 @@
+use hdi::prelude::*;
+
+use crate::dna_properties::{PrivacyLevel, RoomDnaProperties};
+use crate::{validate_room_metadata, RoomMetadata};
+
+pub const DIRECTORY_LISTING: &str = "DIRECTORY_LISTING";
+
+/// Maximum length in bytes of the name of a directory listing
+pub const MAX_DIRECTORY_LISTING_NAME_LENGTH: usize = 100;
+/// Maximum length in bytes of the description of a directory listing
+pub const MAX_DIRECTORY_LISTING_DESCRIPTION_LENGTH: usize = 2000;
+
+/// What a public directory may show about this room. Rooms without a directory
+/// listing are not meant to be indexed.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DirectoryListing {
+    pub name: String,
+    pub description: String,
+    pub icon_src: Option<String>,
+    pub metadata: RoomMetadata,
+    /// How to join the room, e.g. its WAL or an invite link
+    pub join_link: Option<String>,
+}
+
+pub fn validate_create_directory_listing(
+    _action: EntryCreationAction,
+    directory_listing: DirectoryListing,
+) -> ExternResult<ValidateCallbackResult> {
+    if directory_listing.name.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The name of a directory listing must not be empty.".into(),
+        ));
+    }
+    if directory_listing.name.len() > MAX_DIRECTORY_LISTING_NAME_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The name of a directory listing must not be longer than {MAX_DIRECTORY_LISTING_NAME_LENGTH} bytes."
+        )));
+    }
+    if directory_listing.description.len() > MAX_DIRECTORY_LISTING_DESCRIPTION_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The description of a directory listing must not be longer than {MAX_DIRECTORY_LISTING_DESCRIPTION_LENGTH} bytes."
+        )));
+    }
+    Ok(validate_room_metadata(&directory_listing.metadata))
+}
+pub fn validate_update_directory_listing(
+    _action: Update,
+    _directory_listing: DirectoryListing,
+    _original_action: EntryCreationAction,
+    _original_directory_listing: DirectoryListing,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a DirectoryListing entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_directory_listing(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_directory_listing: DirectoryListing,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Directory Listings cannot be deleted",
+    )))
+}
+pub fn validate_create_link_directory_listing_updates(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(DIRECTORY_LISTING);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "DirectoryListingUpdates links must have the DirectoryListing anchor as their base."
+                .into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to DirectoryListing entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _directory_listing: crate::DirectoryListing = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a DirectoryListing entry"
+        ))))?;
+    if RoomDnaProperties::get()?.privacy == PrivacyLevel::Private {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Private rooms cannot be listed in directories.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Deleting the links opts the room out of directories again
+pub fn validate_delete_link_directory_listing_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
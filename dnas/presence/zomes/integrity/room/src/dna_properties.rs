@@ -0,0 +1,56 @@
+use hdi::prelude::*;
+
+/// Whether a room may be found by agents outside of it
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PrivacyLevel {
+    /// The room may be listed in public directories
+    #[default]
+    Public,
+    /// The room must not be listed in public directories
+    Private,
+}
+
+/// Properties a room DNA may be installed with. Rooms installed without properties
+/// get the defaults: no progenitor, public, open to anyone and without a member
+/// limit.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct RoomDnaProperties {
+    /// Agent that may grant roles in this room without holding a role itself
+    #[cfg_attr(feature = "schema", schemars(with = "Option<String>"))]
+    pub progenitor: Option<AgentPubKeyB64>,
+    pub privacy: PrivacyLevel,
+    /// Hash of the code that agents have to present in their membrane proof to join
+    /// the room, as returned by invite_code_hash. Only the hash is stored so that the
+    /// code can't be read from the properties.
+    pub invite_code_hash: Option<String>,
+    /// Whether agents have to present an invitation signed by the progenitor to join
+    /// the room, unless they know its invite code
+    pub require_invitation: bool,
+    /// Number of agents up to which the room accepts new members. No limit if None.
+    pub max_members: Option<u32>,
+}
+
+impl RoomDnaProperties {
+    /// Returns the properties this room has been installed with
+    pub fn get() -> ExternResult<Self> {
+        Self::from_properties(dna_info()?.modifiers.properties)
+    }
+
+    /// Decodes the properties of a room. Only rooms installed without properties get
+    /// the defaults: properties that can't be decoded are an error, so that a typo
+    /// doesn't silently open up a room that was meant to be restricted.
+    pub fn from_properties(properties: SerializedBytes) -> ExternResult<Self> {
+        // Empty or msgpack nil
+        if properties.bytes().is_empty() || properties.bytes().as_slice() == [0xc0] {
+            return Ok(Self::default());
+        }
+        Self::try_from(properties).map_err(|e| wasm_error!(e))
+    }
+
+    pub fn progenitor_key(&self) -> Option<AgentPubKey> {
+        self.progenitor.clone().map(AgentPubKey::from)
+    }
+}
@@ -0,0 +1,109 @@
+use hdi::prelude::*;
+
+pub const EVENT_SUBSCRIPTIONS: &str = "EVENT_SUBSCRIPTIONS";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum EventKind {
+    AttachmentCreated,
+    /// An agent became active in the call of the room
+    CallStarted,
+}
+
+/// Designates an agent, typically a bot bridging the room to a chat service, to
+/// receive a RoomEvent remote signal whenever an event of the given kinds happens
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EventSubscription {
+    pub event_kinds: Vec<EventKind>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub delivery_agent: AgentPubKey,
+}
+pub fn validate_create_event_subscription(
+    _action: EntryCreationAction,
+    event_subscription: EventSubscription,
+) -> ExternResult<ValidateCallbackResult> {
+    if event_subscription.event_kinds.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An event subscription must subscribe to at least one kind of event.".into(),
+        ));
+    }
+    let kinds = &event_subscription.event_kinds;
+    if kinds
+        .iter()
+        .enumerate()
+        .any(|(i, kind)| kinds[..i].contains(kind))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An event subscription must not contain duplicate kinds of events.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_event_subscription(
+    _action: Update,
+    _event_subscription: EventSubscription,
+    _original_action: EntryCreationAction,
+    _original_event_subscription: EventSubscription,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an EventSubscription entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_event_subscription(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_event_subscription: EventSubscription,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of an event subscription can delete it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_all_event_subscriptions(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(EVENT_SUBSCRIPTIONS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllEventSubscriptions links must have the EVENT_SUBSCRIPTIONS anchor as their base."
+                .into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "No action hash associated with link"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _event_subscription: crate::EventSubscription = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference an entry"
+        ))))?;
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Links get deleted along with the event subscription, by its author
+pub fn validate_delete_link_all_event_subscriptions(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of an event subscription can remove it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
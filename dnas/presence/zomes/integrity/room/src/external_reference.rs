@@ -0,0 +1,88 @@
+use hdi::prelude::*;
+
+pub const EXTERNAL_REFERENCES: &str = "EXTERNAL_REFERENCES";
+
+/// A WAL of an asset in another applet that embeds this room
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct ExternalReference {
+    pub wal: String,
+}
+pub fn validate_create_external_reference(
+    _action: EntryCreationAction,
+    external_reference: ExternalReference,
+) -> ExternResult<ValidateCallbackResult> {
+    if external_reference.wal.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The WAL of an ExternalReference must not be empty.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_external_reference(
+    _action: Update,
+    _external_reference: ExternalReference,
+    _original_action: EntryCreationAction,
+    _original_external_reference: ExternalReference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an ExternalReference entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_external_reference(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_external_reference: ExternalReference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "ExternalReferences cannot be deleted",
+    )))
+}
+pub fn validate_create_link_external_references(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(EXTERNAL_REFERENCES);
+    let base_entry_hash = match EntryHash::try_from(base_address) {
+        Ok(eh) => eh,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Base address of an ExternalReferences link must be an entry hash.".into(),
+            ))
+        }
+    };
+    if base_entry_hash != path.path_entry_hash()? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "ExternalReferences links must have the EXTERNAL_REFERENCES anchor as their base."
+                .into(),
+        ));
+    }
+    let entry_hash = target_address
+        .into_entry_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Link to ExternalReference entry is not an entry hash"
+        ))))?;
+    let entry = must_get_entry(entry_hash)?;
+    if ExternalReference::try_from(entry.content).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Linked entry must be an ExternalReference entry.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_external_references(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "ExternalReferences links can only be deleted by their author.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
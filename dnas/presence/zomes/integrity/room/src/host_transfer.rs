@@ -0,0 +1,145 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority_as_of, RoomRole};
+
+pub const HOST_TRANSFERS: &str = "HOST_TRANSFERS";
+
+/// Hands the hosting of the room over from one agent to another. Committed by both
+/// agents in a countersigning session, so that neither can claim the transfer on
+/// their own.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HostTransfer {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub from_host: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub to_host: AgentPubKey,
+    /// Proof that from_host is a host: its Host role assignment, the HostTransfer
+    /// that made it one, or None for the progenitor
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+pub fn validate_create_host_transfer(
+    action: EntryCreationAction,
+    host_transfer: HostTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    if host_transfer.from_host == host_transfer.to_host {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Agents cannot transfer the hosting to themselves.".into(),
+        ));
+    }
+    if action.author() != &host_transfer.from_host && action.author() != &host_transfer.to_host {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Host transfers can only be committed by the agents involved.".into(),
+        ));
+    }
+    if !is_countersigned_by(&action, &[&host_transfer.from_host, &host_transfer.to_host])? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Host transfers must be countersigned by both the current and the new host.".into(),
+        ));
+    }
+    // The authority of the current host is checked as of its chain at the time of
+    // the transfer
+    let entry = must_get_entry(action.entry_hash().clone())?;
+    let Entry::CounterSign(session_data, _) = entry.as_content() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Host transfers must be countersigned by both the current and the new host.".into(),
+        ));
+    };
+    let from_host_state = session_data
+        .agent_state_for_agent(&host_transfer.from_host)
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.to_string())))?;
+    validate_role_authority_as_of(
+        &host_transfer.from_host,
+        action.timestamp(),
+        from_host_state.chain_top(),
+        host_transfer.granted_via,
+        &[RoomRole::Host],
+        "transfer the hosting",
+    )
+}
+
+/// Whether the entry of the action has been committed in a countersigning session
+/// in which all of the given agents took part
+pub(crate) fn is_countersigned_by(
+    action: &EntryCreationAction,
+    agents: &[&AgentPubKey],
+) -> ExternResult<bool> {
+    let entry = must_get_entry(action.entry_hash().clone())?;
+    let Entry::CounterSign(session_data, _) = entry.as_content() else {
+        return Ok(false);
+    };
+    let signing_agents: Vec<&AgentPubKey> = session_data
+        .preflight_request()
+        .signing_agents
+        .iter()
+        .map(|(agent, _)| agent)
+        .collect();
+    Ok(agents.iter().all(|agent| signing_agents.contains(agent)))
+}
+
+pub fn validate_update_host_transfer(
+    _action: Update,
+    _host_transfer: HostTransfer,
+    _original_action: EntryCreationAction,
+    _original_host_transfer: HostTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a HostTransfer entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_host_transfer(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_host_transfer: HostTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "HostTransfers cannot be deleted",
+    )))
+}
+pub fn validate_create_link_all_host_transfers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(HOST_TRANSFERS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllHostTransfers links must have the HOST_TRANSFERS anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to HostTransfer entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _host_transfer: crate::HostTransfer = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a HostTransfer entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Host transfers can only be linked by the agent that committed them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_host_transfers(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "AllHostTransfers links cannot be deleted.".into(),
+    ))
+}
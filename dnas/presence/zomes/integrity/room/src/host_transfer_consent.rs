@@ -0,0 +1,37 @@
+use hdi::prelude::*;
+
+/// Private record of this agent's consent to take over the hosting of the room from
+/// the given host. The UI records it once its user has agreed to become the host,
+/// as the countersigning itself happens without the user's involvement.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HostTransferConsent {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub from_host: AgentPubKey,
+}
+pub fn validate_create_host_transfer_consent(
+    _action: EntryCreationAction,
+    _host_transfer_consent: HostTransferConsent,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_host_transfer_consent(
+    _action: Update,
+    _host_transfer_consent: HostTransferConsent,
+    _original_action: EntryCreationAction,
+    _original_host_transfer_consent: HostTransferConsent,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a HostTransferConsent entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_host_transfer_consent(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_host_transfer_consent: HostTransferConsent,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "HostTransferConsents cannot be deleted",
+    )))
+}
@@ -0,0 +1,111 @@
+use hdi::prelude::*;
+
+pub const INTERPRETERS: &str = "INTERPRETERS";
+
+/// Prefix of the connection_type of connections carrying an interpreted audio channel,
+/// followed by the language of the channel, e.g. "interpretation:de"
+pub const INTERPRETATION_CONNECTION_TYPE_PREFIX: &str = "interpretation:";
+
+/// Returns the language of the interpretation channel if the connection type
+/// denotes one
+pub fn interpretation_language(connection_type: &str) -> Option<&str> {
+    connection_type.strip_prefix(INTERPRETATION_CONNECTION_TYPE_PREFIX)
+}
+
+/// Registers the author as an interpreter into the given language
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct InterpreterRegistration {
+    pub language: String,
+}
+pub fn validate_create_interpreter_registration(
+    _action: EntryCreationAction,
+    interpreter_registration: InterpreterRegistration,
+) -> ExternResult<ValidateCallbackResult> {
+    if interpreter_registration.language.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The language of an InterpreterRegistration must not be empty.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_interpreter_registration(
+    _action: Update,
+    _interpreter_registration: InterpreterRegistration,
+    _original_action: EntryCreationAction,
+    _original_interpreter_registration: InterpreterRegistration,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an InterpreterRegistration entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_interpreter_registration(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_interpreter_registration: InterpreterRegistration,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "InterpreterRegistrations cannot be deleted",
+    )))
+}
+pub fn validate_create_link_all_interpreter_registrations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(INTERPRETERS);
+    let base_entry_hash = match EntryHash::try_from(base_address) {
+        Ok(eh) => eh,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Base address of an AllInterpreterRegistrations link must be an entry hash.".into(),
+            ))
+        }
+    };
+    if base_entry_hash != path.path_entry_hash()? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllInterpreterRegistrations links must have the INTERPRETERS anchor as their base."
+                .into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to InterpreterRegistration entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let interpreter_registration: crate::InterpreterRegistration = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to an InterpreterRegistration entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Agents can only register themselves as interpreters.".into(),
+        ));
+    }
+    if tag.into_inner() != interpreter_registration.language.into_bytes() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllInterpreterRegistrations link tag must contain the language.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_interpreter_registrations(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Interpreters can only unregister themselves.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
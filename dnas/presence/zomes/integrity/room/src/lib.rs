@@ -3,10 +3,106 @@ pub mod attachment;
 pub use attachment::*;
 pub mod room_info;
 pub use room_info::*;
+pub mod metadata;
+pub use metadata::*;
 pub mod descendent_room;
 pub use descendent_room::*;
 pub mod anchors;
 pub use anchors::*;
+pub mod external_reference;
+pub use external_reference::*;
+pub mod breakout_assignment;
+pub use breakout_assignment::*;
+pub mod room_settings;
+pub use room_settings::*;
+pub mod caption_preference;
+pub use caption_preference::*;
+pub mod interpreter_registration;
+pub use interpreter_registration::*;
+pub mod device_binding;
+pub use device_binding::*;
+pub mod call_activity;
+pub use call_activity::*;
+pub mod block_list;
+pub use block_list::*;
+pub mod agent_status;
+pub use agent_status::*;
+pub mod mailbox_item;
+pub use mailbox_item::*;
+pub mod connection_binding;
+pub use connection_binding::*;
+pub mod deprecation_notice;
+pub use deprecation_notice::*;
+pub mod chat_message;
+pub use chat_message::*;
+pub mod question;
+pub use question::*;
+pub mod check_in;
+pub use check_in::*;
+pub mod chat_read_marker;
+pub use chat_read_marker::*;
+pub mod pinned_content;
+pub use pinned_content::*;
+pub mod event_subscription;
+pub use event_subscription::*;
+pub mod sibling_lobbies;
+pub use sibling_lobbies::*;
+pub mod directory_listing;
+pub use directory_listing::*;
+pub mod role_assignment;
+pub use role_assignment::*;
+pub mod agent_ban;
+pub use agent_ban::*;
+pub mod call_hand_off;
+pub use call_hand_off::*;
+pub mod permission_override;
+pub use permission_override::*;
+pub mod ownership_claim;
+pub use ownership_claim::*;
+pub mod admission;
+pub use admission::*;
+pub mod signal_trace;
+pub use signal_trace::*;
+pub mod debug_level;
+pub use debug_level::*;
+pub mod host_transfer;
+pub use host_transfer::*;
+pub mod membrane_proof;
+pub use membrane_proof::*;
+pub mod dna_properties;
+pub use dna_properties::*;
+pub mod call_session_end;
+pub use call_session_end::*;
+pub mod moderation_action;
+pub use moderation_action::*;
+pub mod breakout_timer;
+pub use breakout_timer::*;
+pub mod minutes_finalized;
+pub use minutes_finalized::*;
+pub mod announcement;
+pub use announcement::*;
+pub mod presenter_state;
+pub use presenter_state::*;
+pub mod privacy_mode;
+pub use privacy_mode::*;
+pub mod media_key_share;
+pub use media_key_share::*;
+pub mod presence_heartbeat;
+pub use presence_heartbeat::*;
+pub mod task;
+pub use task::*;
+pub mod prompt_deck;
+pub use prompt_deck::*;
+pub mod speaking_grant;
+pub use speaking_grant::*;
+pub mod attendance_attestation;
+pub use attendance_attestation::*;
+pub mod attachment_access;
+pub use attachment_access::*;
+pub mod minutes_co_signer_consent;
+pub use minutes_co_signer_consent::*;
+pub mod host_transfer_consent;
+pub use host_transfer_consent::*;
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[hdk_entry_types]
@@ -15,6 +111,67 @@ pub enum EntryTypes {
     RoomInfo(RoomInfo),
     Attachment(Attachment),
     DescendentRoom(DescendentRoom),
+    ExternalReference(ExternalReference),
+    BreakoutAssignment(BreakoutAssignment),
+    RoomSettings(RoomSettings),
+    CaptionPreference(CaptionPreference),
+    InterpreterRegistration(InterpreterRegistration),
+    DeviceBinding(DeviceBinding),
+    #[entry_type(visibility = "private")]
+    CallActivity(CallActivity),
+    #[entry_type(visibility = "private")]
+    BlockList(BlockList),
+    #[entry_type(visibility = "private")]
+    AgentStatus(AgentStatus),
+    #[entry_type(visibility = "private")]
+    MailboxItem(MailboxItem),
+    #[entry_type(visibility = "private")]
+    ConnectionBinding(ConnectionBinding),
+    DeprecationNotice(DeprecationNotice),
+    ChatMessage(ChatMessage),
+    Question(Question),
+    CheckIn(CheckIn),
+    #[entry_type(visibility = "private")]
+    ChatReadMarker(ChatReadMarker),
+    EventSubscription(EventSubscription),
+    #[entry_type(visibility = "private")]
+    SiblingLobbies(SiblingLobbies),
+    DirectoryListing(DirectoryListing),
+    RoleAssignment(RoleAssignment),
+    AgentBan(AgentBan),
+    #[entry_type(visibility = "private")]
+    CallHandOff(CallHandOff),
+    PermissionOverride(PermissionOverride),
+    OwnershipClaim(OwnershipClaim),
+    #[entry_type(visibility = "private")]
+    AdmissionRequest(AdmissionRequest),
+    Admission(Admission),
+    #[entry_type(visibility = "private")]
+    SignalTrace(SignalTrace),
+    #[entry_type(visibility = "private")]
+    DebugLevelPreference(DebugLevelPreference),
+    HostTransfer(HostTransfer),
+    CallSessionEnd(CallSessionEnd),
+    ModerationAction(ModerationAction),
+    #[entry_type(visibility = "private")]
+    BreakoutTimer(BreakoutTimer),
+    MinutesFinalized(MinutesFinalized),
+    Announcement(Announcement),
+    PresenterState(PresenterState),
+    #[entry_type(visibility = "private")]
+    PrivacyModePreference(PrivacyModePreference),
+    #[entry_type(visibility = "private")]
+    MediaKeyShare(MediaKeyShare),
+    Task(Task),
+    PromptDeck(PromptDeck),
+    SpeakingGrant(SpeakingGrant),
+    #[entry_type(visibility = "private")]
+    AttachmentAccess(AttachmentAccess),
+    AttendanceAttestation(AttendanceAttestation),
+    #[entry_type(visibility = "private")]
+    MinutesCoSignerConsent(MinutesCoSignerConsent),
+    #[entry_type(visibility = "private")]
+    HostTransferConsent(HostTransferConsent),
 }
 #[derive(Serialize, Deserialize)]
 #[hdk_link_types]
@@ -24,16 +181,55 @@ pub enum LinkTypes {
     AllDescendentRooms,
     AttachmentUpdates,
     AllAttachments,
+    ExternalReferences,
+    AllBreakoutAssignments,
+    AgentToBreakoutAssignments,
+    RoomSettingsUpdates,
+    AgentToCaptionPreferences,
+    AllInterpreterRegistrations,
+    AgentToDeviceBindings,
+    DeprecationNoticeUpdates,
+    TrashedAttachments,
+    AllChatMessages,
+    ChatMessageUpdates,
+    AllQuestions,
+    QuestionUpvotes,
+    AnsweredQuestions,
+    ChatMessageReplies,
+    AllCheckIns,
+    PinnedContent,
+    AllEventSubscriptions,
+    DirectoryListingUpdates,
+    AllRoleAssignments,
+    AgentToRoleAssignments,
+    BannedAgents,
+    AgentToPermissionOverrides,
+    AllOwnershipClaims,
+    OwnershipClaimVetoes,
+    AgentToAdmissions,
+    AllHostTransfers,
+    AllCallSessionEnds,
+    ModerationLog,
+    MinutesApprovals,
+    AllAnnouncements,
+    PresenterStateUpdates,
+    PresenceHeartbeats,
+    CallHeartbeats,
+    TaskUpdates,
+    AllTasks,
+    PromptDeckUpdates,
+    AllSpeakingGrants,
+    AgentToAttendanceAttestations,
 }
 #[hdk_extern]
-pub fn genesis_self_check(_data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+pub fn genesis_self_check(data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
+    validate_membrane_proof(&data.agent_key, &data.membrane_proof)
 }
 pub fn validate_agent_joining(
-    _agent_pub_key: AgentPubKey,
-    _membrane_proof: &Option<MembraneProof>,
+    agent_pub_key: AgentPubKey,
+    membrane_proof: &Option<MembraneProof>,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    validate_membrane_proof(&agent_pub_key, membrane_proof)
 }
 #[hdk_extern]
 pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
@@ -50,6 +246,206 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     EntryCreationAction::Create(action),
                     descendent_room,
                 ),
+                EntryTypes::ExternalReference(external_reference) => {
+                    validate_create_external_reference(
+                        EntryCreationAction::Create(action),
+                        external_reference,
+                    )
+                }
+                EntryTypes::BreakoutAssignment(breakout_assignment) => {
+                    validate_create_breakout_assignment(
+                        EntryCreationAction::Create(action),
+                        breakout_assignment,
+                    )
+                }
+                EntryTypes::RoomSettings(room_settings) => validate_create_room_settings(
+                    EntryCreationAction::Create(action),
+                    room_settings,
+                ),
+                EntryTypes::CaptionPreference(caption_preference) => {
+                    validate_create_caption_preference(
+                        EntryCreationAction::Create(action),
+                        caption_preference,
+                    )
+                }
+                EntryTypes::InterpreterRegistration(interpreter_registration) => {
+                    validate_create_interpreter_registration(
+                        EntryCreationAction::Create(action),
+                        interpreter_registration,
+                    )
+                }
+                EntryTypes::DeviceBinding(device_binding) => validate_create_device_binding(
+                    EntryCreationAction::Create(action),
+                    device_binding,
+                ),
+                EntryTypes::CallActivity(call_activity) => validate_create_call_activity(
+                    EntryCreationAction::Create(action),
+                    call_activity,
+                ),
+                EntryTypes::BlockList(block_list) => {
+                    validate_create_block_list(EntryCreationAction::Create(action), block_list)
+                }
+                EntryTypes::AgentStatus(agent_status) => {
+                    validate_create_agent_status(EntryCreationAction::Create(action), agent_status)
+                }
+                EntryTypes::MailboxItem(mailbox_item) => {
+                    validate_create_mailbox_item(EntryCreationAction::Create(action), mailbox_item)
+                }
+                EntryTypes::ConnectionBinding(connection_binding) => {
+                    validate_create_connection_binding(
+                        EntryCreationAction::Create(action),
+                        connection_binding,
+                    )
+                }
+                EntryTypes::DeprecationNotice(deprecation_notice) => {
+                    validate_create_deprecation_notice(
+                        EntryCreationAction::Create(action),
+                        deprecation_notice,
+                    )
+                }
+                EntryTypes::ChatMessage(chat_message) => {
+                    validate_create_chat_message(EntryCreationAction::Create(action), chat_message)
+                }
+                EntryTypes::Question(question) => {
+                    validate_create_question(EntryCreationAction::Create(action), question)
+                }
+                EntryTypes::CheckIn(check_in) => {
+                    validate_create_check_in(EntryCreationAction::Create(action), check_in)
+                }
+                EntryTypes::ChatReadMarker(chat_read_marker) => validate_create_chat_read_marker(
+                    EntryCreationAction::Create(action),
+                    chat_read_marker,
+                ),
+                EntryTypes::EventSubscription(event_subscription) => {
+                    validate_create_event_subscription(
+                        EntryCreationAction::Create(action),
+                        event_subscription,
+                    )
+                }
+                EntryTypes::SiblingLobbies(sibling_lobbies) => validate_create_sibling_lobbies(
+                    EntryCreationAction::Create(action),
+                    sibling_lobbies,
+                ),
+                EntryTypes::DirectoryListing(directory_listing) => {
+                    validate_create_directory_listing(
+                        EntryCreationAction::Create(action),
+                        directory_listing,
+                    )
+                }
+                EntryTypes::RoleAssignment(role_assignment) => validate_create_role_assignment(
+                    EntryCreationAction::Create(action),
+                    role_assignment,
+                ),
+                EntryTypes::AgentBan(agent_ban) => {
+                    validate_create_agent_ban(EntryCreationAction::Create(action), agent_ban)
+                }
+                EntryTypes::CallHandOff(call_hand_off) => validate_create_call_hand_off(
+                    EntryCreationAction::Create(action),
+                    call_hand_off,
+                ),
+                EntryTypes::PermissionOverride(permission_override) => {
+                    validate_create_permission_override(
+                        EntryCreationAction::Create(action),
+                        permission_override,
+                    )
+                }
+                EntryTypes::OwnershipClaim(ownership_claim) => validate_create_ownership_claim(
+                    EntryCreationAction::Create(action),
+                    ownership_claim,
+                ),
+                EntryTypes::AdmissionRequest(admission_request) => {
+                    validate_create_admission_request(
+                        EntryCreationAction::Create(action),
+                        admission_request,
+                    )
+                }
+                EntryTypes::Admission(admission) => {
+                    validate_create_admission(EntryCreationAction::Create(action), admission)
+                }
+                EntryTypes::SignalTrace(signal_trace) => {
+                    validate_create_signal_trace(EntryCreationAction::Create(action), signal_trace)
+                }
+                EntryTypes::DebugLevelPreference(debug_level_preference) => {
+                    validate_create_debug_level_preference(
+                        EntryCreationAction::Create(action),
+                        debug_level_preference,
+                    )
+                }
+                EntryTypes::HostTransfer(host_transfer) => validate_create_host_transfer(
+                    EntryCreationAction::Create(action),
+                    host_transfer,
+                ),
+                EntryTypes::CallSessionEnd(call_session_end) => validate_create_call_session_end(
+                    EntryCreationAction::Create(action),
+                    call_session_end,
+                ),
+                EntryTypes::ModerationAction(moderation_action) => {
+                    validate_create_moderation_action(
+                        EntryCreationAction::Create(action),
+                        moderation_action,
+                    )
+                }
+                EntryTypes::BreakoutTimer(breakout_timer) => validate_create_breakout_timer(
+                    EntryCreationAction::Create(action),
+                    breakout_timer,
+                ),
+                EntryTypes::MinutesFinalized(minutes_finalized) => {
+                    validate_create_minutes_finalized(
+                        EntryCreationAction::Create(action),
+                        minutes_finalized,
+                    )
+                }
+                EntryTypes::Announcement(announcement) => {
+                    validate_create_announcement(EntryCreationAction::Create(action), announcement)
+                }
+                EntryTypes::PresenterState(presenter_state) => validate_create_presenter_state(
+                    EntryCreationAction::Create(action),
+                    presenter_state,
+                ),
+                EntryTypes::PrivacyModePreference(privacy_mode_preference) => {
+                    validate_create_privacy_mode_preference(
+                        EntryCreationAction::Create(action),
+                        privacy_mode_preference,
+                    )
+                }
+                EntryTypes::MediaKeyShare(media_key_share) => validate_create_media_key_share(
+                    EntryCreationAction::Create(action),
+                    media_key_share,
+                ),
+                EntryTypes::Task(task) => {
+                    validate_create_task(EntryCreationAction::Create(action), task)
+                }
+                EntryTypes::PromptDeck(prompt_deck) => {
+                    validate_create_prompt_deck(EntryCreationAction::Create(action), prompt_deck)
+                }
+                EntryTypes::SpeakingGrant(speaking_grant) => validate_create_speaking_grant(
+                    EntryCreationAction::Create(action),
+                    speaking_grant,
+                ),
+                EntryTypes::AttendanceAttestation(attendance_attestation) => {
+                    validate_create_attendance_attestation(
+                        EntryCreationAction::Create(action),
+                        attendance_attestation,
+                    )
+                }
+                EntryTypes::AttachmentAccess(attachment_access) => {
+                    validate_create_attachment_access(
+                        EntryCreationAction::Create(action),
+                        attachment_access,
+                    )
+                }
+                EntryTypes::MinutesCoSignerConsent(minutes_co_signer_consent) => {
+                    validate_create_minutes_co_signer_consent(
+                        EntryCreationAction::Create(action),
+                        minutes_co_signer_consent,
+                    )
+                }
+                EntryTypes::HostTransferConsent(host_transfer_consent) => {
+                    validate_create_host_transfer_consent(
+                        EntryCreationAction::Create(action),
+                        host_transfer_consent,
+                    )
+                }
             },
             OpEntry::UpdateEntry {
                 app_entry, action, ..
@@ -64,6 +460,206 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     EntryCreationAction::Update(action),
                     descendent_room,
                 ),
+                EntryTypes::ExternalReference(external_reference) => {
+                    validate_create_external_reference(
+                        EntryCreationAction::Update(action),
+                        external_reference,
+                    )
+                }
+                EntryTypes::BreakoutAssignment(breakout_assignment) => {
+                    validate_create_breakout_assignment(
+                        EntryCreationAction::Update(action),
+                        breakout_assignment,
+                    )
+                }
+                EntryTypes::RoomSettings(room_settings) => validate_create_room_settings(
+                    EntryCreationAction::Update(action),
+                    room_settings,
+                ),
+                EntryTypes::CaptionPreference(caption_preference) => {
+                    validate_create_caption_preference(
+                        EntryCreationAction::Update(action),
+                        caption_preference,
+                    )
+                }
+                EntryTypes::InterpreterRegistration(interpreter_registration) => {
+                    validate_create_interpreter_registration(
+                        EntryCreationAction::Update(action),
+                        interpreter_registration,
+                    )
+                }
+                EntryTypes::DeviceBinding(device_binding) => validate_create_device_binding(
+                    EntryCreationAction::Update(action),
+                    device_binding,
+                ),
+                EntryTypes::CallActivity(call_activity) => validate_create_call_activity(
+                    EntryCreationAction::Update(action),
+                    call_activity,
+                ),
+                EntryTypes::BlockList(block_list) => {
+                    validate_create_block_list(EntryCreationAction::Update(action), block_list)
+                }
+                EntryTypes::AgentStatus(agent_status) => {
+                    validate_create_agent_status(EntryCreationAction::Update(action), agent_status)
+                }
+                EntryTypes::MailboxItem(mailbox_item) => {
+                    validate_create_mailbox_item(EntryCreationAction::Update(action), mailbox_item)
+                }
+                EntryTypes::ConnectionBinding(connection_binding) => {
+                    validate_create_connection_binding(
+                        EntryCreationAction::Update(action),
+                        connection_binding,
+                    )
+                }
+                EntryTypes::DeprecationNotice(deprecation_notice) => {
+                    validate_create_deprecation_notice(
+                        EntryCreationAction::Update(action),
+                        deprecation_notice,
+                    )
+                }
+                EntryTypes::ChatMessage(chat_message) => {
+                    validate_create_chat_message(EntryCreationAction::Update(action), chat_message)
+                }
+                EntryTypes::Question(question) => {
+                    validate_create_question(EntryCreationAction::Update(action), question)
+                }
+                EntryTypes::CheckIn(check_in) => {
+                    validate_create_check_in(EntryCreationAction::Update(action), check_in)
+                }
+                EntryTypes::ChatReadMarker(chat_read_marker) => validate_create_chat_read_marker(
+                    EntryCreationAction::Update(action),
+                    chat_read_marker,
+                ),
+                EntryTypes::EventSubscription(event_subscription) => {
+                    validate_create_event_subscription(
+                        EntryCreationAction::Update(action),
+                        event_subscription,
+                    )
+                }
+                EntryTypes::SiblingLobbies(sibling_lobbies) => validate_create_sibling_lobbies(
+                    EntryCreationAction::Update(action),
+                    sibling_lobbies,
+                ),
+                EntryTypes::DirectoryListing(directory_listing) => {
+                    validate_create_directory_listing(
+                        EntryCreationAction::Update(action),
+                        directory_listing,
+                    )
+                }
+                EntryTypes::RoleAssignment(role_assignment) => validate_create_role_assignment(
+                    EntryCreationAction::Update(action),
+                    role_assignment,
+                ),
+                EntryTypes::AgentBan(agent_ban) => {
+                    validate_create_agent_ban(EntryCreationAction::Update(action), agent_ban)
+                }
+                EntryTypes::CallHandOff(call_hand_off) => validate_create_call_hand_off(
+                    EntryCreationAction::Update(action),
+                    call_hand_off,
+                ),
+                EntryTypes::PermissionOverride(permission_override) => {
+                    validate_create_permission_override(
+                        EntryCreationAction::Update(action),
+                        permission_override,
+                    )
+                }
+                EntryTypes::OwnershipClaim(ownership_claim) => validate_create_ownership_claim(
+                    EntryCreationAction::Update(action),
+                    ownership_claim,
+                ),
+                EntryTypes::AdmissionRequest(admission_request) => {
+                    validate_create_admission_request(
+                        EntryCreationAction::Update(action),
+                        admission_request,
+                    )
+                }
+                EntryTypes::Admission(admission) => {
+                    validate_create_admission(EntryCreationAction::Update(action), admission)
+                }
+                EntryTypes::SignalTrace(signal_trace) => {
+                    validate_create_signal_trace(EntryCreationAction::Update(action), signal_trace)
+                }
+                EntryTypes::DebugLevelPreference(debug_level_preference) => {
+                    validate_create_debug_level_preference(
+                        EntryCreationAction::Update(action),
+                        debug_level_preference,
+                    )
+                }
+                EntryTypes::HostTransfer(host_transfer) => validate_create_host_transfer(
+                    EntryCreationAction::Update(action),
+                    host_transfer,
+                ),
+                EntryTypes::CallSessionEnd(call_session_end) => validate_create_call_session_end(
+                    EntryCreationAction::Update(action),
+                    call_session_end,
+                ),
+                EntryTypes::ModerationAction(moderation_action) => {
+                    validate_create_moderation_action(
+                        EntryCreationAction::Update(action),
+                        moderation_action,
+                    )
+                }
+                EntryTypes::BreakoutTimer(breakout_timer) => validate_create_breakout_timer(
+                    EntryCreationAction::Update(action),
+                    breakout_timer,
+                ),
+                EntryTypes::MinutesFinalized(minutes_finalized) => {
+                    validate_create_minutes_finalized(
+                        EntryCreationAction::Update(action),
+                        minutes_finalized,
+                    )
+                }
+                EntryTypes::Announcement(announcement) => {
+                    validate_create_announcement(EntryCreationAction::Update(action), announcement)
+                }
+                EntryTypes::PresenterState(presenter_state) => validate_create_presenter_state(
+                    EntryCreationAction::Update(action),
+                    presenter_state,
+                ),
+                EntryTypes::PrivacyModePreference(privacy_mode_preference) => {
+                    validate_create_privacy_mode_preference(
+                        EntryCreationAction::Update(action),
+                        privacy_mode_preference,
+                    )
+                }
+                EntryTypes::MediaKeyShare(media_key_share) => validate_create_media_key_share(
+                    EntryCreationAction::Update(action),
+                    media_key_share,
+                ),
+                EntryTypes::Task(task) => {
+                    validate_create_task(EntryCreationAction::Update(action), task)
+                }
+                EntryTypes::PromptDeck(prompt_deck) => {
+                    validate_create_prompt_deck(EntryCreationAction::Update(action), prompt_deck)
+                }
+                EntryTypes::SpeakingGrant(speaking_grant) => validate_create_speaking_grant(
+                    EntryCreationAction::Update(action),
+                    speaking_grant,
+                ),
+                EntryTypes::AttendanceAttestation(attendance_attestation) => {
+                    validate_create_attendance_attestation(
+                        EntryCreationAction::Update(action),
+                        attendance_attestation,
+                    )
+                }
+                EntryTypes::AttachmentAccess(attachment_access) => {
+                    validate_create_attachment_access(
+                        EntryCreationAction::Update(action),
+                        attachment_access,
+                    )
+                }
+                EntryTypes::MinutesCoSignerConsent(minutes_co_signer_consent) => {
+                    validate_create_minutes_co_signer_consent(
+                        EntryCreationAction::Update(action),
+                        minutes_co_signer_consent,
+                    )
+                }
+                EntryTypes::HostTransferConsent(host_transfer_consent) => {
+                    validate_create_host_transfer_consent(
+                        EntryCreationAction::Update(action),
+                        host_transfer_consent,
+                    )
+                }
             },
             _ => Ok(ValidateCallbackResult::Valid),
         },
@@ -136,146 +732,1875 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             original_descendent_room,
                         )
                     }
-                }
-            }
-            _ => Ok(ValidateCallbackResult::Valid),
-        },
-        FlatOp::RegisterDelete(delete_entry) => {
-            let original_action_hash = delete_entry.clone().action.deletes_address;
-            let original_record = must_get_valid_record(original_action_hash)?;
-            let original_record_action = original_record.action().clone();
-            let original_action = match EntryCreationAction::try_from(original_record_action) {
-                Ok(action) => action,
-                Err(e) => {
-                    return Ok(ValidateCallbackResult::Invalid(format!(
-                        "Expected to get EntryCreationAction from Action: {e:?}"
-                    )));
-                }
-            };
-            let app_entry_type = match original_action.entry_type() {
-                EntryType::App(app_entry_type) => app_entry_type,
-                _ => {
-                    return Ok(ValidateCallbackResult::Valid);
-                }
-            };
-            let entry = match original_record.entry().as_option() {
-                Some(entry) => entry,
-                None => {
-                    return Ok(ValidateCallbackResult::Invalid(
-                        "Original record for a delete must contain an entry".to_string(),
-                    ));
-                }
-            };
-            let original_app_entry = match EntryTypes::deserialize_from_type(
-                app_entry_type.zome_index,
-                app_entry_type.entry_index,
-                entry,
-            )? {
-                Some(app_entry) => app_entry,
-                None => {
-                    return Ok(ValidateCallbackResult::Invalid(
-                        "Original app entry must be one of the defined entry types for this zome"
-                            .to_string(),
-                    ));
-                }
-            };
-            match original_app_entry {
-                EntryTypes::RoomInfo(room_info) => validate_delete_room_info(
-                    delete_entry.clone().action,
-                    original_action,
-                    room_info,
-                ),
-                EntryTypes::Attachment(attachment) => validate_delete_attachment(
-                    delete_entry.clone().action,
-                    original_action,
-                    attachment,
-                ),
-                EntryTypes::DescendentRoom(descendent_room) => validate_delete_descendent_room(
-                    delete_entry.clone().action,
-                    original_action,
-                    descendent_room,
-                ),
-            }
-        }
-        FlatOp::RegisterCreateLink {
-            link_type,
-            base_address,
-            target_address,
-            tag,
-            action,
-        } => match link_type {
-            LinkTypes::RoomInfoUpdates => {
-                validate_create_link_room_info_updates(action, base_address, target_address, tag)
-            }
-            LinkTypes::AllAgents => {
-                validate_create_link_all_agents(action, base_address, target_address, tag)
-            }
-            LinkTypes::AllDescendentRooms => {
-                validate_create_link_all_descendent_rooms(action, base_address, target_address, tag)
-            }
-            LinkTypes::AttachmentUpdates => {
-                validate_create_link_attachment_updates(action, base_address, target_address, tag)
-            }
-            LinkTypes::AllAttachments => {
-                validate_create_link_all_attachments(action, base_address, target_address, tag)
-            }
-        },
-        FlatOp::RegisterDeleteLink {
-            link_type,
-            base_address,
-            target_address,
-            tag,
-            original_action,
-            action,
-        } => match link_type {
-            LinkTypes::RoomInfoUpdates => validate_delete_link_room_info_updates(
-                action,
-                original_action,
-                base_address,
-                target_address,
-                tag,
-            ),
-            LinkTypes::AllAgents => validate_delete_link_all_agents(
-                action,
-                original_action,
-                base_address,
-                target_address,
-                tag,
-            ),
-            LinkTypes::AllDescendentRooms => validate_delete_link_all_descendent_rooms(
-                action,
-                original_action,
-                base_address,
-                target_address,
-                tag,
-            ),
-            LinkTypes::AttachmentUpdates => validate_delete_link_attachment_updates(
-                action,
-                original_action,
-                base_address,
-                target_address,
-                tag,
-            ),
-            LinkTypes::AllAttachments => validate_delete_link_all_attachments(
-                action,
-                original_action,
-                base_address,
-                target_address,
-                tag,
-            ),
-        },
-        FlatOp::StoreRecord(store_record) => match store_record {
-            OpRecord::CreateEntry { app_entry, action } => match app_entry {
-                EntryTypes::RoomInfo(room_info) => {
-                    validate_create_room_info(EntryCreationAction::Create(action), room_info)
-                }
-                EntryTypes::Attachment(attachment) => {
-                    validate_create_attachment(EntryCreationAction::Create(action), attachment)
-                }
-                EntryTypes::DescendentRoom(descendent_room) => validate_create_descendent_room(
-                    EntryCreationAction::Create(action),
-                    descendent_room,
-                ),
+                    EntryTypes::ExternalReference(external_reference) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_external_reference =
+                            match ExternalReference::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get ExternalReference from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_external_reference(
+                            action,
+                            external_reference,
+                            original_create_action,
+                            original_external_reference,
+                        )
+                    }
+                    EntryTypes::BreakoutAssignment(breakout_assignment) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_breakout_assignment =
+                            match BreakoutAssignment::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get BreakoutAssignment from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_breakout_assignment(
+                            action,
+                            breakout_assignment,
+                            original_create_action,
+                            original_breakout_assignment,
+                        )
+                    }
+                    EntryTypes::RoomSettings(room_settings) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_room_settings =
+                            match RoomSettings::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get RoomSettings from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_room_settings(
+                            action,
+                            room_settings,
+                            original_create_action,
+                            original_room_settings,
+                        )
+                    }
+                    EntryTypes::CaptionPreference(caption_preference) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_caption_preference =
+                            match CaptionPreference::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get CaptionPreference from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_caption_preference(
+                            action,
+                            caption_preference,
+                            original_create_action,
+                            original_caption_preference,
+                        )
+                    }
+                    EntryTypes::InterpreterRegistration(interpreter_registration) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_interpreter_registration =
+                            match InterpreterRegistration::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                "Expected to get InterpreterRegistration from Record: {e:?}"
+            )));
+                                }
+                            };
+                        validate_update_interpreter_registration(
+                            action,
+                            interpreter_registration,
+                            original_create_action,
+                            original_interpreter_registration,
+                        )
+                    }
+                    EntryTypes::DeviceBinding(device_binding) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_device_binding =
+                            match DeviceBinding::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get DeviceBinding from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_device_binding(
+                            action,
+                            device_binding,
+                            original_create_action,
+                            original_device_binding,
+                        )
+                    }
+                    EntryTypes::CallActivity(call_activity) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_call_activity =
+                            match CallActivity::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get CallActivity from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_call_activity(
+                            action,
+                            call_activity,
+                            original_create_action,
+                            original_call_activity,
+                        )
+                    }
+                    EntryTypes::BlockList(block_list) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_block_list = match BlockList::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get BlockList from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_block_list(
+                            action,
+                            block_list,
+                            original_create_action,
+                            original_block_list,
+                        )
+                    }
+                    EntryTypes::AgentStatus(agent_status) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_agent_status = match AgentStatus::try_from(original_app_entry)
+                        {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get AgentStatus from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_agent_status(
+                            action,
+                            agent_status,
+                            original_create_action,
+                            original_agent_status,
+                        )
+                    }
+                    EntryTypes::MailboxItem(mailbox_item) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_mailbox_item = match MailboxItem::try_from(original_app_entry)
+                        {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get MailboxItem from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_mailbox_item(
+                            action,
+                            mailbox_item,
+                            original_create_action,
+                            original_mailbox_item,
+                        )
+                    }
+                    EntryTypes::ConnectionBinding(connection_binding) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_connection_binding =
+                            match ConnectionBinding::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get ConnectionBinding from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_connection_binding(
+                            action,
+                            connection_binding,
+                            original_create_action,
+                            original_connection_binding,
+                        )
+                    }
+                    EntryTypes::DeprecationNotice(deprecation_notice) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_deprecation_notice =
+                            match DeprecationNotice::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get DeprecationNotice from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_deprecation_notice(
+                            action,
+                            deprecation_notice,
+                            original_create_action,
+                            original_deprecation_notice,
+                        )
+                    }
+                    EntryTypes::ChatMessage(chat_message) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_chat_message = match ChatMessage::try_from(original_app_entry)
+                        {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get ChatMessage from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_chat_message(
+                            action,
+                            chat_message,
+                            original_create_action,
+                            original_chat_message,
+                        )
+                    }
+                    EntryTypes::Question(question) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_question = match Question::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get Question from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_question(
+                            action,
+                            question,
+                            original_create_action,
+                            original_question,
+                        )
+                    }
+                    EntryTypes::CheckIn(check_in) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_check_in = match CheckIn::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get CheckIn from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_check_in(
+                            action,
+                            check_in,
+                            original_create_action,
+                            original_check_in,
+                        )
+                    }
+                    EntryTypes::ChatReadMarker(chat_read_marker) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_chat_read_marker =
+                            match ChatReadMarker::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get ChatReadMarker from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_chat_read_marker(
+                            action,
+                            chat_read_marker,
+                            original_create_action,
+                            original_chat_read_marker,
+                        )
+                    }
+                    EntryTypes::EventSubscription(event_subscription) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_event_subscription =
+                            match EventSubscription::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get EventSubscription from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_event_subscription(
+                            action,
+                            event_subscription,
+                            original_create_action,
+                            original_event_subscription,
+                        )
+                    }
+                    EntryTypes::SiblingLobbies(sibling_lobbies) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_sibling_lobbies =
+                            match SiblingLobbies::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get SiblingLobbies from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_sibling_lobbies(
+                            action,
+                            sibling_lobbies,
+                            original_create_action,
+                            original_sibling_lobbies,
+                        )
+                    }
+                    EntryTypes::DirectoryListing(directory_listing) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_directory_listing =
+                            match DirectoryListing::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get DirectoryListing from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_directory_listing(
+                            action,
+                            directory_listing,
+                            original_create_action,
+                            original_directory_listing,
+                        )
+                    }
+                    EntryTypes::RoleAssignment(role_assignment) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_role_assignment =
+                            match RoleAssignment::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get RoleAssignment from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_role_assignment(
+                            action,
+                            role_assignment,
+                            original_create_action,
+                            original_role_assignment,
+                        )
+                    }
+                    EntryTypes::AgentBan(agent_ban) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_agent_ban = match AgentBan::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get AgentBan from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_agent_ban(
+                            action,
+                            agent_ban,
+                            original_create_action,
+                            original_agent_ban,
+                        )
+                    }
+                    EntryTypes::CallHandOff(call_hand_off) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_call_hand_off = match CallHandOff::try_from(original_app_entry)
+                        {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get CallHandOff from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_call_hand_off(
+                            action,
+                            call_hand_off,
+                            original_create_action,
+                            original_call_hand_off,
+                        )
+                    }
+                    EntryTypes::PermissionOverride(permission_override) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_permission_override =
+                            match PermissionOverride::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get PermissionOverride from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_permission_override(
+                            action,
+                            permission_override,
+                            original_create_action,
+                            original_permission_override,
+                        )
+                    }
+                    EntryTypes::OwnershipClaim(ownership_claim) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_ownership_claim =
+                            match OwnershipClaim::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get OwnershipClaim from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_ownership_claim(
+                            action,
+                            ownership_claim,
+                            original_create_action,
+                            original_ownership_claim,
+                        )
+                    }
+                    EntryTypes::AdmissionRequest(admission_request) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_admission_request =
+                            match AdmissionRequest::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get AdmissionRequest from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_admission_request(
+                            action,
+                            admission_request,
+                            original_create_action,
+                            original_admission_request,
+                        )
+                    }
+                    EntryTypes::Admission(admission) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_admission = match Admission::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get Admission from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_admission(
+                            action,
+                            admission,
+                            original_create_action,
+                            original_admission,
+                        )
+                    }
+                    EntryTypes::SignalTrace(signal_trace) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_signal_trace = match SignalTrace::try_from(original_app_entry)
+                        {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get SignalTrace from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_signal_trace(
+                            action,
+                            signal_trace,
+                            original_create_action,
+                            original_signal_trace,
+                        )
+                    }
+                    EntryTypes::DebugLevelPreference(debug_level_preference) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_debug_level_preference =
+                            match DebugLevelPreference::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get DebugLevelPreference from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_debug_level_preference(
+                            action,
+                            debug_level_preference,
+                            original_create_action,
+                            original_debug_level_preference,
+                        )
+                    }
+                    EntryTypes::HostTransfer(host_transfer) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_host_transfer =
+                            match HostTransfer::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get HostTransfer from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_host_transfer(
+                            action,
+                            host_transfer,
+                            original_create_action,
+                            original_host_transfer,
+                        )
+                    }
+                    EntryTypes::CallSessionEnd(call_session_end) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_call_session_end =
+                            match CallSessionEnd::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get CallSessionEnd from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_call_session_end(
+                            action,
+                            call_session_end,
+                            original_create_action,
+                            original_call_session_end,
+                        )
+                    }
+                    EntryTypes::ModerationAction(moderation_action) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_moderation_action =
+                            match ModerationAction::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get ModerationAction from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_moderation_action(
+                            action,
+                            moderation_action,
+                            original_create_action,
+                            original_moderation_action,
+                        )
+                    }
+                    EntryTypes::BreakoutTimer(breakout_timer) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_breakout_timer =
+                            match BreakoutTimer::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get BreakoutTimer from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_breakout_timer(
+                            action,
+                            breakout_timer,
+                            original_create_action,
+                            original_breakout_timer,
+                        )
+                    }
+                    EntryTypes::MinutesFinalized(minutes_finalized) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_minutes_finalized =
+                            match MinutesFinalized::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get MinutesFinalized from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_minutes_finalized(
+                            action,
+                            minutes_finalized,
+                            original_create_action,
+                            original_minutes_finalized,
+                        )
+                    }
+                    EntryTypes::Announcement(announcement) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_announcement = match Announcement::try_from(original_app_entry)
+                        {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get Announcement from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_announcement(
+                            action,
+                            announcement,
+                            original_create_action,
+                            original_announcement,
+                        )
+                    }
+                    EntryTypes::PresenterState(presenter_state) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_presenter_state =
+                            match PresenterState::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get PresenterState from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_presenter_state(
+                            action,
+                            presenter_state,
+                            original_create_action,
+                            original_presenter_state,
+                        )
+                    }
+                    EntryTypes::PrivacyModePreference(privacy_mode_preference) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_privacy_mode_preference =
+                            match PrivacyModePreference::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get PrivacyModePreference from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_privacy_mode_preference(
+                            action,
+                            privacy_mode_preference,
+                            original_create_action,
+                            original_privacy_mode_preference,
+                        )
+                    }
+                    EntryTypes::MediaKeyShare(media_key_share) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_media_key_share =
+                            match MediaKeyShare::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get MediaKeyShare from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_media_key_share(
+                            action,
+                            media_key_share,
+                            original_create_action,
+                            original_media_key_share,
+                        )
+                    }
+                    EntryTypes::Task(task) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_task = match Task::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get Task from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_task(action, task, original_create_action, original_task)
+                    }
+                    EntryTypes::PromptDeck(prompt_deck) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_prompt_deck = match PromptDeck::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get PromptDeck from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_prompt_deck(
+                            action,
+                            prompt_deck,
+                            original_create_action,
+                            original_prompt_deck,
+                        )
+                    }
+                    EntryTypes::SpeakingGrant(speaking_grant) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_speaking_grant =
+                            match SpeakingGrant::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get SpeakingGrant from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_speaking_grant(
+                            action,
+                            speaking_grant,
+                            original_create_action,
+                            original_speaking_grant,
+                        )
+                    }
+                    EntryTypes::AttendanceAttestation(attendance_attestation) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_attendance_attestation =
+                            match AttendanceAttestation::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get AttendanceAttestation from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_attendance_attestation(
+                            action,
+                            attendance_attestation,
+                            original_create_action,
+                            original_attendance_attestation,
+                        )
+                    }
+                    EntryTypes::AttachmentAccess(attachment_access) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_attachment_access =
+                            match AttachmentAccess::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get AttachmentAccess from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_attachment_access(
+                            action,
+                            attachment_access,
+                            original_create_action,
+                            original_attachment_access,
+                        )
+                    }
+                    EntryTypes::MinutesCoSignerConsent(minutes_co_signer_consent) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_minutes_co_signer_consent =
+                            match MinutesCoSignerConsent::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get MinutesCoSignerConsent from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_minutes_co_signer_consent(
+                            action,
+                            minutes_co_signer_consent,
+                            original_create_action,
+                            original_minutes_co_signer_consent,
+                        )
+                    }
+                    EntryTypes::HostTransferConsent(host_transfer_consent) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_host_transfer_consent =
+                            match HostTransferConsent::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get HostTransferConsent from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_host_transfer_consent(
+                            action,
+                            host_transfer_consent,
+                            original_create_action,
+                            original_host_transfer_consent,
+                        )
+                    }
+                }
+            }
+            _ => Ok(ValidateCallbackResult::Valid),
+        },
+        FlatOp::RegisterDelete(delete_entry) => {
+            let original_action_hash = delete_entry.clone().action.deletes_address;
+            let original_record = must_get_valid_record(original_action_hash)?;
+            let original_record_action = original_record.action().clone();
+            let original_action = match EntryCreationAction::try_from(original_record_action) {
+                Ok(action) => action,
+                Err(e) => {
+                    return Ok(ValidateCallbackResult::Invalid(format!(
+                        "Expected to get EntryCreationAction from Action: {e:?}"
+                    )));
+                }
+            };
+            let app_entry_type = match original_action.entry_type() {
+                EntryType::App(app_entry_type) => app_entry_type,
+                _ => {
+                    return Ok(ValidateCallbackResult::Valid);
+                }
+            };
+            let entry = match original_record.entry().as_option() {
+                Some(entry) => entry,
+                None => {
+                    return Ok(ValidateCallbackResult::Invalid(
+                        "Original record for a delete must contain an entry".to_string(),
+                    ));
+                }
+            };
+            let original_app_entry = match EntryTypes::deserialize_from_type(
+                app_entry_type.zome_index,
+                app_entry_type.entry_index,
+                entry,
+            )? {
+                Some(app_entry) => app_entry,
+                None => {
+                    return Ok(ValidateCallbackResult::Invalid(
+                        "Original app entry must be one of the defined entry types for this zome"
+                            .to_string(),
+                    ));
+                }
+            };
+            match original_app_entry {
+                EntryTypes::RoomInfo(room_info) => validate_delete_room_info(
+                    delete_entry.clone().action,
+                    original_action,
+                    room_info,
+                ),
+                EntryTypes::Attachment(attachment) => validate_delete_attachment(
+                    delete_entry.clone().action,
+                    original_action,
+                    attachment,
+                ),
+                EntryTypes::DescendentRoom(descendent_room) => validate_delete_descendent_room(
+                    delete_entry.clone().action,
+                    original_action,
+                    descendent_room,
+                ),
+                EntryTypes::ExternalReference(external_reference) => {
+                    validate_delete_external_reference(
+                        delete_entry.clone().action,
+                        original_action,
+                        external_reference,
+                    )
+                }
+                EntryTypes::BreakoutAssignment(breakout_assignment) => {
+                    validate_delete_breakout_assignment(
+                        delete_entry.clone().action,
+                        original_action,
+                        breakout_assignment,
+                    )
+                }
+                EntryTypes::RoomSettings(room_settings) => validate_delete_room_settings(
+                    delete_entry.clone().action,
+                    original_action,
+                    room_settings,
+                ),
+                EntryTypes::CaptionPreference(caption_preference) => {
+                    validate_delete_caption_preference(
+                        delete_entry.clone().action,
+                        original_action,
+                        caption_preference,
+                    )
+                }
+                EntryTypes::InterpreterRegistration(interpreter_registration) => {
+                    validate_delete_interpreter_registration(
+                        delete_entry.clone().action,
+                        original_action,
+                        interpreter_registration,
+                    )
+                }
+                EntryTypes::DeviceBinding(device_binding) => validate_delete_device_binding(
+                    delete_entry.clone().action,
+                    original_action,
+                    device_binding,
+                ),
+                EntryTypes::CallActivity(call_activity) => validate_delete_call_activity(
+                    delete_entry.clone().action,
+                    original_action,
+                    call_activity,
+                ),
+                EntryTypes::BlockList(block_list) => validate_delete_block_list(
+                    delete_entry.clone().action,
+                    original_action,
+                    block_list,
+                ),
+                EntryTypes::AgentStatus(agent_status) => validate_delete_agent_status(
+                    delete_entry.clone().action,
+                    original_action,
+                    agent_status,
+                ),
+                EntryTypes::MailboxItem(mailbox_item) => validate_delete_mailbox_item(
+                    delete_entry.clone().action,
+                    original_action,
+                    mailbox_item,
+                ),
+                EntryTypes::ConnectionBinding(connection_binding) => {
+                    validate_delete_connection_binding(
+                        delete_entry.clone().action,
+                        original_action,
+                        connection_binding,
+                    )
+                }
+                EntryTypes::DeprecationNotice(deprecation_notice) => {
+                    validate_delete_deprecation_notice(
+                        delete_entry.clone().action,
+                        original_action,
+                        deprecation_notice,
+                    )
+                }
+                EntryTypes::ChatMessage(chat_message) => validate_delete_chat_message(
+                    delete_entry.clone().action,
+                    original_action,
+                    chat_message,
+                ),
+                EntryTypes::Question(question) => {
+                    validate_delete_question(delete_entry.clone().action, original_action, question)
+                }
+                EntryTypes::CheckIn(check_in) => {
+                    validate_delete_check_in(delete_entry.clone().action, original_action, check_in)
+                }
+                EntryTypes::ChatReadMarker(chat_read_marker) => validate_delete_chat_read_marker(
+                    delete_entry.clone().action,
+                    original_action,
+                    chat_read_marker,
+                ),
+                EntryTypes::EventSubscription(event_subscription) => {
+                    validate_delete_event_subscription(
+                        delete_entry.clone().action,
+                        original_action,
+                        event_subscription,
+                    )
+                }
+                EntryTypes::SiblingLobbies(sibling_lobbies) => validate_delete_sibling_lobbies(
+                    delete_entry.clone().action,
+                    original_action,
+                    sibling_lobbies,
+                ),
+                EntryTypes::DirectoryListing(directory_listing) => {
+                    validate_delete_directory_listing(
+                        delete_entry.clone().action,
+                        original_action,
+                        directory_listing,
+                    )
+                }
+                EntryTypes::RoleAssignment(role_assignment) => validate_delete_role_assignment(
+                    delete_entry.clone().action,
+                    original_action,
+                    role_assignment,
+                ),
+                EntryTypes::AgentBan(agent_ban) => validate_delete_agent_ban(
+                    delete_entry.clone().action,
+                    original_action,
+                    agent_ban,
+                ),
+                EntryTypes::CallHandOff(call_hand_off) => validate_delete_call_hand_off(
+                    delete_entry.clone().action,
+                    original_action,
+                    call_hand_off,
+                ),
+                EntryTypes::PermissionOverride(permission_override) => {
+                    validate_delete_permission_override(
+                        delete_entry.clone().action,
+                        original_action,
+                        permission_override,
+                    )
+                }
+                EntryTypes::OwnershipClaim(ownership_claim) => validate_delete_ownership_claim(
+                    delete_entry.clone().action,
+                    original_action,
+                    ownership_claim,
+                ),
+                EntryTypes::AdmissionRequest(admission_request) => {
+                    validate_delete_admission_request(
+                        delete_entry.clone().action,
+                        original_action,
+                        admission_request,
+                    )
+                }
+                EntryTypes::Admission(admission) => validate_delete_admission(
+                    delete_entry.clone().action,
+                    original_action,
+                    admission,
+                ),
+                EntryTypes::SignalTrace(signal_trace) => validate_delete_signal_trace(
+                    delete_entry.clone().action,
+                    original_action,
+                    signal_trace,
+                ),
+                EntryTypes::DebugLevelPreference(debug_level_preference) => {
+                    validate_delete_debug_level_preference(
+                        delete_entry.clone().action,
+                        original_action,
+                        debug_level_preference,
+                    )
+                }
+                EntryTypes::HostTransfer(host_transfer) => validate_delete_host_transfer(
+                    delete_entry.clone().action,
+                    original_action,
+                    host_transfer,
+                ),
+                EntryTypes::CallSessionEnd(call_session_end) => validate_delete_call_session_end(
+                    delete_entry.clone().action,
+                    original_action,
+                    call_session_end,
+                ),
+                EntryTypes::ModerationAction(moderation_action) => {
+                    validate_delete_moderation_action(
+                        delete_entry.clone().action,
+                        original_action,
+                        moderation_action,
+                    )
+                }
+                EntryTypes::BreakoutTimer(breakout_timer) => validate_delete_breakout_timer(
+                    delete_entry.clone().action,
+                    original_action,
+                    breakout_timer,
+                ),
+                EntryTypes::MinutesFinalized(minutes_finalized) => {
+                    validate_delete_minutes_finalized(
+                        delete_entry.clone().action,
+                        original_action,
+                        minutes_finalized,
+                    )
+                }
+                EntryTypes::Announcement(announcement) => validate_delete_announcement(
+                    delete_entry.clone().action,
+                    original_action,
+                    announcement,
+                ),
+                EntryTypes::PresenterState(presenter_state) => validate_delete_presenter_state(
+                    delete_entry.clone().action,
+                    original_action,
+                    presenter_state,
+                ),
+                EntryTypes::PrivacyModePreference(privacy_mode_preference) => {
+                    validate_delete_privacy_mode_preference(
+                        delete_entry.clone().action,
+                        original_action,
+                        privacy_mode_preference,
+                    )
+                }
+                EntryTypes::MediaKeyShare(media_key_share) => validate_delete_media_key_share(
+                    delete_entry.clone().action,
+                    original_action,
+                    media_key_share,
+                ),
+                EntryTypes::Task(task) => {
+                    validate_delete_task(delete_entry.clone().action, original_action, task)
+                }
+                EntryTypes::PromptDeck(prompt_deck) => validate_delete_prompt_deck(
+                    delete_entry.clone().action,
+                    original_action,
+                    prompt_deck,
+                ),
+                EntryTypes::SpeakingGrant(speaking_grant) => validate_delete_speaking_grant(
+                    delete_entry.clone().action,
+                    original_action,
+                    speaking_grant,
+                ),
+                EntryTypes::AttendanceAttestation(attendance_attestation) => {
+                    validate_delete_attendance_attestation(
+                        delete_entry.clone().action,
+                        original_action,
+                        attendance_attestation,
+                    )
+                }
+                EntryTypes::AttachmentAccess(attachment_access) => {
+                    validate_delete_attachment_access(
+                        delete_entry.clone().action,
+                        original_action,
+                        attachment_access,
+                    )
+                }
+                EntryTypes::MinutesCoSignerConsent(minutes_co_signer_consent) => {
+                    validate_delete_minutes_co_signer_consent(
+                        delete_entry.clone().action,
+                        original_action,
+                        minutes_co_signer_consent,
+                    )
+                }
+                EntryTypes::HostTransferConsent(host_transfer_consent) => {
+                    validate_delete_host_transfer_consent(
+                        delete_entry.clone().action,
+                        original_action,
+                        host_transfer_consent,
+                    )
+                }
+            }
+        }
+        FlatOp::RegisterCreateLink {
+            link_type,
+            base_address,
+            target_address,
+            tag,
+            action,
+        } => match link_type {
+            LinkTypes::RoomInfoUpdates => {
+                validate_create_link_room_info_updates(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllAgents => {
+                validate_create_link_all_agents(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllDescendentRooms => {
+                validate_create_link_all_descendent_rooms(action, base_address, target_address, tag)
+            }
+            LinkTypes::AttachmentUpdates => {
+                validate_create_link_attachment_updates(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllAttachments => {
+                validate_create_link_all_attachments(action, base_address, target_address, tag)
+            }
+            LinkTypes::ExternalReferences => {
+                validate_create_link_external_references(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllBreakoutAssignments => validate_create_link_all_breakout_assignments(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToBreakoutAssignments => {
+                validate_create_link_agent_to_breakout_assignments(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::RoomSettingsUpdates => validate_create_link_room_settings_updates(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToCaptionPreferences => {
+                validate_create_link_agent_to_caption_preferences(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllInterpreterRegistrations => {
+                validate_create_link_all_interpreter_registrations(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AgentToDeviceBindings => validate_create_link_agent_to_device_bindings(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::DeprecationNoticeUpdates => validate_create_link_deprecation_notice_updates(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::TrashedAttachments => {
+                validate_create_link_trashed_attachments(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllChatMessages => {
+                validate_create_link_all_chat_messages(action, base_address, target_address, tag)
+            }
+            LinkTypes::ChatMessageUpdates => {
+                validate_create_link_chat_message_updates(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllQuestions => {
+                validate_create_link_all_questions(action, base_address, target_address, tag)
+            }
+            LinkTypes::QuestionUpvotes => {
+                validate_create_link_question_upvotes(action, base_address, target_address, tag)
+            }
+            LinkTypes::AnsweredQuestions => {
+                validate_create_link_answered_questions(action, base_address, target_address, tag)
+            }
+            LinkTypes::ChatMessageReplies => {
+                validate_create_link_chat_message_replies(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllCheckIns => {
+                validate_create_link_all_check_ins(action, base_address, target_address, tag)
+            }
+            LinkTypes::PinnedContent => {
+                validate_create_link_pinned_content(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllEventSubscriptions => validate_create_link_all_event_subscriptions(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::DirectoryListingUpdates => validate_create_link_directory_listing_updates(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllRoleAssignments => {
+                validate_create_link_all_role_assignments(action, base_address, target_address, tag)
+            }
+            LinkTypes::AgentToRoleAssignments => validate_create_link_agent_to_role_assignments(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::BannedAgents => {
+                validate_create_link_banned_agents(action, base_address, target_address, tag)
+            }
+            LinkTypes::AgentToPermissionOverrides => {
+                validate_create_link_agent_to_permission_overrides(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllOwnershipClaims => {
+                validate_create_link_all_ownership_claims(action, base_address, target_address, tag)
+            }
+            LinkTypes::OwnershipClaimVetoes => validate_create_link_ownership_claim_vetoes(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToAdmissions => {
+                validate_create_link_agent_to_admissions(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllHostTransfers => {
+                validate_create_link_all_host_transfers(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllCallSessionEnds => validate_create_link_all_call_session_ends(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ModerationLog => {
+                validate_create_link_moderation_log(action, base_address, target_address, tag)
+            }
+            LinkTypes::MinutesApprovals => {
+                validate_create_link_minutes_approvals(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllAnnouncements => {
+                validate_create_link_all_announcements(action, base_address, target_address, tag)
+            }
+            LinkTypes::PresenterStateUpdates => validate_create_link_presenter_state_updates(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::PresenceHeartbeats => {
+                validate_create_link_presence_heartbeats(action, base_address, target_address, tag)
+            }
+            LinkTypes::TaskUpdates => {
+                validate_create_link_task_updates(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllTasks => {
+                validate_create_link_all_tasks(action, base_address, target_address, tag)
+            }
+            LinkTypes::PromptDeckUpdates => {
+                validate_create_link_prompt_deck_updates(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllSpeakingGrants => {
+                validate_create_link_all_speaking_grants(action, base_address, target_address, tag)
+            }
+            LinkTypes::AgentToAttendanceAttestations => {
+                validate_create_link_agent_to_attendance_attestations(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::CallHeartbeats => {
+                validate_create_link_call_heartbeats(action, base_address, target_address, tag)
+            }
+        },
+        FlatOp::RegisterDeleteLink {
+            link_type,
+            base_address,
+            target_address,
+            tag,
+            original_action,
+            action,
+        } => match link_type {
+            LinkTypes::RoomInfoUpdates => validate_delete_link_room_info_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllAgents => validate_delete_link_all_agents(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllDescendentRooms => validate_delete_link_all_descendent_rooms(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AttachmentUpdates => validate_delete_link_attachment_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllAttachments => validate_delete_link_all_attachments(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ExternalReferences => validate_delete_link_external_references(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllBreakoutAssignments => validate_delete_link_all_breakout_assignments(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToBreakoutAssignments => {
+                validate_delete_link_agent_to_breakout_assignments(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::RoomSettingsUpdates => validate_delete_link_room_settings_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToCaptionPreferences => {
+                validate_delete_link_agent_to_caption_preferences(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllInterpreterRegistrations => {
+                validate_delete_link_all_interpreter_registrations(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AgentToDeviceBindings => validate_delete_link_agent_to_device_bindings(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::DeprecationNoticeUpdates => validate_delete_link_deprecation_notice_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::TrashedAttachments => validate_delete_link_trashed_attachments(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllChatMessages => validate_delete_link_all_chat_messages(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ChatMessageUpdates => validate_delete_link_chat_message_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllQuestions => validate_delete_link_all_questions(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::QuestionUpvotes => validate_delete_link_question_upvotes(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AnsweredQuestions => validate_delete_link_answered_questions(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ChatMessageReplies => validate_delete_link_chat_message_replies(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllCheckIns => validate_delete_link_all_check_ins(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::PinnedContent => validate_delete_link_pinned_content(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllEventSubscriptions => validate_delete_link_all_event_subscriptions(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::DirectoryListingUpdates => validate_delete_link_directory_listing_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllRoleAssignments => validate_delete_link_all_role_assignments(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToRoleAssignments => validate_delete_link_agent_to_role_assignments(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::BannedAgents => validate_delete_link_banned_agents(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToPermissionOverrides => {
+                validate_delete_link_agent_to_permission_overrides(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllOwnershipClaims => validate_delete_link_all_ownership_claims(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::OwnershipClaimVetoes => validate_delete_link_ownership_claim_vetoes(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToAdmissions => validate_delete_link_agent_to_admissions(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllHostTransfers => validate_delete_link_all_host_transfers(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllCallSessionEnds => validate_delete_link_all_call_session_ends(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ModerationLog => validate_delete_link_moderation_log(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::MinutesApprovals => validate_delete_link_minutes_approvals(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllAnnouncements => validate_delete_link_all_announcements(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::PresenterStateUpdates => validate_delete_link_presenter_state_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::PresenceHeartbeats => validate_delete_link_presence_heartbeats(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::TaskUpdates => validate_delete_link_task_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllTasks => validate_delete_link_all_tasks(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::PromptDeckUpdates => validate_delete_link_prompt_deck_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllSpeakingGrants => validate_delete_link_all_speaking_grants(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToAttendanceAttestations => {
+                validate_delete_link_agent_to_attendance_attestations(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::CallHeartbeats => validate_delete_link_call_heartbeats(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+        },
+        FlatOp::StoreRecord(store_record) => match store_record {
+            OpRecord::CreateEntry { app_entry, action } => match app_entry {
+                EntryTypes::RoomInfo(room_info) => {
+                    validate_create_room_info(EntryCreationAction::Create(action), room_info)
+                }
+                EntryTypes::Attachment(attachment) => {
+                    validate_create_attachment(EntryCreationAction::Create(action), attachment)
+                }
+                EntryTypes::DescendentRoom(descendent_room) => validate_create_descendent_room(
+                    EntryCreationAction::Create(action),
+                    descendent_room,
+                ),
+                EntryTypes::ExternalReference(external_reference) => {
+                    validate_create_external_reference(
+                        EntryCreationAction::Create(action),
+                        external_reference,
+                    )
+                }
+                EntryTypes::BreakoutAssignment(breakout_assignment) => {
+                    validate_create_breakout_assignment(
+                        EntryCreationAction::Create(action),
+                        breakout_assignment,
+                    )
+                }
+                EntryTypes::RoomSettings(room_settings) => validate_create_room_settings(
+                    EntryCreationAction::Create(action),
+                    room_settings,
+                ),
+                EntryTypes::CaptionPreference(caption_preference) => {
+                    validate_create_caption_preference(
+                        EntryCreationAction::Create(action),
+                        caption_preference,
+                    )
+                }
+                EntryTypes::InterpreterRegistration(interpreter_registration) => {
+                    validate_create_interpreter_registration(
+                        EntryCreationAction::Create(action),
+                        interpreter_registration,
+                    )
+                }
+                EntryTypes::DeviceBinding(device_binding) => validate_create_device_binding(
+                    EntryCreationAction::Create(action),
+                    device_binding,
+                ),
+                EntryTypes::CallActivity(call_activity) => validate_create_call_activity(
+                    EntryCreationAction::Create(action),
+                    call_activity,
+                ),
+                EntryTypes::BlockList(block_list) => {
+                    validate_create_block_list(EntryCreationAction::Create(action), block_list)
+                }
+                EntryTypes::AgentStatus(agent_status) => {
+                    validate_create_agent_status(EntryCreationAction::Create(action), agent_status)
+                }
+                EntryTypes::MailboxItem(mailbox_item) => {
+                    validate_create_mailbox_item(EntryCreationAction::Create(action), mailbox_item)
+                }
+                EntryTypes::ConnectionBinding(connection_binding) => {
+                    validate_create_connection_binding(
+                        EntryCreationAction::Create(action),
+                        connection_binding,
+                    )
+                }
+                EntryTypes::DeprecationNotice(deprecation_notice) => {
+                    validate_create_deprecation_notice(
+                        EntryCreationAction::Create(action),
+                        deprecation_notice,
+                    )
+                }
+                EntryTypes::ChatMessage(chat_message) => {
+                    validate_create_chat_message(EntryCreationAction::Create(action), chat_message)
+                }
+                EntryTypes::Question(question) => {
+                    validate_create_question(EntryCreationAction::Create(action), question)
+                }
+                EntryTypes::CheckIn(check_in) => {
+                    validate_create_check_in(EntryCreationAction::Create(action), check_in)
+                }
+                EntryTypes::ChatReadMarker(chat_read_marker) => validate_create_chat_read_marker(
+                    EntryCreationAction::Create(action),
+                    chat_read_marker,
+                ),
+                EntryTypes::EventSubscription(event_subscription) => {
+                    validate_create_event_subscription(
+                        EntryCreationAction::Create(action),
+                        event_subscription,
+                    )
+                }
+                EntryTypes::SiblingLobbies(sibling_lobbies) => validate_create_sibling_lobbies(
+                    EntryCreationAction::Create(action),
+                    sibling_lobbies,
+                ),
+                EntryTypes::DirectoryListing(directory_listing) => {
+                    validate_create_directory_listing(
+                        EntryCreationAction::Create(action),
+                        directory_listing,
+                    )
+                }
+                EntryTypes::RoleAssignment(role_assignment) => validate_create_role_assignment(
+                    EntryCreationAction::Create(action),
+                    role_assignment,
+                ),
+                EntryTypes::AgentBan(agent_ban) => {
+                    validate_create_agent_ban(EntryCreationAction::Create(action), agent_ban)
+                }
+                EntryTypes::CallHandOff(call_hand_off) => validate_create_call_hand_off(
+                    EntryCreationAction::Create(action),
+                    call_hand_off,
+                ),
+                EntryTypes::PermissionOverride(permission_override) => {
+                    validate_create_permission_override(
+                        EntryCreationAction::Create(action),
+                        permission_override,
+                    )
+                }
+                EntryTypes::OwnershipClaim(ownership_claim) => validate_create_ownership_claim(
+                    EntryCreationAction::Create(action),
+                    ownership_claim,
+                ),
+                EntryTypes::AdmissionRequest(admission_request) => {
+                    validate_create_admission_request(
+                        EntryCreationAction::Create(action),
+                        admission_request,
+                    )
+                }
+                EntryTypes::Admission(admission) => {
+                    validate_create_admission(EntryCreationAction::Create(action), admission)
+                }
+                EntryTypes::SignalTrace(signal_trace) => {
+                    validate_create_signal_trace(EntryCreationAction::Create(action), signal_trace)
+                }
+                EntryTypes::DebugLevelPreference(debug_level_preference) => {
+                    validate_create_debug_level_preference(
+                        EntryCreationAction::Create(action),
+                        debug_level_preference,
+                    )
+                }
+                EntryTypes::HostTransfer(host_transfer) => validate_create_host_transfer(
+                    EntryCreationAction::Create(action),
+                    host_transfer,
+                ),
+                EntryTypes::CallSessionEnd(call_session_end) => validate_create_call_session_end(
+                    EntryCreationAction::Create(action),
+                    call_session_end,
+                ),
+                EntryTypes::ModerationAction(moderation_action) => {
+                    validate_create_moderation_action(
+                        EntryCreationAction::Create(action),
+                        moderation_action,
+                    )
+                }
+                EntryTypes::BreakoutTimer(breakout_timer) => validate_create_breakout_timer(
+                    EntryCreationAction::Create(action),
+                    breakout_timer,
+                ),
+                EntryTypes::MinutesFinalized(minutes_finalized) => {
+                    validate_create_minutes_finalized(
+                        EntryCreationAction::Create(action),
+                        minutes_finalized,
+                    )
+                }
+                EntryTypes::Announcement(announcement) => {
+                    validate_create_announcement(EntryCreationAction::Create(action), announcement)
+                }
+                EntryTypes::PresenterState(presenter_state) => validate_create_presenter_state(
+                    EntryCreationAction::Create(action),
+                    presenter_state,
+                ),
+                EntryTypes::PrivacyModePreference(privacy_mode_preference) => {
+                    validate_create_privacy_mode_preference(
+                        EntryCreationAction::Create(action),
+                        privacy_mode_preference,
+                    )
+                }
+                EntryTypes::MediaKeyShare(media_key_share) => validate_create_media_key_share(
+                    EntryCreationAction::Create(action),
+                    media_key_share,
+                ),
+                EntryTypes::Task(task) => {
+                    validate_create_task(EntryCreationAction::Create(action), task)
+                }
+                EntryTypes::PromptDeck(prompt_deck) => {
+                    validate_create_prompt_deck(EntryCreationAction::Create(action), prompt_deck)
+                }
+                EntryTypes::SpeakingGrant(speaking_grant) => validate_create_speaking_grant(
+                    EntryCreationAction::Create(action),
+                    speaking_grant,
+                ),
+                EntryTypes::AttendanceAttestation(attendance_attestation) => {
+                    validate_create_attendance_attestation(
+                        EntryCreationAction::Create(action),
+                        attendance_attestation,
+                    )
+                }
+                EntryTypes::AttachmentAccess(attachment_access) => {
+                    validate_create_attachment_access(
+                        EntryCreationAction::Create(action),
+                        attachment_access,
+                    )
+                }
+                EntryTypes::MinutesCoSignerConsent(minutes_co_signer_consent) => {
+                    validate_create_minutes_co_signer_consent(
+                        EntryCreationAction::Create(action),
+                        minutes_co_signer_consent,
+                    )
+                }
+                EntryTypes::HostTransferConsent(host_transfer_consent) => {
+                    validate_create_host_transfer_consent(
+                        EntryCreationAction::Create(action),
+                        host_transfer_consent,
+                    )
+                }
             },
             OpRecord::UpdateEntry {
                 original_action_hash,
@@ -292,98 +2617,1481 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         return Ok(ValidateCallbackResult::Invalid(
                             "Original action for an update must be a Create or Update action"
                                 .to_string(),
-                        ));
+                        ));
+                    }
+                };
+                match app_entry {
+                    EntryTypes::RoomInfo(room_info) => {
+                        let result = validate_create_room_info(
+                            EntryCreationAction::Update(action.clone()),
+                            room_info.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_room_info: Option<RoomInfo> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_room_info = match original_room_info {
+                                Some(room_info) => room_info,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_room_info(
+                                action,
+                                room_info,
+                                original_action,
+                                original_room_info,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::Attachment(attachment) => {
+                        let result = validate_create_attachment(
+                            EntryCreationAction::Update(action.clone()),
+                            attachment.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_attachment: Option<Attachment> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_attachment = match original_attachment {
+                                Some(attachment) => attachment,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_attachment(
+                                action,
+                                attachment,
+                                original_action,
+                                original_attachment,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::DescendentRoom(descendent_room) => {
+                        let result = validate_create_descendent_room(
+                            EntryCreationAction::Update(action.clone()),
+                            descendent_room.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_descendent_room: Option<DescendentRoom> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_descendent_room = match original_descendent_room {
+                                Some(descendent_room) => descendent_room,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_descendent_room(
+                                action,
+                                descendent_room,
+                                original_action,
+                                original_descendent_room,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::ExternalReference(external_reference) => {
+                        let result = validate_create_external_reference(
+                            EntryCreationAction::Update(action.clone()),
+                            external_reference.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_external_reference: Option<ExternalReference> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_external_reference = match original_external_reference {
+                                Some(external_reference) => external_reference,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_external_reference(
+                                action,
+                                external_reference,
+                                original_action,
+                                original_external_reference,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::BreakoutAssignment(breakout_assignment) => {
+                        let result = validate_create_breakout_assignment(
+                            EntryCreationAction::Update(action.clone()),
+                            breakout_assignment.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_breakout_assignment: Option<BreakoutAssignment> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_breakout_assignment = match original_breakout_assignment {
+                                Some(breakout_assignment) => breakout_assignment,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_breakout_assignment(
+                                action,
+                                breakout_assignment,
+                                original_action,
+                                original_breakout_assignment,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::RoomSettings(room_settings) => {
+                        let result = validate_create_room_settings(
+                            EntryCreationAction::Update(action.clone()),
+                            room_settings.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_room_settings: Option<RoomSettings> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_room_settings = match original_room_settings {
+                                Some(room_settings) => room_settings,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_room_settings(
+                                action,
+                                room_settings,
+                                original_action,
+                                original_room_settings,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::CaptionPreference(caption_preference) => {
+                        let result = validate_create_caption_preference(
+                            EntryCreationAction::Update(action.clone()),
+                            caption_preference.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_caption_preference: Option<CaptionPreference> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_caption_preference = match original_caption_preference {
+                                Some(caption_preference) => caption_preference,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_caption_preference(
+                                action,
+                                caption_preference,
+                                original_action,
+                                original_caption_preference,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::InterpreterRegistration(interpreter_registration) => {
+                        let result = validate_create_interpreter_registration(
+                            EntryCreationAction::Update(action.clone()),
+                            interpreter_registration.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_interpreter_registration: Option<InterpreterRegistration> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_interpreter_registration =
+                                match original_interpreter_registration {
+                                    Some(interpreter_registration) => interpreter_registration,
+                                    None => {
+                                        return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                    }
+                                };
+                            validate_update_interpreter_registration(
+                                action,
+                                interpreter_registration,
+                                original_action,
+                                original_interpreter_registration,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::DeviceBinding(device_binding) => {
+                        let result = validate_create_device_binding(
+                            EntryCreationAction::Update(action.clone()),
+                            device_binding.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_device_binding: Option<DeviceBinding> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_device_binding = match original_device_binding {
+                                Some(device_binding) => device_binding,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_device_binding(
+                                action,
+                                device_binding,
+                                original_action,
+                                original_device_binding,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::CallActivity(call_activity) => {
+                        let result = validate_create_call_activity(
+                            EntryCreationAction::Update(action.clone()),
+                            call_activity.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_call_activity: Option<CallActivity> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_call_activity = match original_call_activity {
+                                Some(call_activity) => call_activity,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_call_activity(
+                                action,
+                                call_activity,
+                                original_action,
+                                original_call_activity,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::BlockList(block_list) => {
+                        let result = validate_create_block_list(
+                            EntryCreationAction::Update(action.clone()),
+                            block_list.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_block_list: Option<BlockList> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_block_list = match original_block_list {
+                                Some(block_list) => block_list,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_block_list(
+                                action,
+                                block_list,
+                                original_action,
+                                original_block_list,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::AgentStatus(agent_status) => {
+                        let result = validate_create_agent_status(
+                            EntryCreationAction::Update(action.clone()),
+                            agent_status.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_agent_status: Option<AgentStatus> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_agent_status = match original_agent_status {
+                                Some(agent_status) => agent_status,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_agent_status(
+                                action,
+                                agent_status,
+                                original_action,
+                                original_agent_status,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::MailboxItem(mailbox_item) => {
+                        let result = validate_create_mailbox_item(
+                            EntryCreationAction::Update(action.clone()),
+                            mailbox_item.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_mailbox_item: Option<MailboxItem> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_mailbox_item = match original_mailbox_item {
+                                Some(mailbox_item) => mailbox_item,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_mailbox_item(
+                                action,
+                                mailbox_item,
+                                original_action,
+                                original_mailbox_item,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::ConnectionBinding(connection_binding) => {
+                        let result = validate_create_connection_binding(
+                            EntryCreationAction::Update(action.clone()),
+                            connection_binding.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_connection_binding: Option<ConnectionBinding> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_connection_binding = match original_connection_binding {
+                                Some(connection_binding) => connection_binding,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_connection_binding(
+                                action,
+                                connection_binding,
+                                original_action,
+                                original_connection_binding,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::DeprecationNotice(deprecation_notice) => {
+                        let result = validate_create_deprecation_notice(
+                            EntryCreationAction::Update(action.clone()),
+                            deprecation_notice.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_deprecation_notice: Option<DeprecationNotice> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_deprecation_notice = match original_deprecation_notice {
+                                Some(deprecation_notice) => deprecation_notice,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_deprecation_notice(
+                                action,
+                                deprecation_notice,
+                                original_action,
+                                original_deprecation_notice,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::ChatMessage(chat_message) => {
+                        let result = validate_create_chat_message(
+                            EntryCreationAction::Update(action.clone()),
+                            chat_message.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_chat_message: Option<ChatMessage> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_chat_message = match original_chat_message {
+                                Some(chat_message) => chat_message,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_chat_message(
+                                action,
+                                chat_message,
+                                original_action,
+                                original_chat_message,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::Question(question) => {
+                        let result = validate_create_question(
+                            EntryCreationAction::Update(action.clone()),
+                            question.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_question: Option<Question> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_question = match original_question {
+                                Some(question) => question,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_question(
+                                action,
+                                question,
+                                original_action,
+                                original_question,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::CheckIn(check_in) => {
+                        let result = validate_create_check_in(
+                            EntryCreationAction::Update(action.clone()),
+                            check_in.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_check_in: Option<CheckIn> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_check_in = match original_check_in {
+                                Some(check_in) => check_in,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_check_in(
+                                action,
+                                check_in,
+                                original_action,
+                                original_check_in,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::ChatReadMarker(chat_read_marker) => {
+                        let result = validate_create_chat_read_marker(
+                            EntryCreationAction::Update(action.clone()),
+                            chat_read_marker.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_chat_read_marker: Option<ChatReadMarker> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_chat_read_marker = match original_chat_read_marker {
+                                Some(chat_read_marker) => chat_read_marker,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_chat_read_marker(
+                                action,
+                                chat_read_marker,
+                                original_action,
+                                original_chat_read_marker,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::EventSubscription(event_subscription) => {
+                        let result = validate_create_event_subscription(
+                            EntryCreationAction::Update(action.clone()),
+                            event_subscription.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_event_subscription: Option<EventSubscription> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_event_subscription = match original_event_subscription {
+                                Some(event_subscription) => event_subscription,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_event_subscription(
+                                action,
+                                event_subscription,
+                                original_action,
+                                original_event_subscription,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::SiblingLobbies(sibling_lobbies) => {
+                        let result = validate_create_sibling_lobbies(
+                            EntryCreationAction::Update(action.clone()),
+                            sibling_lobbies.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_sibling_lobbies: Option<SiblingLobbies> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_sibling_lobbies = match original_sibling_lobbies {
+                                Some(sibling_lobbies) => sibling_lobbies,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_sibling_lobbies(
+                                action,
+                                sibling_lobbies,
+                                original_action,
+                                original_sibling_lobbies,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::DirectoryListing(directory_listing) => {
+                        let result = validate_create_directory_listing(
+                            EntryCreationAction::Update(action.clone()),
+                            directory_listing.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_directory_listing: Option<DirectoryListing> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_directory_listing = match original_directory_listing {
+                                Some(directory_listing) => directory_listing,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_directory_listing(
+                                action,
+                                directory_listing,
+                                original_action,
+                                original_directory_listing,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::RoleAssignment(role_assignment) => {
+                        let result = validate_create_role_assignment(
+                            EntryCreationAction::Update(action.clone()),
+                            role_assignment.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_role_assignment: Option<RoleAssignment> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_role_assignment = match original_role_assignment {
+                                Some(role_assignment) => role_assignment,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_role_assignment(
+                                action,
+                                role_assignment,
+                                original_action,
+                                original_role_assignment,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::AgentBan(agent_ban) => {
+                        let result = validate_create_agent_ban(
+                            EntryCreationAction::Update(action.clone()),
+                            agent_ban.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_agent_ban: Option<AgentBan> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_agent_ban = match original_agent_ban {
+                                Some(agent_ban) => agent_ban,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_agent_ban(
+                                action,
+                                agent_ban,
+                                original_action,
+                                original_agent_ban,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::CallHandOff(call_hand_off) => {
+                        let result = validate_create_call_hand_off(
+                            EntryCreationAction::Update(action.clone()),
+                            call_hand_off.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_call_hand_off: Option<CallHandOff> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_call_hand_off = match original_call_hand_off {
+                                Some(call_hand_off) => call_hand_off,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_call_hand_off(
+                                action,
+                                call_hand_off,
+                                original_action,
+                                original_call_hand_off,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::PermissionOverride(permission_override) => {
+                        let result = validate_create_permission_override(
+                            EntryCreationAction::Update(action.clone()),
+                            permission_override.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_permission_override: Option<PermissionOverride> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_permission_override = match original_permission_override {
+                                Some(permission_override) => permission_override,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_permission_override(
+                                action,
+                                permission_override,
+                                original_action,
+                                original_permission_override,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::OwnershipClaim(ownership_claim) => {
+                        let result = validate_create_ownership_claim(
+                            EntryCreationAction::Update(action.clone()),
+                            ownership_claim.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_ownership_claim: Option<OwnershipClaim> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_ownership_claim = match original_ownership_claim {
+                                Some(ownership_claim) => ownership_claim,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_ownership_claim(
+                                action,
+                                ownership_claim,
+                                original_action,
+                                original_ownership_claim,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::AdmissionRequest(admission_request) => {
+                        let result = validate_create_admission_request(
+                            EntryCreationAction::Update(action.clone()),
+                            admission_request.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_admission_request: Option<AdmissionRequest> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_admission_request = match original_admission_request {
+                                Some(admission_request) => admission_request,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_admission_request(
+                                action,
+                                admission_request,
+                                original_action,
+                                original_admission_request,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::Admission(admission) => {
+                        let result = validate_create_admission(
+                            EntryCreationAction::Update(action.clone()),
+                            admission.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_admission: Option<Admission> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_admission = match original_admission {
+                                Some(admission) => admission,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_admission(
+                                action,
+                                admission,
+                                original_action,
+                                original_admission,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::SignalTrace(signal_trace) => {
+                        let result = validate_create_signal_trace(
+                            EntryCreationAction::Update(action.clone()),
+                            signal_trace.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_signal_trace: Option<SignalTrace> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_signal_trace = match original_signal_trace {
+                                Some(signal_trace) => signal_trace,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_signal_trace(
+                                action,
+                                signal_trace,
+                                original_action,
+                                original_signal_trace,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::DebugLevelPreference(debug_level_preference) => {
+                        let result = validate_create_debug_level_preference(
+                            EntryCreationAction::Update(action.clone()),
+                            debug_level_preference.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_debug_level_preference: Option<DebugLevelPreference> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_debug_level_preference =
+                                match original_debug_level_preference {
+                                    Some(debug_level_preference) => debug_level_preference,
+                                    None => {
+                                        return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                    }
+                                };
+                            validate_update_debug_level_preference(
+                                action,
+                                debug_level_preference,
+                                original_action,
+                                original_debug_level_preference,
+                            )
+                        } else {
+                            Ok(result)
+                        }
                     }
-                };
-                match app_entry {
-                    EntryTypes::RoomInfo(room_info) => {
-                        let result = validate_create_room_info(
+                    EntryTypes::HostTransfer(host_transfer) => {
+                        let result = validate_create_host_transfer(
                             EntryCreationAction::Update(action.clone()),
-                            room_info.clone(),
+                            host_transfer.clone(),
                         )?;
                         if let ValidateCallbackResult::Valid = result {
-                            let original_room_info: Option<RoomInfo> = original_record
+                            let original_host_transfer: Option<HostTransfer> = original_record
                                 .entry()
                                 .to_app_option()
                                 .map_err(|e| wasm_error!(e))?;
-                            let original_room_info = match original_room_info {
-                                Some(room_info) => room_info,
+                            let original_host_transfer = match original_host_transfer {
+                                Some(host_transfer) => host_transfer,
                                 None => {
                                     return Ok(
-                                            ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
-                                            ),
-                                        );
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
                                 }
                             };
-                            validate_update_room_info(
+                            validate_update_host_transfer(
                                 action,
-                                room_info,
+                                host_transfer,
                                 original_action,
-                                original_room_info,
+                                original_host_transfer,
                             )
                         } else {
                             Ok(result)
                         }
                     }
-                    EntryTypes::Attachment(attachment) => {
-                        let result = validate_create_attachment(
+                    EntryTypes::CallSessionEnd(call_session_end) => {
+                        let result = validate_create_call_session_end(
                             EntryCreationAction::Update(action.clone()),
-                            attachment.clone(),
+                            call_session_end.clone(),
                         )?;
                         if let ValidateCallbackResult::Valid = result {
-                            let original_attachment: Option<Attachment> = original_record
+                            let original_call_session_end: Option<CallSessionEnd> = original_record
                                 .entry()
                                 .to_app_option()
                                 .map_err(|e| wasm_error!(e))?;
-                            let original_attachment = match original_attachment {
-                                Some(attachment) => attachment,
+                            let original_call_session_end = match original_call_session_end {
+                                Some(call_session_end) => call_session_end,
                                 None => {
                                     return Ok(
-                                            ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
-                                            ),
-                                        );
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
                                 }
                             };
-                            validate_update_attachment(
+                            validate_update_call_session_end(
                                 action,
-                                attachment,
+                                call_session_end,
                                 original_action,
-                                original_attachment,
+                                original_call_session_end,
                             )
                         } else {
                             Ok(result)
                         }
                     }
-                    EntryTypes::DescendentRoom(descendent_room) => {
-                        let result = validate_create_descendent_room(
+                    EntryTypes::ModerationAction(moderation_action) => {
+                        let result = validate_create_moderation_action(
                             EntryCreationAction::Update(action.clone()),
-                            descendent_room.clone(),
+                            moderation_action.clone(),
                         )?;
                         if let ValidateCallbackResult::Valid = result {
-                            let original_descendent_room: Option<DescendentRoom> = original_record
+                            let original_moderation_action: Option<ModerationAction> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_moderation_action = match original_moderation_action {
+                                Some(moderation_action) => moderation_action,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_moderation_action(
+                                action,
+                                moderation_action,
+                                original_action,
+                                original_moderation_action,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::BreakoutTimer(breakout_timer) => {
+                        let result = validate_create_breakout_timer(
+                            EntryCreationAction::Update(action.clone()),
+                            breakout_timer.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_breakout_timer: Option<BreakoutTimer> = original_record
                                 .entry()
                                 .to_app_option()
                                 .map_err(|e| wasm_error!(e))?;
-                            let original_descendent_room = match original_descendent_room {
-                                Some(descendent_room) => descendent_room,
+                            let original_breakout_timer = match original_breakout_timer {
+                                Some(breakout_timer) => breakout_timer,
                                 None => {
                                     return Ok(
-                                            ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
-                                            ),
-                                        );
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
                                 }
                             };
-                            validate_update_descendent_room(
+                            validate_update_breakout_timer(
                                 action,
-                                descendent_room,
+                                breakout_timer,
+                                original_action,
+                                original_breakout_timer,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::MinutesFinalized(minutes_finalized) => {
+                        let result = validate_create_minutes_finalized(
+                            EntryCreationAction::Update(action.clone()),
+                            minutes_finalized.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_minutes_finalized: Option<MinutesFinalized> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_minutes_finalized = match original_minutes_finalized {
+                                Some(minutes_finalized) => minutes_finalized,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_minutes_finalized(
+                                action,
+                                minutes_finalized,
+                                original_action,
+                                original_minutes_finalized,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::Announcement(announcement) => {
+                        let result = validate_create_announcement(
+                            EntryCreationAction::Update(action.clone()),
+                            announcement.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_announcement: Option<Announcement> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_announcement = match original_announcement {
+                                Some(announcement) => announcement,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_announcement(
+                                action,
+                                announcement,
+                                original_action,
+                                original_announcement,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::PresenterState(presenter_state) => {
+                        let result = validate_create_presenter_state(
+                            EntryCreationAction::Update(action.clone()),
+                            presenter_state.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_presenter_state: Option<PresenterState> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_presenter_state = match original_presenter_state {
+                                Some(presenter_state) => presenter_state,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_presenter_state(
+                                action,
+                                presenter_state,
+                                original_action,
+                                original_presenter_state,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::PrivacyModePreference(privacy_mode_preference) => {
+                        let result = validate_create_privacy_mode_preference(
+                            EntryCreationAction::Update(action.clone()),
+                            privacy_mode_preference.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_privacy_mode_preference: Option<PrivacyModePreference> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_privacy_mode_preference =
+                                match original_privacy_mode_preference {
+                                    Some(privacy_mode_preference) => privacy_mode_preference,
+                                    None => {
+                                        return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                    }
+                                };
+                            validate_update_privacy_mode_preference(
+                                action,
+                                privacy_mode_preference,
+                                original_action,
+                                original_privacy_mode_preference,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::MediaKeyShare(media_key_share) => {
+                        let result = validate_create_media_key_share(
+                            EntryCreationAction::Update(action.clone()),
+                            media_key_share.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_media_key_share: Option<MediaKeyShare> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_media_key_share = match original_media_key_share {
+                                Some(media_key_share) => media_key_share,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_media_key_share(
+                                action,
+                                media_key_share,
+                                original_action,
+                                original_media_key_share,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::Task(task) => {
+                        let result = validate_create_task(
+                            EntryCreationAction::Update(action.clone()),
+                            task.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_task: Option<Task> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_task = match original_task {
+                                Some(task) => task,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_task(action, task, original_action, original_task)
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::PromptDeck(prompt_deck) => {
+                        let result = validate_create_prompt_deck(
+                            EntryCreationAction::Update(action.clone()),
+                            prompt_deck.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_prompt_deck: Option<PromptDeck> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_prompt_deck = match original_prompt_deck {
+                                Some(prompt_deck) => prompt_deck,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_prompt_deck(
+                                action,
+                                prompt_deck,
+                                original_action,
+                                original_prompt_deck,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::SpeakingGrant(speaking_grant) => {
+                        let result = validate_create_speaking_grant(
+                            EntryCreationAction::Update(action.clone()),
+                            speaking_grant.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_speaking_grant: Option<SpeakingGrant> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_speaking_grant = match original_speaking_grant {
+                                Some(speaking_grant) => speaking_grant,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_speaking_grant(
+                                action,
+                                speaking_grant,
+                                original_action,
+                                original_speaking_grant,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::AttendanceAttestation(attendance_attestation) => {
+                        let result = validate_create_attendance_attestation(
+                            EntryCreationAction::Update(action.clone()),
+                            attendance_attestation.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_attendance_attestation: Option<AttendanceAttestation> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_attendance_attestation =
+                                match original_attendance_attestation {
+                                    Some(attendance_attestation) => attendance_attestation,
+                                    None => {
+                                        return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                    }
+                                };
+                            validate_update_attendance_attestation(
+                                action,
+                                attendance_attestation,
+                                original_action,
+                                original_attendance_attestation,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::AttachmentAccess(attachment_access) => {
+                        let result = validate_create_attachment_access(
+                            EntryCreationAction::Update(action.clone()),
+                            attachment_access.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_attachment_access: Option<AttachmentAccess> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_attachment_access = match original_attachment_access {
+                                Some(attachment_access) => attachment_access,
+                                None => {
+                                    return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                }
+                            };
+                            validate_update_attachment_access(
+                                action,
+                                attachment_access,
+                                original_action,
+                                original_attachment_access,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::MinutesCoSignerConsent(minutes_co_signer_consent) => {
+                        let result = validate_create_minutes_co_signer_consent(
+                            EntryCreationAction::Update(action.clone()),
+                            minutes_co_signer_consent.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_minutes_co_signer_consent: Option<MinutesCoSignerConsent> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_minutes_co_signer_consent =
+                                match original_minutes_co_signer_consent {
+                                    Some(minutes_co_signer_consent) => minutes_co_signer_consent,
+                                    None => {
+                                        return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                    }
+                                };
+                            validate_update_minutes_co_signer_consent(
+                                action,
+                                minutes_co_signer_consent,
+                                original_action,
+                                original_minutes_co_signer_consent,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::HostTransferConsent(host_transfer_consent) => {
+                        let result = validate_create_host_transfer_consent(
+                            EntryCreationAction::Update(action.clone()),
+                            host_transfer_consent.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_host_transfer_consent: Option<HostTransferConsent> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_host_transfer_consent =
+                                match original_host_transfer_consent {
+                                    Some(host_transfer_consent) => host_transfer_consent,
+                                    None => {
+                                        return Ok(
+                        ValidateCallbackResult::Invalid(
+                            "The updated entry type must be the same as the original entry type"
+                                .to_string(),
+                        ),
+                    );
+                                    }
+                                };
+                            validate_update_host_transfer_consent(
+                                action,
+                                host_transfer_consent,
                                 original_action,
-                                original_descendent_room,
+                                original_host_transfer_consent,
                             )
                         } else {
                             Ok(result)
@@ -458,38 +4166,521 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             original_descendent_room,
                         )
                     }
+                    EntryTypes::ExternalReference(original_external_reference) => {
+                        validate_delete_external_reference(
+                            action,
+                            original_action,
+                            original_external_reference,
+                        )
+                    }
+                    EntryTypes::BreakoutAssignment(original_breakout_assignment) => {
+                        validate_delete_breakout_assignment(
+                            action,
+                            original_action,
+                            original_breakout_assignment,
+                        )
+                    }
+                    EntryTypes::RoomSettings(original_room_settings) => {
+                        validate_delete_room_settings(
+                            action,
+                            original_action,
+                            original_room_settings,
+                        )
+                    }
+                    EntryTypes::CaptionPreference(original_caption_preference) => {
+                        validate_delete_caption_preference(
+                            action,
+                            original_action,
+                            original_caption_preference,
+                        )
+                    }
+                    EntryTypes::InterpreterRegistration(original_interpreter_registration) => {
+                        validate_delete_interpreter_registration(
+                            action,
+                            original_action,
+                            original_interpreter_registration,
+                        )
+                    }
+                    EntryTypes::DeviceBinding(original_device_binding) => {
+                        validate_delete_device_binding(
+                            action,
+                            original_action,
+                            original_device_binding,
+                        )
+                    }
+                    EntryTypes::CallActivity(original_call_activity) => {
+                        validate_delete_call_activity(
+                            action,
+                            original_action,
+                            original_call_activity,
+                        )
+                    }
+                    EntryTypes::BlockList(original_block_list) => {
+                        validate_delete_block_list(action, original_action, original_block_list)
+                    }
+                    EntryTypes::AgentStatus(original_agent_status) => {
+                        validate_delete_agent_status(action, original_action, original_agent_status)
+                    }
+                    EntryTypes::MailboxItem(original_mailbox_item) => {
+                        validate_delete_mailbox_item(action, original_action, original_mailbox_item)
+                    }
+                    EntryTypes::ConnectionBinding(original_connection_binding) => {
+                        validate_delete_connection_binding(
+                            action,
+                            original_action,
+                            original_connection_binding,
+                        )
+                    }
+                    EntryTypes::DeprecationNotice(original_deprecation_notice) => {
+                        validate_delete_deprecation_notice(
+                            action,
+                            original_action,
+                            original_deprecation_notice,
+                        )
+                    }
+                    EntryTypes::ChatMessage(original_chat_message) => {
+                        validate_delete_chat_message(action, original_action, original_chat_message)
+                    }
+                    EntryTypes::Question(original_question) => {
+                        validate_delete_question(action, original_action, original_question)
+                    }
+                    EntryTypes::CheckIn(original_check_in) => {
+                        validate_delete_check_in(action, original_action, original_check_in)
+                    }
+                    EntryTypes::ChatReadMarker(original_chat_read_marker) => {
+                        validate_delete_chat_read_marker(
+                            action,
+                            original_action,
+                            original_chat_read_marker,
+                        )
+                    }
+                    EntryTypes::EventSubscription(original_event_subscription) => {
+                        validate_delete_event_subscription(
+                            action,
+                            original_action,
+                            original_event_subscription,
+                        )
+                    }
+                    EntryTypes::SiblingLobbies(original_sibling_lobbies) => {
+                        validate_delete_sibling_lobbies(
+                            action,
+                            original_action,
+                            original_sibling_lobbies,
+                        )
+                    }
+                    EntryTypes::DirectoryListing(original_directory_listing) => {
+                        validate_delete_directory_listing(
+                            action,
+                            original_action,
+                            original_directory_listing,
+                        )
+                    }
+                    EntryTypes::RoleAssignment(original_role_assignment) => {
+                        validate_delete_role_assignment(
+                            action,
+                            original_action,
+                            original_role_assignment,
+                        )
+                    }
+                    EntryTypes::AgentBan(original_agent_ban) => {
+                        validate_delete_agent_ban(action, original_action, original_agent_ban)
+                    }
+                    EntryTypes::CallHandOff(original_call_hand_off) => {
+                        validate_delete_call_hand_off(
+                            action,
+                            original_action,
+                            original_call_hand_off,
+                        )
+                    }
+                    EntryTypes::PermissionOverride(original_permission_override) => {
+                        validate_delete_permission_override(
+                            action,
+                            original_action,
+                            original_permission_override,
+                        )
+                    }
+                    EntryTypes::OwnershipClaim(original_ownership_claim) => {
+                        validate_delete_ownership_claim(
+                            action,
+                            original_action,
+                            original_ownership_claim,
+                        )
+                    }
+                    EntryTypes::AdmissionRequest(original_admission_request) => {
+                        validate_delete_admission_request(
+                            action,
+                            original_action,
+                            original_admission_request,
+                        )
+                    }
+                    EntryTypes::Admission(original_admission) => {
+                        validate_delete_admission(action, original_action, original_admission)
+                    }
+                    EntryTypes::SignalTrace(original_signal_trace) => {
+                        validate_delete_signal_trace(action, original_action, original_signal_trace)
+                    }
+                    EntryTypes::DebugLevelPreference(original_debug_level_preference) => {
+                        validate_delete_debug_level_preference(
+                            action,
+                            original_action,
+                            original_debug_level_preference,
+                        )
+                    }
+                    EntryTypes::HostTransfer(original_host_transfer) => {
+                        validate_delete_host_transfer(
+                            action,
+                            original_action,
+                            original_host_transfer,
+                        )
+                    }
+                    EntryTypes::CallSessionEnd(original_call_session_end) => {
+                        validate_delete_call_session_end(
+                            action,
+                            original_action,
+                            original_call_session_end,
+                        )
+                    }
+                    EntryTypes::ModerationAction(original_moderation_action) => {
+                        validate_delete_moderation_action(
+                            action,
+                            original_action,
+                            original_moderation_action,
+                        )
+                    }
+                    EntryTypes::BreakoutTimer(original_breakout_timer) => {
+                        validate_delete_breakout_timer(
+                            action,
+                            original_action,
+                            original_breakout_timer,
+                        )
+                    }
+                    EntryTypes::MinutesFinalized(original_minutes_finalized) => {
+                        validate_delete_minutes_finalized(
+                            action,
+                            original_action,
+                            original_minutes_finalized,
+                        )
+                    }
+                    EntryTypes::Announcement(original_announcement) => {
+                        validate_delete_announcement(action, original_action, original_announcement)
+                    }
+                    EntryTypes::PresenterState(original_presenter_state) => {
+                        validate_delete_presenter_state(
+                            action,
+                            original_action,
+                            original_presenter_state,
+                        )
+                    }
+                    EntryTypes::PrivacyModePreference(original_privacy_mode_preference) => {
+                        validate_delete_privacy_mode_preference(
+                            action,
+                            original_action,
+                            original_privacy_mode_preference,
+                        )
+                    }
+                    EntryTypes::MediaKeyShare(original_media_key_share) => {
+                        validate_delete_media_key_share(
+                            action,
+                            original_action,
+                            original_media_key_share,
+                        )
+                    }
+                    EntryTypes::Task(original_task) => {
+                        validate_delete_task(action, original_action, original_task)
+                    }
+                    EntryTypes::PromptDeck(original_prompt_deck) => {
+                        validate_delete_prompt_deck(action, original_action, original_prompt_deck)
+                    }
+                    EntryTypes::SpeakingGrant(original_speaking_grant) => {
+                        validate_delete_speaking_grant(
+                            action,
+                            original_action,
+                            original_speaking_grant,
+                        )
+                    }
+                    EntryTypes::AttendanceAttestation(original_attendance_attestation) => {
+                        validate_delete_attendance_attestation(
+                            action,
+                            original_action,
+                            original_attendance_attestation,
+                        )
+                    }
+                    EntryTypes::AttachmentAccess(original_attachment_access) => {
+                        validate_delete_attachment_access(
+                            action,
+                            original_action,
+                            original_attachment_access,
+                        )
+                    }
+                    EntryTypes::MinutesCoSignerConsent(original_minutes_co_signer_consent) => {
+                        validate_delete_minutes_co_signer_consent(
+                            action,
+                            original_action,
+                            original_minutes_co_signer_consent,
+                        )
+                    }
+                    EntryTypes::HostTransferConsent(original_host_transfer_consent) => {
+                        validate_delete_host_transfer_consent(
+                            action,
+                            original_action,
+                            original_host_transfer_consent,
+                        )
+                    }
+                }
+            }
+            OpRecord::CreateLink {
+                base_address,
+                target_address,
+                tag,
+                link_type,
+                action,
+            } => match link_type {
+                LinkTypes::RoomInfoUpdates => validate_create_link_room_info_updates(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AllAgents => {
+                    validate_create_link_all_agents(action, base_address, target_address, tag)
+                }
+                LinkTypes::AllDescendentRooms => validate_create_link_all_descendent_rooms(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AttachmentUpdates => validate_create_link_attachment_updates(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AllAttachments => {
+                    validate_create_link_all_attachments(action, base_address, target_address, tag)
+                }
+                LinkTypes::ExternalReferences => validate_create_link_external_references(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AllBreakoutAssignments => validate_create_link_all_breakout_assignments(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AgentToBreakoutAssignments => {
+                    validate_create_link_agent_to_breakout_assignments(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::RoomSettingsUpdates => validate_create_link_room_settings_updates(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AgentToCaptionPreferences => {
+                    validate_create_link_agent_to_caption_preferences(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllInterpreterRegistrations => {
+                    validate_create_link_all_interpreter_registrations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToDeviceBindings => validate_create_link_agent_to_device_bindings(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::DeprecationNoticeUpdates => {
+                    validate_create_link_deprecation_notice_updates(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::TrashedAttachments => validate_create_link_trashed_attachments(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AllChatMessages => validate_create_link_all_chat_messages(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::ChatMessageUpdates => validate_create_link_chat_message_updates(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AllQuestions => {
+                    validate_create_link_all_questions(action, base_address, target_address, tag)
+                }
+                LinkTypes::QuestionUpvotes => {
+                    validate_create_link_question_upvotes(action, base_address, target_address, tag)
+                }
+                LinkTypes::AnsweredQuestions => validate_create_link_answered_questions(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::ChatMessageReplies => validate_create_link_chat_message_replies(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AllCheckIns => {
+                    validate_create_link_all_check_ins(action, base_address, target_address, tag)
+                }
+                LinkTypes::PinnedContent => {
+                    validate_create_link_pinned_content(action, base_address, target_address, tag)
+                }
+                LinkTypes::AllEventSubscriptions => validate_create_link_all_event_subscriptions(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::DirectoryListingUpdates => {
+                    validate_create_link_directory_listing_updates(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllRoleAssignments => validate_create_link_all_role_assignments(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AgentToRoleAssignments => {
+                    validate_create_link_agent_to_role_assignments(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::BannedAgents => {
+                    validate_create_link_banned_agents(action, base_address, target_address, tag)
+                }
+                LinkTypes::AgentToPermissionOverrides => {
+                    validate_create_link_agent_to_permission_overrides(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllOwnershipClaims => validate_create_link_all_ownership_claims(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::OwnershipClaimVetoes => validate_create_link_ownership_claim_vetoes(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AgentToAdmissions => validate_create_link_agent_to_admissions(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AllHostTransfers => validate_create_link_all_host_transfers(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AllCallSessionEnds => validate_create_link_all_call_session_ends(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::ModerationLog => {
+                    validate_create_link_moderation_log(action, base_address, target_address, tag)
                 }
-            }
-            OpRecord::CreateLink {
-                base_address,
-                target_address,
-                tag,
-                link_type,
-                action,
-            } => match link_type {
-                LinkTypes::RoomInfoUpdates => validate_create_link_room_info_updates(
+                LinkTypes::MinutesApprovals => validate_create_link_minutes_approvals(
                     action,
                     base_address,
                     target_address,
                     tag,
                 ),
-                LinkTypes::AllAgents => {
-                    validate_create_link_all_agents(action, base_address, target_address, tag)
+                LinkTypes::AllAnnouncements => validate_create_link_all_announcements(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::PresenterStateUpdates => validate_create_link_presenter_state_updates(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::PresenceHeartbeats => validate_create_link_presence_heartbeats(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::TaskUpdates => {
+                    validate_create_link_task_updates(action, base_address, target_address, tag)
                 }
-                LinkTypes::AllDescendentRooms => validate_create_link_all_descendent_rooms(
+                LinkTypes::AllTasks => {
+                    validate_create_link_all_tasks(action, base_address, target_address, tag)
+                }
+                LinkTypes::PromptDeckUpdates => validate_create_link_prompt_deck_updates(
                     action,
                     base_address,
                     target_address,
                     tag,
                 ),
-                LinkTypes::AttachmentUpdates => validate_create_link_attachment_updates(
+                LinkTypes::AllSpeakingGrants => validate_create_link_all_speaking_grants(
                     action,
                     base_address,
                     target_address,
                     tag,
                 ),
-                LinkTypes::AllAttachments => {
-                    validate_create_link_all_attachments(action, base_address, target_address, tag)
+                LinkTypes::AgentToAttendanceAttestations => {
+                    validate_create_link_agent_to_attendance_attestations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::CallHeartbeats => {
+                    validate_create_link_call_heartbeats(action, base_address, target_address, tag)
                 }
             },
             OpRecord::DeleteLink {
@@ -551,6 +4742,303 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         create_link.target_address,
                         create_link.tag,
                     ),
+                    LinkTypes::ExternalReferences => validate_delete_link_external_references(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllBreakoutAssignments => {
+                        validate_delete_link_all_breakout_assignments(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::AgentToBreakoutAssignments => {
+                        validate_delete_link_agent_to_breakout_assignments(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::RoomSettingsUpdates => validate_delete_link_room_settings_updates(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AgentToCaptionPreferences => {
+                        validate_delete_link_agent_to_caption_preferences(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::AllInterpreterRegistrations => {
+                        validate_delete_link_all_interpreter_registrations(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::AgentToDeviceBindings => {
+                        validate_delete_link_agent_to_device_bindings(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::DeprecationNoticeUpdates => {
+                        validate_delete_link_deprecation_notice_updates(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::TrashedAttachments => validate_delete_link_trashed_attachments(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllChatMessages => validate_delete_link_all_chat_messages(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::ChatMessageUpdates => validate_delete_link_chat_message_updates(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllQuestions => validate_delete_link_all_questions(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::QuestionUpvotes => validate_delete_link_question_upvotes(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AnsweredQuestions => validate_delete_link_answered_questions(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::ChatMessageReplies => validate_delete_link_chat_message_replies(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllCheckIns => validate_delete_link_all_check_ins(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::PinnedContent => validate_delete_link_pinned_content(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllEventSubscriptions => {
+                        validate_delete_link_all_event_subscriptions(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::DirectoryListingUpdates => {
+                        validate_delete_link_directory_listing_updates(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::AllRoleAssignments => validate_delete_link_all_role_assignments(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AgentToRoleAssignments => {
+                        validate_delete_link_agent_to_role_assignments(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::BannedAgents => validate_delete_link_banned_agents(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AgentToPermissionOverrides => {
+                        validate_delete_link_agent_to_permission_overrides(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::AllOwnershipClaims => validate_delete_link_all_ownership_claims(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::OwnershipClaimVetoes => validate_delete_link_ownership_claim_vetoes(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AgentToAdmissions => validate_delete_link_agent_to_admissions(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllHostTransfers => validate_delete_link_all_host_transfers(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllCallSessionEnds => validate_delete_link_all_call_session_ends(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::ModerationLog => validate_delete_link_moderation_log(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::MinutesApprovals => validate_delete_link_minutes_approvals(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllAnnouncements => validate_delete_link_all_announcements(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::PresenterStateUpdates => {
+                        validate_delete_link_presenter_state_updates(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::PresenceHeartbeats => validate_delete_link_presence_heartbeats(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::TaskUpdates => validate_delete_link_task_updates(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllTasks => validate_delete_link_all_tasks(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::PromptDeckUpdates => validate_delete_link_prompt_deck_updates(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllSpeakingGrants => validate_delete_link_all_speaking_grants(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AgentToAttendanceAttestations => {
+                        validate_delete_link_agent_to_attendance_attestations(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::CallHeartbeats => validate_delete_link_call_heartbeats(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
                 }
             }
             OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
@@ -0,0 +1,38 @@
+use hdi::prelude::*;
+
+/// Private copy of a remote signal that has not been passed on to the UI because the
+/// author was in do-not-disturb mode when receiving it
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct MailboxItem {
+    /// The received signal payload, as encoded by the sender
+    pub signal: Vec<u8>,
+    /// The agent that sent the signal. None for items queued by older versions.
+    #[serde(default)]
+    pub from_agent: Option<AgentPubKey>,
+}
+pub fn validate_create_mailbox_item(
+    _action: EntryCreationAction,
+    _mailbox_item: MailboxItem,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_mailbox_item(
+    _action: Update,
+    _mailbox_item: MailboxItem,
+    _original_action: EntryCreationAction,
+    _original_mailbox_item: MailboxItem,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a MailboxItem entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_mailbox_item(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_mailbox_item: MailboxItem,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "MailboxItems cannot be deleted",
+    )))
+}
@@ -0,0 +1,49 @@
+use hdi::prelude::*;
+
+/// Length in bytes of the keys used to encrypt media frames
+pub const MEDIA_KEY_LENGTH: usize = 32;
+
+/// Private record of a key that an agent encrypts its media frames with, so that
+/// only the participants it has been shared with can decrypt the media, even if it
+/// gets relayed. Each agent keeps its own keys as well as the keys shared with it.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MediaKeyShare {
+    /// The agent that encrypts its media with the key
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub sender: AgentPubKey,
+    /// Increased every time the sender replaces its key
+    pub epoch: u32,
+    pub key: Vec<u8>,
+}
+pub fn validate_create_media_key_share(
+    _action: EntryCreationAction,
+    media_key_share: MediaKeyShare,
+) -> ExternResult<ValidateCallbackResult> {
+    if media_key_share.key.len() != MEDIA_KEY_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Media keys must be {MEDIA_KEY_LENGTH} bytes long."
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_media_key_share(
+    _action: Update,
+    _media_key_share: MediaKeyShare,
+    _original_action: EntryCreationAction,
+    _original_media_key_share: MediaKeyShare,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a MediaKeyShare entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_media_key_share(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_media_key_share: MediaKeyShare,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "MediaKeyShares cannot be deleted",
+    )))
+}
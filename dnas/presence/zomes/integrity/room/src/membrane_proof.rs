@@ -0,0 +1,91 @@
+use hdi::prelude::*;
+
+use crate::dna_properties::RoomDnaProperties;
+
+/// Membrane proof of agents joining a room that requires an invite code
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, PartialEq)]
+pub struct InviteCodeProof {
+    pub invite_code: String,
+}
+
+/// Membrane proof of agents that have been invited to the room personally: the
+/// progenitor's signature over the DNA hash of the room and the joining agent's
+/// public key
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InvitationProof {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub signature: Signature,
+}
+
+/// Requires agents other than the progenitor to present the invite code of the room
+/// or an invitation by the progenitor in their membrane proof, if the room has been
+/// installed with an invite code or requires invitations. A valid invitation by the
+/// progenitor is always accepted.
+pub fn validate_membrane_proof(
+    agent: &AgentPubKey,
+    membrane_proof: &Option<MembraneProof>,
+) -> ExternResult<ValidateCallbackResult> {
+    let properties = RoomDnaProperties::get()?;
+    let progenitor = properties.progenitor_key();
+    if progenitor.as_ref() == Some(agent) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    if let (Some(progenitor), Some(membrane_proof)) = (progenitor, membrane_proof) {
+        if let Ok(invitation) = InvitationProof::try_from((**membrane_proof).clone()) {
+            return Ok(
+                if verify_signature(
+                    progenitor,
+                    invitation.signature,
+                    (dna_info()?.hash, agent.clone()),
+                )? {
+                    ValidateCallbackResult::Valid
+                } else {
+                    ValidateCallbackResult::Invalid(
+                        "The invitation has not been signed by the progenitor.".into(),
+                    )
+                },
+            );
+        }
+    }
+    if properties.require_invitation && properties.invite_code_hash.is_none() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "This room requires an invitation to join.".into(),
+        ));
+    }
+    check_invite_code(properties.invite_code_hash.as_deref(), membrane_proof)
+}
+
+/// Returns the hex-encoded BLAKE2b-256 hash of the invite code, which is what rooms
+/// store in their properties
+pub fn invite_code_hash(invite_code: &str) -> ExternResult<String> {
+    let hash = hash_blake2b(invite_code.as_bytes().to_vec(), 32)?;
+    Ok(hash.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Checks the membrane proof against the hash of the invite code the room requires,
+/// if any
+pub fn check_invite_code(
+    invite_code_hash: Option<&str>,
+    membrane_proof: &Option<MembraneProof>,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(expected_hash) = invite_code_hash else {
+        return Ok(ValidateCallbackResult::Valid);
+    };
+    let Some(membrane_proof) = membrane_proof else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "This room requires an invite code to join.".into(),
+        ));
+    };
+    let Ok(proof) = InviteCodeProof::try_from((**membrane_proof).clone()) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The membrane proof is not an invite code proof.".into(),
+        ));
+    };
+    if invite_code_hash(&proof.invite_code)? != expected_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The invite code is not valid.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,49 @@
+use hdi::prelude::*;
+use serde::de::DeserializeOwned;
+
+/// Version of the format written by encode_metadata
+pub const METADATA_VERSION: u32 = 1;
+
+/// Wrapper around the metadata stored in `meta_data` string fields, so that
+/// clients can tell which format the metadata was written in
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VersionedMetadata<T> {
+    pub version: u32,
+    pub data: T,
+}
+
+/// Encodes the metadata for a `meta_data` string field
+pub fn encode_metadata<T: Serialize>(data: &T) -> Result<String, String> {
+    serde_json::to_string(&VersionedMetadata {
+        version: METADATA_VERSION,
+        data,
+    })
+    .map_err(|err| err.to_string())
+}
+
+/// Decodes the contents of a `meta_data` string field. Metadata written by clients
+/// predating the versioned format is decoded as plain JSON.
+pub fn decode_metadata<T: DeserializeOwned>(meta_data: &str) -> Result<T, String> {
+    if let Ok(versioned) = serde_json::from_str::<VersionedMetadata<serde_json::Value>>(meta_data) {
+        if versioned.version > METADATA_VERSION {
+            return Err(format!(
+                "Metadata version {} is not supported",
+                versioned.version
+            ));
+        }
+        return serde_json::from_value(versioned.data).map_err(|err| err.to_string());
+    }
+    serde_json::from_str(meta_data).map_err(|err| err.to_string())
+}
+
+/// Rejects `meta_data` strings that can't be decoded as T
+pub(crate) fn validate_meta_data<T: DeserializeOwned>(
+    meta_data: Option<&str>,
+) -> ValidateCallbackResult {
+    match meta_data.map(decode_metadata::<T>) {
+        Some(Err(err)) => ValidateCallbackResult::Invalid(format!(
+            "The meta_data field could not be decoded: {err}"
+        )),
+        _ => ValidateCallbackResult::Valid,
+    }
+}
@@ -0,0 +1,41 @@
+use hdi::prelude::*;
+
+/// Private record of this agent's consent to co-sign the finalization of a revision
+/// of the minutes by the given facilitator. The co-signer's UI records it before the
+/// facilitator finalizes the minutes, as the countersigning itself happens without
+/// the co-signer's involvement.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MinutesCoSignerConsent {
+    /// The revision of the minutes to approve
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub note_revision: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub facilitator: AgentPubKey,
+}
+pub fn validate_create_minutes_co_signer_consent(
+    _action: EntryCreationAction,
+    _minutes_co_signer_consent: MinutesCoSignerConsent,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_minutes_co_signer_consent(
+    _action: Update,
+    _minutes_co_signer_consent: MinutesCoSignerConsent,
+    _original_action: EntryCreationAction,
+    _original_minutes_co_signer_consent: MinutesCoSignerConsent,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a MinutesCoSignerConsent entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_minutes_co_signer_consent(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_minutes_co_signer_consent: MinutesCoSignerConsent,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "MinutesCoSignerConsents cannot be deleted",
+    )))
+}
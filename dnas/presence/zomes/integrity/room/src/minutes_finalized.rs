@@ -0,0 +1,114 @@
+use hdi::prelude::*;
+
+use crate::host_transfer::is_countersigned_by;
+
+/// Approves a revision of the meeting minutes. Committed by the facilitator and a
+/// second member in a countersigning session, so that the approved revision cannot
+/// be swapped out afterwards by either of them alone.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MinutesFinalized {
+    /// The revision of the minutes that gets approved
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub note_revision: ActionHash,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub facilitator: AgentPubKey,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub co_signer: AgentPubKey,
+}
+
+pub fn validate_create_minutes_finalized(
+    action: EntryCreationAction,
+    minutes_finalized: MinutesFinalized,
+) -> ExternResult<ValidateCallbackResult> {
+    if minutes_finalized.facilitator == minutes_finalized.co_signer {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Minutes must be co-signed by a second member.".into(),
+        ));
+    }
+    if action.author() != &minutes_finalized.facilitator
+        && action.author() != &minutes_finalized.co_signer
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Finalized minutes can only be committed by the agents that signed them.".into(),
+        ));
+    }
+    if !is_countersigned_by(
+        &action,
+        &[&minutes_finalized.facilitator, &minutes_finalized.co_signer],
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Finalized minutes must be countersigned by the facilitator and the co-signer.".into(),
+        ));
+    }
+    let note_record = must_get_valid_record(minutes_finalized.note_revision)?;
+    if note_record.action().timestamp() > action.timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Minutes cannot be finalized before the revision they approve.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_minutes_finalized(
+    _action: Update,
+    _minutes_finalized: MinutesFinalized,
+    _original_action: EntryCreationAction,
+    _original_minutes_finalized: MinutesFinalized,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a MinutesFinalized entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_minutes_finalized(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_minutes_finalized: MinutesFinalized,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Finalized minutes cannot be deleted",
+    )))
+}
+pub fn validate_create_link_minutes_approvals(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to MinutesFinalized entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let minutes_finalized: crate::MinutesFinalized = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a MinutesFinalized entry"
+        ))))?;
+    if ActionHash::try_from(base_address).ok() != Some(minutes_finalized.note_revision) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "MinutesApprovals links must have the approved revision as their base.".into(),
+        ));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Finalized minutes can only be linked by the agent that committed them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_minutes_approvals(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "MinutesApprovals links cannot be deleted.".into(),
+    ))
+}
@@ -0,0 +1,172 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority, RoomRole};
+
+pub const MODERATION_LOG: &str = "MODERATION_LOG";
+pub const MAX_MODERATION_REASON_LENGTH: usize = 500;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
+pub enum ModerationActionKind {
+    Kick,
+    Ban,
+    Unban,
+    ForceMute,
+    AssignRole { role: RoomRole },
+    RevokeRole { role: RoomRole },
+}
+
+/// Records a moderation action taken against an agent, so that disputes can be
+/// reviewed later
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModerationAction {
+    pub kind: ModerationActionKind,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub target: AgentPubKey,
+    pub reason: Option<String>,
+    /// The entry the action created, e.g. the AgentBan of a ban. For lifting bans
+    /// and revoking roles, the Delete action of the AgentBan or RoleAssignment.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub subject: Option<ActionHash>,
+    /// The Host or Moderator role assignment of the acting agent. Not needed if the
+    /// progenitor acts, nor for lifting bans and revoking roles, which the
+    /// validation of their Delete already restricts to the issuing agents.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+pub fn validate_create_moderation_action(
+    action: EntryCreationAction,
+    moderation_action: ModerationAction,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(reason) = &moderation_action.reason {
+        if reason.len() > MAX_MODERATION_REASON_LENGTH {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "The reason of a moderation action must not be longer than {MAX_MODERATION_REASON_LENGTH} bytes."
+            )));
+        }
+    }
+    match moderation_action.kind {
+        ModerationActionKind::Unban | ModerationActionKind::RevokeRole { .. } => {
+            validate_logged_deletion(&action, &moderation_action)
+        }
+        _ => validate_role_authority(
+            &action,
+            moderation_action.granted_via,
+            &[RoomRole::Host, RoomRole::Moderator],
+            "moderate agents",
+        ),
+    }
+}
+
+/// Lifted bans and revoked roles are only logged for a Delete of the logging agent,
+/// so that nobody can log moderation actions that never happened
+fn validate_logged_deletion(
+    action: &EntryCreationAction,
+    moderation_action: &ModerationAction,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(subject) = moderation_action.subject.clone() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Lifted bans and revoked roles must reference their Delete action.".into(),
+        ));
+    };
+    let deletion = must_get_action(subject)?;
+    let Action::Delete(delete) = deletion.action() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Lifted bans and revoked roles must reference their Delete action.".into(),
+        ));
+    };
+    if &delete.author != action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Agents can only log the bans they lifted and the roles they revoked.".into(),
+        ));
+    }
+    let deleted_record = must_get_valid_record(delete.deletes_address.clone())?;
+    let is_of_target = match &moderation_action.kind {
+        ModerationActionKind::Unban => deleted_record
+            .entry()
+            .to_app_option::<crate::AgentBan>()
+            .map_err(|e| wasm_error!(e))?
+            .is_some_and(|agent_ban| agent_ban.agent == moderation_action.target),
+        ModerationActionKind::RevokeRole { role } => deleted_record
+            .entry()
+            .to_app_option::<crate::RoleAssignment>()
+            .map_err(|e| wasm_error!(e))?
+            .is_some_and(|role_assignment| {
+                role_assignment.agent == moderation_action.target && &role_assignment.role == role
+            }),
+        _ => false,
+    };
+    if !is_of_target {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The referenced Delete must remove the ban or role of the target.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_moderation_action(
+    _action: Update,
+    _moderation_action: ModerationAction,
+    _original_action: EntryCreationAction,
+    _original_moderation_action: ModerationAction,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a ModerationAction entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_moderation_action(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_moderation_action: ModerationAction,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "ModerationActions cannot be deleted",
+    )))
+}
+pub fn validate_create_link_moderation_log(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(MODERATION_LOG);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "ModerationLog links must have the MODERATION_LOG anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to ModerationAction entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _moderation_action: crate::ModerationAction = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a ModerationAction entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Moderation actions can only be linked by the agent that took them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_moderation_log(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "ModerationLog links cannot be deleted.".into(),
+    ))
+}
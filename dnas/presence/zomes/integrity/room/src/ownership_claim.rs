@@ -0,0 +1,224 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{has_deleted, room_progenitor, RoleAssignment, RoomRole};
+
+pub const OWNERSHIP_CLAIMS: &str = "OWNERSHIP_CLAIMS";
+
+const MICROS_PER_DAY: i64 = 24 * 60 * 60 * 1_000_000;
+/// Number of days without any host-authored action after which a room may be claimed
+pub const OWNERSHIP_CLAIM_INACTIVITY_DAYS: i64 = 30;
+/// Number of days during which hosts can veto an ownership claim
+pub const OWNERSHIP_CLAIM_CONTEST_DAYS: i64 = 7;
+
+/// Claims ownership of a room whose hosts have been inactive. The claim matures
+/// after the contest window unless a host vetoes it, after which clients show the
+/// claimant as the owner of the room. It doesn't grant any roles: vetoes are links
+/// that validation can't see, so a vetoed claim couldn't be told apart from a
+/// matured one.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OwnershipClaim {
+    /// The latest action authored by a host of the room
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub last_host_action: ActionHash,
+    /// Host role assignment of the author of last_host_action. Not needed if the
+    /// progenitor authored it.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub host_role_assignment: Option<ActionHash>,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub matures_at: Timestamp,
+}
+
+/// Returns when a claim made at the given time matures
+pub fn ownership_claim_maturity(claimed_at: Timestamp) -> Timestamp {
+    Timestamp::from_micros(claimed_at.as_micros() + OWNERSHIP_CLAIM_CONTEST_DAYS * MICROS_PER_DAY)
+}
+
+/// Returns the earliest time at which the room may be claimed, given the time of
+/// the latest host-authored action
+pub fn ownership_claimable_from(last_host_action_at: Timestamp) -> Timestamp {
+    Timestamp::from_micros(
+        last_host_action_at.as_micros() + OWNERSHIP_CLAIM_INACTIVITY_DAYS * MICROS_PER_DAY,
+    )
+}
+
+pub fn validate_create_ownership_claim(
+    action: EntryCreationAction,
+    ownership_claim: OwnershipClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    if ownership_claim.matures_at != ownership_claim_maturity(*action.timestamp()) {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Ownership claims must mature {OWNERSHIP_CLAIM_CONTEST_DAYS} days after they are made."
+        )));
+    }
+    let last_host_action = must_get_action(ownership_claim.last_host_action.clone())?;
+    let host = last_host_action.action().author();
+    if let Some(reason) = host_rejection(host, ownership_claim.host_role_assignment.clone())? {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    // The host must still have held its Host role when it authored the action, so
+    // that claims can't point to the activity of a former host
+    if let Some(host_role_assignment) = &ownership_claim.host_role_assignment {
+        if has_deleted(
+            host,
+            &ownership_claim.last_host_action,
+            host_role_assignment,
+        )? {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The last host action must have been authored while its author was a host.".into(),
+            ));
+        }
+    }
+    if *action.timestamp() < ownership_claimable_from(last_host_action.action().timestamp()) {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Rooms can only be claimed after {OWNERSHIP_CLAIM_INACTIVITY_DAYS} days without host activity."
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_ownership_claim(
+    _action: Update,
+    _ownership_claim: OwnershipClaim,
+    _original_action: EntryCreationAction,
+    _original_ownership_claim: OwnershipClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating an OwnershipClaim entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_ownership_claim(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_ownership_claim: OwnershipClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the claimant can withdraw an ownership claim.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_all_ownership_claims(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(OWNERSHIP_CLAIMS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllOwnershipClaims links must have the OWNERSHIP_CLAIMS anchor as their base.".into(),
+        ));
+    }
+    let (claimant, _ownership_claim) = ownership_claim_from_address(target_address)?;
+    if claimant != action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Ownership claims can only be linked by their claimant.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_ownership_claims(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the claimant can withdraw an ownership claim.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Links from an ownership claim to the host vetoing it. The target is the host's
+/// Host role assignment, or the agent public key of the progenitor.
+pub fn validate_create_link_ownership_claim_vetoes(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (_claimant, ownership_claim) = ownership_claim_from_address(base_address)?;
+    if action.timestamp >= ownership_claim.matures_at {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Ownership claims can only be vetoed before they mature.".into(),
+        ));
+    }
+    let host_role_assignment = match target_address.clone().into_agent_pub_key() {
+        Some(agent) if agent == action.author => None,
+        Some(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Hosts can only veto ownership claims in their own name.".into(),
+            ))
+        }
+        None => target_address.into_action_hash(),
+    };
+    if let Some(reason) = host_rejection(&action.author, host_role_assignment)? {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_ownership_claim_vetoes(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Vetoes of ownership claims cannot be withdrawn.".into(),
+    ))
+}
+
+/// Returns why the agent can't be considered a host, if it can't. Hosts are the
+/// progenitor and the holders of a Host role assignment.
+fn host_rejection(
+    agent: &AgentPubKey,
+    host_role_assignment: Option<ActionHash>,
+) -> ExternResult<Option<String>> {
+    match host_role_assignment {
+        Some(action_hash) => {
+            let role_assignment: Option<RoleAssignment> = must_get_valid_record(action_hash)?
+                .entry()
+                .to_app_option()
+                .map_err(|e| wasm_error!(e))?;
+            let is_host = role_assignment.is_some_and(|role_assignment| {
+                &role_assignment.agent == agent && role_assignment.role == RoomRole::Host
+            });
+            Ok((!is_host).then(|| {
+                String::from("The referenced role assignment is not a Host role of the agent.")
+            }))
+        }
+        None => match room_progenitor()? {
+            Some(progenitor) if &progenitor == agent => Ok(None),
+            Some(_) => Ok(Some(String::from(
+                "Agents without a Host role assignment must be the progenitor.",
+            ))),
+            None => Ok(Some(String::from(
+                "Rooms without a progenitor have no hosts whose inactivity could be claimed.",
+            ))),
+        },
+    }
+}
+
+fn ownership_claim_from_address(
+    address: AnyLinkableHash,
+) -> ExternResult<(AgentPubKey, OwnershipClaim)> {
+    let action_hash = address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Link to OwnershipClaim entry is not an action hash"
+        ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let ownership_claim = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to an OwnershipClaim entry"
+        ))))?;
+    Ok((record.action().author().clone(), ownership_claim))
+}
@@ -0,0 +1,117 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{room_progenitor, validate_role_authority, RoomRole};
+
+/// Things agents may be allowed or denied to do in a room
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Capability {
+    ScreenShare,
+    Video,
+    Chat,
+}
+
+/// Allows or denies a capability to a single agent regardless of the room settings
+/// and of the agent's roles
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PermissionOverride {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub capability: Capability,
+    pub allowed: bool,
+    /// The Host or Moderator role assignment of the author. Not needed if the
+    /// progenitor overrides the permission.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+pub fn validate_create_permission_override(
+    action: EntryCreationAction,
+    permission_override: PermissionOverride,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_role_authority(
+        &action,
+        permission_override.granted_via,
+        &[RoomRole::Host, RoomRole::Moderator],
+        "override permissions",
+    )
+}
+pub fn validate_update_permission_override(
+    _action: Update,
+    _permission_override: PermissionOverride,
+    _original_action: EntryCreationAction,
+    _original_permission_override: PermissionOverride,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a PermissionOverride entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_permission_override(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_permission_override: PermissionOverride,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_removal(&action.author, original_action.author())
+}
+pub fn validate_create_link_agent_to_permission_overrides(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let Ok(agent) = AgentPubKey::try_from(base_address) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToPermissionOverrides link base is not an agent public key.".into(),
+        ));
+    };
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to PermissionOverride entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let permission_override: crate::PermissionOverride = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a PermissionOverride entry"
+        ))))?;
+    if permission_override.agent != agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToPermissionOverrides links must have the affected agent as their base.".into(),
+        ));
+    }
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Permission overrides can only be linked by the agent that issued them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_permission_overrides(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_removal(&action.author, &original_action.author)
+}
+
+/// A permission override can be removed by the agent that issued it or by the
+/// progenitor
+fn validate_removal(
+    remover: &AgentPubKey,
+    issuer: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+    if remover == issuer || room_progenitor()?.as_ref() == Some(remover) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Only the issuing agent or the progenitor can remove a permission override.".into(),
+    ))
+}
@@ -0,0 +1,40 @@
+use hdi::prelude::*;
+
+pub const PINNED_CONTENT: &str = "PINNED_CONTENT";
+
+/// Pins links from the PINNED_CONTENT anchor to the action of any content of the
+/// room, e.g. a chat message or an attachment
+pub fn validate_create_link_pinned_content(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if target_address.into_action_hash().is_none() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "PinnedContent links must point to an action hash.".into(),
+        ));
+    }
+    let path = Path::from(PINNED_CONTENT);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "PinnedContent links must have the PINNED_CONTENT anchor as their base.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Until rooms have moderators, content can only be unpinned by whoever pinned it
+pub fn validate_delete_link_pinned_content(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that pinned the content can unpin it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,112 @@
+use hdi::prelude::*;
+
+/// Length of the time buckets in which the presence of agents is recorded
+pub const PRESENCE_BUCKET_SECS: i64 = 60;
+
+/// Returns the presence bucket that the given time falls into
+pub fn presence_bucket(timestamp: Timestamp) -> i64 {
+    timestamp
+        .as_micros()
+        .div_euclid(PRESENCE_BUCKET_SECS * 1_000_000)
+}
+
+/// Returns the time at which the given presence bucket starts
+pub fn presence_bucket_start(bucket: i64) -> Timestamp {
+    Timestamp::from_micros(bucket * PRESENCE_BUCKET_SECS * 1_000_000)
+}
+
+/// The anchor from which all heartbeats of agents in a call during the given bucket
+/// are linked, so that ongoing calls can be detected without visiting every agent
+pub fn call_heartbeats_path(bucket: i64) -> Path {
+    Path::from(format!("CALL_HEARTBEATS.{bucket}"))
+}
+
+/// The link tag of PresenceHeartbeats links: the bucket the heartbeat was recorded
+/// in, followed by whether the agent was in a call at the time
+pub fn presence_heartbeat_tag(bucket: i64, in_call: bool) -> LinkTag {
+    let mut bytes = bucket.to_be_bytes().to_vec();
+    bytes.push(u8::from(in_call));
+    LinkTag::new(bytes)
+}
+
+/// Returns the bucket and whether the agent was in a call, if the tag is a valid
+/// PresenceHeartbeats link tag
+pub fn parse_presence_heartbeat_tag(tag: &LinkTag) -> Option<(i64, bool)> {
+    let bytes: [u8; 9] = tag.bytes().as_slice().try_into().ok()?;
+    let bucket = i64::from_be_bytes(bytes[..8].try_into().ok()?);
+    match bytes[8] {
+        0 => Some((bucket, false)),
+        1 => Some((bucket, true)),
+        _ => None,
+    }
+}
+
+/// Heartbeats are links from the agent to itself, recorded at most once per bucket
+/// while the agent is online
+pub fn validate_create_link_presence_heartbeats(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if AgentPubKey::try_from(base_address.clone()).ok() != Some(action.author.clone())
+        || target_address != base_address
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "PresenceHeartbeats links must point from the author to itself.".into(),
+        ));
+    }
+    let Some((bucket, _in_call)) = parse_presence_heartbeat_tag(&tag) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "PresenceHeartbeats links must have a presence heartbeat tag.".into(),
+        ));
+    };
+    if bucket != presence_bucket(action.timestamp) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Presence heartbeats can only be recorded for the current bucket.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_presence_heartbeats(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "PresenceHeartbeats links cannot be deleted.".into(),
+    ))
+}
+/// Call heartbeats are links from the anchor of the current bucket to the author
+pub fn validate_create_link_call_heartbeats(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = call_heartbeats_path(presence_bucket(action.timestamp));
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "CallHeartbeats links must have the anchor of the current bucket as their base.".into(),
+        ));
+    }
+    if AgentPubKey::try_from(target_address).ok() != Some(action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "CallHeartbeats links must point to their author.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_call_heartbeats(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "CallHeartbeats links cannot be deleted.".into(),
+    ))
+}
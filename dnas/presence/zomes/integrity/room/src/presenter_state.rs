@@ -0,0 +1,104 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority, RoomRole};
+
+pub const PRESENTER_STATE: &str = "PRESENTER_STATE";
+
+/// Who is currently presenting in the call, so that agents joining late know whose
+/// stream to focus on. Each change is a new revision linked from the
+/// PRESENTER_STATE anchor.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PresenterState {
+    /// None if nobody is presenting
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub presenter: Option<AgentPubKey>,
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub started_at: Timestamp,
+    /// The Host role assignment of the author. Not needed if the progenitor sets the
+    /// presenter.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+pub fn validate_create_presenter_state(
+    action: EntryCreationAction,
+    presenter_state: PresenterState,
+) -> ExternResult<ValidateCallbackResult> {
+    if presenter_state.started_at > *action.timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A presentation cannot start after the presenter has been set.".into(),
+        ));
+    }
+    validate_role_authority(
+        &action,
+        presenter_state.granted_via,
+        &[RoomRole::Host],
+        "set the presenter",
+    )
+}
+pub fn validate_update_presenter_state(
+    _action: Update,
+    _presenter_state: PresenterState,
+    _original_action: EntryCreationAction,
+    _original_presenter_state: PresenterState,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a PresenterState entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_presenter_state(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_presenter_state: PresenterState,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "PresenterStates cannot be deleted",
+    )))
+}
+pub fn validate_create_link_presenter_state_updates(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(PRESENTER_STATE);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "PresenterStateUpdates links must have the PRESENTER_STATE anchor as their base."
+                .into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to PresenterState entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _presenter_state: crate::PresenterState = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a PresenterState entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Presenter states can only be linked by the agent that set them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_presenter_state_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "PresenterStateUpdates links cannot be deleted",
+    )))
+}
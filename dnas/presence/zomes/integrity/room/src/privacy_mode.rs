@@ -0,0 +1,36 @@
+use hdi::prelude::*;
+
+/// Private record of whether the author has turned on privacy mode, in which the
+/// zome stops answering Pings on its own so that the author's online status is
+/// only revealed while the UI is open. The latest PrivacyModePreference on the
+/// author's source chain is the one in effect.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct PrivacyModePreference {
+    pub enabled: bool,
+}
+pub fn validate_create_privacy_mode_preference(
+    _action: EntryCreationAction,
+    _privacy_mode_preference: PrivacyModePreference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_privacy_mode_preference(
+    _action: Update,
+    _privacy_mode_preference: PrivacyModePreference,
+    _original_action: EntryCreationAction,
+    _original_privacy_mode_preference: PrivacyModePreference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a PrivacyModePreference entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_privacy_mode_preference(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_privacy_mode_preference: PrivacyModePreference,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "PrivacyModePreferences cannot be deleted",
+    )))
+}
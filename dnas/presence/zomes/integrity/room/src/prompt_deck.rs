@@ -0,0 +1,135 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority, RoomRole};
+
+pub const PROMPT_DECK: &str = "PROMPT_DECK";
+
+/// Maximum number of prompts in a deck
+pub const MAX_PROMPTS: usize = 500;
+
+/// Maximum length in bytes of a prompt
+pub const MAX_PROMPT_LENGTH: usize = 500;
+
+/// Icebreaker or check-in questions that facilitators open sessions with. Each
+/// change is a new revision linked from the PROMPT_DECK anchor.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PromptDeck {
+    pub prompts: Vec<String>,
+    /// The Host role assignment of the author. Not needed if the progenitor manages
+    /// the deck.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+impl PromptDeck {
+    /// Selects the prompt for the session. All clients drawing with the same seed from
+    /// the same deck get the same prompt.
+    pub fn draw(&self, session_seed: &str) -> Option<&String> {
+        if self.prompts.is_empty() {
+            return None;
+        }
+        // FNV-1a, which unlike the hashers of the standard library is guaranteed
+        // to give the same result on every client
+        let hash = session_seed
+            .bytes()
+            .fold(0xcbf29ce484222325_u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+        self.prompts
+            .get((hash % self.prompts.len() as u64) as usize)
+    }
+}
+
+pub fn validate_create_prompt_deck(
+    action: EntryCreationAction,
+    prompt_deck: PromptDeck,
+) -> ExternResult<ValidateCallbackResult> {
+    if prompt_deck.prompts.len() > MAX_PROMPTS {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "A prompt deck must not contain more than {MAX_PROMPTS} prompts."
+        )));
+    }
+    for prompt in &prompt_deck.prompts {
+        if prompt.trim().is_empty() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The prompts of a prompt deck must not be empty.".into(),
+            ));
+        }
+        if prompt.len() > MAX_PROMPT_LENGTH {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "A prompt must not be longer than {MAX_PROMPT_LENGTH} bytes."
+            )));
+        }
+    }
+    validate_role_authority(
+        &action,
+        prompt_deck.granted_via,
+        &[RoomRole::Host],
+        "manage the prompt deck",
+    )
+}
+pub fn validate_update_prompt_deck(
+    _action: Update,
+    _prompt_deck: PromptDeck,
+    _original_action: EntryCreationAction,
+    _original_prompt_deck: PromptDeck,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a PromptDeck entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_prompt_deck(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_prompt_deck: PromptDeck,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "PromptDecks cannot be deleted",
+    )))
+}
+pub fn validate_create_link_prompt_deck_updates(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(PROMPT_DECK);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "PromptDeckUpdates links must have the PROMPT_DECK anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to PromptDeck entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _prompt_deck: crate::PromptDeck = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a PromptDeck entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Prompt decks can only be linked by the agent that set them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_prompt_deck_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "PromptDeckUpdates links cannot be deleted",
+    )))
+}
@@ -0,0 +1,157 @@
+use hdi::prelude::*;
+
+pub const ALL_QUESTIONS: &str = "ALL_QUESTIONS";
+
+/// Maximum length in bytes of the content of a question
+pub const MAX_QUESTION_LENGTH: usize = 1000;
+
+/// A question asked to the hosts of a webinar, to be upvoted by the audience
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Question {
+    pub content: String,
+}
+pub fn validate_create_question(
+    _action: EntryCreationAction,
+    question: Question,
+) -> ExternResult<ValidateCallbackResult> {
+    if question.content.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A question must not be empty.".into(),
+        ));
+    }
+    if question.content.len() > MAX_QUESTION_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "A question must not be longer than {MAX_QUESTION_LENGTH} bytes."
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_question(
+    _action: Update,
+    _question: Question,
+    _original_action: EntryCreationAction,
+    _original_question: Question,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a Question entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_question(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_question: Question,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Questions cannot be deleted",
+    )))
+}
+pub fn validate_create_link_all_questions(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(ALL_QUESTIONS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllQuestions links must have the AllQuestions anchor as their base.".into(),
+        ));
+    }
+    must_get_question(target_address)?;
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_questions(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AllQuestions links cannot be deleted",
+    )))
+}
+/// Upvotes link from the question to the agent that upvoted it
+pub fn validate_create_link_question_upvotes(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if AgentPubKey::try_from(target_address).ok() != Some(action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Agents can only upvote questions on their own behalf.".into(),
+        ));
+    }
+    must_get_question(base_address)?;
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_question_upvotes(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that upvoted a question can withdraw the upvote.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Marks the question at the base as answered. Links to the agent that marked it.
+pub fn validate_create_link_answered_questions(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if AgentPubKey::try_from(target_address).ok() != Some(action.author.clone()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AnsweredQuestions links must point to the agent that created them.".into(),
+        ));
+    }
+    let record = must_get_question(base_address)?;
+    // Rooms don't have hosts yet, so for now only the asker can mark their own
+    // question as answered
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a question can mark it as answered.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_answered_questions(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the agent that marked a question as answered can unmark it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+fn must_get_question(address: AnyLinkableHash) -> ExternResult<Record> {
+    let action_hash = address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "No action hash associated with link"
+        ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _question: crate::Question = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference an entry"
+        ))))?;
+    Ok(record)
+}
@@ -0,0 +1,390 @@
+use hdi::prelude::*;
+
+use crate::dna_properties::RoomDnaProperties;
+
+pub const ROLE_ASSIGNMENTS: &str = "ROLE_ASSIGNMENTS";
+
+/// Returns the progenitor of this room, if it has been installed with one. Rooms
+/// without a progenitor have nobody who could grant the first role, so nobody holds
+/// roles there.
+pub fn room_progenitor() -> ExternResult<Option<AgentPubKey>> {
+    Ok(RoomDnaProperties::get()?.progenitor_key())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RoomRole {
+    /// Runs the sessions of the room
+    Host,
+    /// Moderates the room and may grant and revoke roles
+    Moderator,
+    /// May publish announcements. Can be earned by attending sessions if the room
+    /// settings have an attendance promotion rule.
+    Contributor,
+}
+
+/// Grants a role to an agent
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RoleAssignment {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub agent: AgentPubKey,
+    pub role: RoomRole,
+    /// The Moderator role assignment of the granting agent, proving that it may
+    /// grant roles. Not needed if the progenitor grants the role. For roles earned
+    /// by attendance, the RoomSettings revision with the attendance promotion rule.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+    /// The attendance attestations of the agent, if it assigns itself the role
+    /// through the attendance promotion rule of the room settings
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<Vec<u8>>"))]
+    pub attended: Vec<ActionHash>,
+}
+
+pub fn validate_create_role_assignment(
+    action: EntryCreationAction,
+    role_assignment: RoleAssignment,
+) -> ExternResult<ValidateCallbackResult> {
+    if !role_assignment.attended.is_empty() {
+        return validate_attendance_promotion(&action, role_assignment);
+    }
+    validate_moderator_authority(&action, role_assignment.granted_via, "assign roles")
+}
+
+/// Checks a role assignment that the author makes to itself via the attendance
+/// promotion rule of the RoomSettings revision it references. The revision must
+/// have been written by an agent that could change the room settings. Revisions
+/// that came later can't be seen in validation, so turning the rule off doesn't
+/// take back roles that have already been earned; those have to be revoked.
+fn validate_attendance_promotion(
+    action: &EntryCreationAction,
+    role_assignment: RoleAssignment,
+) -> ExternResult<ValidateCallbackResult> {
+    if &role_assignment.agent != action.author() || role_assignment.role != RoomRole::Contributor {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Agents can only earn the Contributor role for themselves by attendance.".into(),
+        ));
+    }
+    let Some(room_settings_hash) = role_assignment.granted_via else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Roles earned by attendance must reference the room settings with the rule.".into(),
+        ));
+    };
+    let record = must_get_valid_record(room_settings_hash)?;
+    let Some(room_settings) = record
+        .entry()
+        .to_app_option::<crate::RoomSettings>()
+        .map_err(|e| wasm_error!(e))?
+    else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Roles earned by attendance must reference the room settings with the rule.".into(),
+        ));
+    };
+    let Some(attendance_promotion) = room_settings.attendance_promotion else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The referenced room settings have no attendance promotion rule.".into(),
+        ));
+    };
+    if record.action().timestamp() > *action.timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The referenced room settings must have been written before the role is earned.".into(),
+        ));
+    }
+    let room_settings_action = EntryCreationAction::try_from(record.action().clone())
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(format!("{err:?}"))))?;
+    let result = validate_role_authority(
+        &room_settings_action,
+        room_settings.granted_via,
+        &[RoomRole::Host, RoomRole::Moderator],
+        "change the room settings",
+    )?;
+    let ValidateCallbackResult::Valid = result else {
+        return Ok(result);
+    };
+    let mut sessions: Vec<ActionHash> = Vec::new();
+    for attestation_hash in role_assignment.attended {
+        let attendance_attestation: Option<crate::AttendanceAttestation> =
+            must_get_valid_record(attestation_hash)?
+                .entry()
+                .to_app_option()
+                .map_err(|e| wasm_error!(e))?;
+        let Some(attendance_attestation) = attendance_attestation else {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The attended sessions must be attendance attestations.".into(),
+            ));
+        };
+        if !attendance_attestation.agents.contains(action.author()) {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The attendance attestations must attest the attendance of the author.".into(),
+            ));
+        }
+        if !sessions.contains(&attendance_attestation.session) {
+            sessions.push(attendance_attestation.session);
+        }
+    }
+    if sessions.len() < attendance_promotion.sessions as usize {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The Contributor role can only be earned after attending {} sessions.",
+            attendance_promotion.sessions
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Checks that the author either is the progenitor of the room or proves to be a
+/// moderator via granted_via, which must be the author's own Moderator role
+/// assignment. In rooms without a progenitor, nobody is allowed.
+pub(crate) fn validate_moderator_authority(
+    action: &EntryCreationAction,
+    granted_via: Option<ActionHash>,
+    privilege: &str,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_role_authority(action, granted_via, &[RoomRole::Moderator], privilege)
+}
+
+/// Same as validate_moderator_authority but accepts a role assignment of any of
+/// the given roles as proof. Ownership claims are not accepted, since whether a
+/// host has vetoed them can't be seen in validation.
+pub(crate) fn validate_role_authority(
+    action: &EntryCreationAction,
+    granted_via: Option<ActionHash>,
+    accepted_roles: &[RoomRole],
+    privilege: &str,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_role_authority_as_of(
+        action.author(),
+        action.timestamp(),
+        action.prev_action(),
+        granted_via,
+        accepted_roles,
+        privilege,
+    )
+}
+
+/// Same as validate_role_authority, for an action of the author at the given time
+/// whose previous action on the author's chain is chain_top. Role assignments the
+/// author gave up on its chain before that are not accepted. Revocations by the
+/// granting agent or the progenitor happen on their chains, which validation can't
+/// see as of a given time, so those are only enforced by the coordinator.
+pub(crate) fn validate_role_authority_as_of(
+    author: &AgentPubKey,
+    timestamp: &Timestamp,
+    chain_top: &ActionHash,
+    granted_via: Option<ActionHash>,
+    accepted_roles: &[RoomRole],
+    privilege: &str,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(granted_via) = granted_via {
+        let record = must_get_valid_record(granted_via.clone())?;
+        let granter_assignment: Option<crate::RoleAssignment> =
+            record.entry().to_app_option().ok().flatten();
+        let holds_role = match granter_assignment {
+            Some(granter_assignment) => {
+                &granter_assignment.agent == author
+                    && accepted_roles.contains(&granter_assignment.role)
+                    && !has_deleted(author, chain_top, &granted_via)?
+            }
+            // Hosts that got the hosting transferred to them hold all roles until they
+            // hand it over to someone else
+            None => {
+                let host_transfer: Option<crate::HostTransfer> =
+                    record.entry().to_app_option().map_err(|e| wasm_error!(e))?;
+                match host_transfer {
+                    Some(host_transfer) => {
+                        let transferred_at = record.action().timestamp();
+                        &host_transfer.to_host == author
+                            && transferred_at <= *timestamp
+                            && !has_transferred_hosting_since(author, chain_top, transferred_at)?
+                    }
+                    None => false,
+                }
+            }
+        };
+        if !holds_role {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "Agents can only {privilege} via a role assignment of their own."
+            )));
+        }
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    match room_progenitor()? {
+        Some(progenitor) if &progenitor == author => Ok(ValidateCallbackResult::Valid),
+        Some(_) => Ok(ValidateCallbackResult::Invalid(format!(
+            "Only the progenitor or agents with the required role can {privilege}."
+        ))),
+        None => Ok(ValidateCallbackResult::Invalid(format!(
+            "Nobody can {privilege} in rooms without a progenitor."
+        ))),
+    }
+}
+
+/// Whether the agent has handed the hosting over to another agent on its chain up to
+/// chain_top, at or after the given time
+fn has_transferred_hosting_since(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    since: Timestamp,
+) -> ExternResult<bool> {
+    let host_transfer_type: EntryType = crate::UnitEntryTypes::HostTransfer.try_into()?;
+    let activity = must_get_agent_activity(agent.clone(), ChainFilter::new(chain_top.clone()))?;
+    for activity in activity {
+        let action = activity.action.action();
+        if action.timestamp() < since || action.entry_type() != Some(&host_transfer_type) {
+            continue;
+        }
+        let Some(entry_hash) = action.entry_hash() else {
+            continue;
+        };
+        let entry = must_get_entry(entry_hash.clone())?;
+        let (Entry::App(bytes) | Entry::CounterSign(_, bytes)) = entry.as_content() else {
+            continue;
+        };
+        let host_transfer =
+            crate::HostTransfer::try_from(bytes.clone().into_sb()).map_err(|e| wasm_error!(e))?;
+        if &host_transfer.from_host == agent {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Whether the agent has deleted the given action on its chain up to chain_top
+pub(crate) fn has_deleted(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    deleted_action: &ActionHash,
+) -> ExternResult<bool> {
+    let activity = must_get_agent_activity(agent.clone(), ChainFilter::new(chain_top.clone()))?;
+    Ok(activity.iter().any(|activity| {
+        matches!(
+            activity.action.action(),
+            Action::Delete(delete) if &delete.deletes_address == deleted_action
+        )
+    }))
+}
+
+pub fn validate_update_role_assignment(
+    _action: Update,
+    _role_assignment: RoleAssignment,
+    _original_action: EntryCreationAction,
+    _original_role_assignment: RoleAssignment,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a RoleAssignment entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_role_assignment(
+    action: Delete,
+    original_action: EntryCreationAction,
+    original_role_assignment: RoleAssignment,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_revocation(
+        &action.author,
+        original_action.author(),
+        &original_role_assignment,
+    )
+}
+pub fn validate_create_link_all_role_assignments(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(ROLE_ASSIGNMENTS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllRoleAssignments links must have the ROLE_ASSIGNMENTS anchor as their base.".into(),
+        ));
+    }
+    let (granter, _role_assignment) = role_assignment_from_target(target_address)?;
+    if granter != action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Role assignments can only be linked by the agent that granted them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_role_assignments(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (granter, role_assignment) = role_assignment_from_target(target)?;
+    validate_revocation(&action.author, &granter, &role_assignment)
+}
+pub fn validate_create_link_agent_to_role_assignments(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let Ok(agent) = AgentPubKey::try_from(base_address) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToRoleAssignments link base is not an agent public key.".into(),
+        ));
+    };
+    let (granter, role_assignment) = role_assignment_from_target(target_address)?;
+    if role_assignment.agent != agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToRoleAssignments links must have the assigned agent as their base.".into(),
+        ));
+    }
+    if granter != action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Role assignments can only be linked by the agent that granted them.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_role_assignments(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (granter, role_assignment) = role_assignment_from_target(target)?;
+    validate_revocation(&action.author, &granter, &role_assignment)
+}
+
+/// A role can be revoked by the agent that granted it, given up by the agent that
+/// holds it, or revoked by the progenitor
+fn validate_revocation(
+    revoker: &AgentPubKey,
+    granter: &AgentPubKey,
+    role_assignment: &RoleAssignment,
+) -> ExternResult<ValidateCallbackResult> {
+    if revoker == granter || revoker == &role_assignment.agent {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    if room_progenitor()?.as_ref() == Some(revoker) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Only the granting agent, the holder or the progenitor can revoke a role.".into(),
+    ))
+}
+
+fn role_assignment_from_target(
+    target_address: AnyLinkableHash,
+) -> ExternResult<(AgentPubKey, RoleAssignment)> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to RoleAssignment entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let role_assignment = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a RoleAssignment entry"
+        ))))?;
+    Ok((record.action().author().clone(), role_assignment))
+}
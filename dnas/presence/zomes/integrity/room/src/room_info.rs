@@ -1,18 +1,105 @@
 use hdi::prelude::*;
 
+use crate::metadata::{decode_metadata, validate_meta_data};
+use crate::role_assignment::{room_progenitor, validate_moderator_authority};
+
 pub const ROOM_INFO: &str = "ROOM_INFO";
 
+pub const MAX_ROOM_METADATA_LABELS: usize = 20;
+pub const MAX_ROOM_METADATA_EXTERNAL_LINKS: usize = 20;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExternalLink {
+    pub label: String,
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct RoomMetadata {
+    pub labels: Vec<String>,
+    pub external_links: Vec<ExternalLink>,
+    /// BCP 47 language tag of the room, e.g. "en" or "de-CH"
+    pub locale: Option<String>,
+}
+
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RoomInfo {
     pub name: String,
     pub icon_src: Option<String>,
+    /// Deprecated: metadata as written by older clients, decodable with
+    /// decode_metadata. Use `metadata` instead.
     pub meta_data: Option<String>,
+    #[serde(default)]
+    pub metadata: Option<RoomMetadata>,
+    /// The Moderator role assignment of the author, proving that it may change the
+    /// room info. Not needed if the progenitor changes it, nor in rooms without a
+    /// progenitor.
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+impl RoomInfo {
+    /// Returns the structured metadata of this room, falling back to parsing the
+    /// legacy meta_data string for RoomInfo entries written by older clients
+    pub fn room_metadata(&self) -> Option<RoomMetadata> {
+        self.metadata.clone().or_else(|| {
+            self.meta_data
+                .as_deref()
+                .and_then(|meta_data| decode_metadata(meta_data).ok())
+        })
+    }
+}
+
+pub(crate) fn validate_room_metadata(metadata: &RoomMetadata) -> ValidateCallbackResult {
+    if metadata.labels.len() > MAX_ROOM_METADATA_LABELS {
+        return ValidateCallbackResult::Invalid(format!(
+            "Room metadata must not contain more than {MAX_ROOM_METADATA_LABELS} labels."
+        ));
+    }
+    if metadata.labels.iter().any(|label| label.trim().is_empty()) {
+        return ValidateCallbackResult::Invalid("Room metadata labels must not be empty.".into());
+    }
+    if metadata.external_links.len() > MAX_ROOM_METADATA_EXTERNAL_LINKS {
+        return ValidateCallbackResult::Invalid(format!(
+            "Room metadata must not contain more than {MAX_ROOM_METADATA_EXTERNAL_LINKS} external links."
+        ));
+    }
+    if metadata
+        .external_links
+        .iter()
+        .any(|link| link.url.trim().is_empty())
+    {
+        return ValidateCallbackResult::Invalid(
+            "External links in room metadata must have a URL.".into(),
+        );
+    }
+    if let Some(locale) = &metadata.locale {
+        if locale.trim().is_empty() {
+            return ValidateCallbackResult::Invalid(
+                "The locale in room metadata must not be empty.".into(),
+            );
+        }
+    }
+    ValidateCallbackResult::Valid
 }
+
 pub fn validate_create_room_info(
     _action: EntryCreationAction,
-    _room_info: RoomInfo,
+    room_info: RoomInfo,
 ) -> ExternResult<ValidateCallbackResult> {
+    let meta_data_result = validate_meta_data::<RoomMetadata>(room_info.meta_data.as_deref());
+    if !matches!(meta_data_result, ValidateCallbackResult::Valid) {
+        return Ok(meta_data_result);
+    }
+    if let Some(metadata) = &room_info.metadata {
+        return Ok(validate_room_metadata(metadata));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_update_room_info(
@@ -35,7 +122,7 @@ pub fn validate_delete_room_info(
     )))
 }
 pub fn validate_create_link_room_info_updates(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
@@ -63,14 +150,32 @@ pub fn validate_create_link_room_info_updates(
                 "Link to RoomInfo entry is not an action hash"
             ))))?;
     let record = must_get_valid_record(room_info_action_hash)?;
-    let _room_info: crate::RoomInfo = record
+    let room_info: crate::RoomInfo = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
         .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
             "Linked action must point to a RoomInfo entry"
         ))))?;
-    Ok(ValidateCallbackResult::Valid)
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "RoomInfo can only be linked by the agent that wrote it.".into(),
+        ));
+    }
+    // Rooms without a progenitor have nobody who could hold a role, so anyone may
+    // change their room info
+    if room_progenitor()?.is_none() {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    // Only the linked RoomInfo takes effect, so this is where the authority of its
+    // author is checked
+    let room_info_action = EntryCreationAction::try_from(record.action().clone())
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(format!("{err:?}"))))?;
+    validate_moderator_authority(
+        &room_info_action,
+        room_info.granted_via,
+        "change the room info",
+    )
 }
 pub fn validate_delete_link_room_info_updates(
     _action: DeleteLink,
@@ -0,0 +1,283 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{room_progenitor, validate_role_authority, RoomRole};
+
+pub const ROOM_SETTINGS: &str = "ROOM_SETTINGS";
+
+/// Maximum number of reactions a room's reaction palette may contain
+pub const MAX_REACTION_PALETTE_SIZE: usize = 50;
+
+/// Maximum length in bytes of the text of a welcome message
+pub const MAX_WELCOME_MESSAGE_LENGTH: usize = 2000;
+
+/// Orientation for new participants, shown the moment they join the room
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WelcomeMessage {
+    pub text: String,
+    /// Sound to be played upon entering the room
+    pub sound_src: Option<String>,
+}
+
+/// When clients play a chime as agents join or leave the call of the room
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ChimePolicy {
+    #[default]
+    JoinAndLeave,
+    JoinOnly,
+    Off,
+}
+
+impl ChimePolicy {
+    pub fn plays_chime(&self, joined: bool) -> bool {
+        match self {
+            ChimePolicy::JoinAndLeave => true,
+            ChimePolicy::JoinOnly => joined,
+            ChimePolicy::Off => false,
+        }
+    }
+}
+
+/// Lets agents assign themselves the Contributor role once hosts have attested
+/// their attendance of the given number of distinct sessions
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AttendancePromotion {
+    pub sessions: u32,
+}
+
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct RoomSettings {
+    /// The reactions that may be sent in this room. Any reaction is allowed if None.
+    pub reaction_palette: Option<Vec<String>>,
+    pub welcome_message: Option<WelcomeMessage>,
+    /// Whether screen sharing is possible in this room
+    pub screen_share_enabled: bool,
+    /// Whether the room has been turned into a read-only archive
+    pub archived: bool,
+    /// Whether participants join calls with their microphone muted
+    pub mute_on_join: bool,
+    /// Whether participants join calls with their camera turned off
+    pub video_off_on_join: bool,
+    /// Number of participants up to which the UI lets agents join the call. No
+    /// limit if None.
+    pub max_participants: Option<u32>,
+    /// Whether the text chat of the room can be used
+    pub chat_enabled: bool,
+    /// Whether calls in this room are restricted to audio. Screen sharing and
+    /// separate video connections are rejected, and clients are expected to not
+    /// send video over the main connection of a call either.
+    pub audio_only: bool,
+    /// Whether agents have to be admitted by a host or moderator before they can
+    /// connect to the call
+    pub waiting_room: bool,
+    /// Whether the room has been locked, so that no new agents can join it
+    pub locked: bool,
+    /// When the next meeting of the room is scheduled, if any
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub next_meeting_at: Option<Timestamp>,
+    /// Maximum number of bytes of public entries each agent may author in this
+    /// room, e.g. 50_000_000 for 50 MB. No limit if None.
+    pub authored_bytes_quota: Option<u64>,
+    /// Seconds after which remote signals are dropped as stale, to protect calls
+    /// against replayed signals. The zome's default applies if None.
+    pub signal_max_age_secs: Option<u32>,
+    /// Whether clients chime when agents join or leave the call, announced to them
+    /// via JoinedCall and LeftCall signals
+    pub chime_policy: ChimePolicy,
+    /// Whether agents earn the Contributor role by attending sessions. Roles are
+    /// only assigned by moderators if None.
+    pub attendance_promotion: Option<AttendancePromotion>,
+    /// The Host or Moderator role assignment of the author, proving that it may
+    /// change the room settings. Not needed if the progenitor changes them, nor in
+    /// rooms without a progenitor.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+impl Default for RoomSettings {
+    fn default() -> Self {
+        Self {
+            reaction_palette: None,
+            welcome_message: None,
+            screen_share_enabled: true,
+            archived: false,
+            mute_on_join: false,
+            video_off_on_join: false,
+            max_participants: None,
+            chat_enabled: true,
+            audio_only: false,
+            waiting_room: false,
+            locked: false,
+            next_meeting_at: None,
+            authored_bytes_quota: None,
+            signal_max_age_secs: None,
+            chime_policy: ChimePolicy::default(),
+            attendance_promotion: None,
+            granted_via: None,
+        }
+    }
+}
+
+impl RoomSettings {
+    pub fn is_reaction_allowed(&self, reaction: &str) -> bool {
+        match &self.reaction_palette {
+            Some(palette) => palette.iter().any(|r| r == reaction),
+            None => true,
+        }
+    }
+}
+
+pub fn validate_create_room_settings(
+    _action: EntryCreationAction,
+    room_settings: RoomSettings,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(palette) = room_settings.reaction_palette {
+        if palette.len() > MAX_REACTION_PALETTE_SIZE {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "A reaction palette must not contain more than {MAX_REACTION_PALETTE_SIZE} reactions."
+            )));
+        }
+        if palette.iter().any(|reaction| reaction.trim().is_empty()) {
+            return Ok(ValidateCallbackResult::Invalid(
+                "A reaction palette must not contain empty reactions.".into(),
+            ));
+        }
+        let mut deduplicated = palette.clone();
+        deduplicated.sort();
+        deduplicated.dedup();
+        if deduplicated.len() != palette.len() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "A reaction palette must not contain duplicate reactions.".into(),
+            ));
+        }
+    }
+    if let Some(welcome_message) = room_settings.welcome_message {
+        if welcome_message.text.trim().is_empty() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The text of a welcome message must not be empty.".into(),
+            ));
+        }
+        if welcome_message.text.len() > MAX_WELCOME_MESSAGE_LENGTH {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "The text of a welcome message must not be longer than {MAX_WELCOME_MESSAGE_LENGTH} bytes."
+            )));
+        }
+    }
+    if room_settings.authored_bytes_quota == Some(0) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The storage quota must be larger than 0 bytes.".into(),
+        ));
+    }
+    if room_settings.signal_max_age_secs == Some(0) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The maximum age of signals must be at least 1 second.".into(),
+        ));
+    }
+    if room_settings
+        .attendance_promotion
+        .as_ref()
+        .is_some_and(|attendance_promotion| attendance_promotion.sessions == 0)
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Attendance promotion must require at least 1 session.".into(),
+        ));
+    }
+    if room_settings.max_participants == Some(0) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The maximum number of participants must be at least 1.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_room_settings(
+    _action: Update,
+    _room_settings: RoomSettings,
+    _original_action: EntryCreationAction,
+    _original_room_settings: RoomSettings,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a RoomSettings entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_room_settings(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_room_settings: RoomSettings,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Room Settings cannot be deleted",
+    )))
+}
+pub fn validate_create_link_room_settings_updates(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(ROOM_SETTINGS);
+    let path_entry_hash = path.path_entry_hash()?;
+    let base_entry_hash = match EntryHash::try_from(base_address) {
+        Ok(eh) => eh,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Base address of a RoomSettingsUpdates link must be an entry hash.".into(),
+            ))
+        }
+    };
+    if base_entry_hash != path_entry_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "RoomSettingsUpdates links must have the RoomSettings anchor as their base.".into(),
+        ));
+    }
+
+    let room_settings_action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to RoomSettings entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(room_settings_action_hash)?;
+    let room_settings: crate::RoomSettings = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a RoomSettings entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "RoomSettings can only be linked by the agent that wrote them.".into(),
+        ));
+    }
+    // Rooms without a progenitor have nobody who could hold a role, so anyone may
+    // change their settings
+    if room_progenitor()?.is_none() {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    // Only the linked RoomSettings take effect, so this is where the authority of
+    // their author is checked
+    let room_settings_action = EntryCreationAction::try_from(record.action().clone())
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(format!("{err:?}"))))?;
+    validate_role_authority(
+        &room_settings_action,
+        room_settings.granted_via,
+        &[RoomRole::Host, RoomRole::Moderator],
+        "change the room settings",
+    )
+}
+pub fn validate_delete_link_room_settings_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "RoomSettingsUpdates links cannot be deleted",
+    )))
+}
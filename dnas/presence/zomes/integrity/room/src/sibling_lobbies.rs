@@ -0,0 +1,35 @@
+use hdi::prelude::*;
+
+/// Private list of the other top-level rooms of the author's organization, whose
+/// descendent rooms get listed along with the ones of this room. The latest
+/// SiblingLobbies on the author's source chain is the one in effect.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct SiblingLobbies {
+    pub dna_hashes: Vec<DnaHash>,
+}
+pub fn validate_create_sibling_lobbies(
+    _action: EntryCreationAction,
+    _sibling_lobbies: SiblingLobbies,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_sibling_lobbies(
+    _action: Update,
+    _sibling_lobbies: SiblingLobbies,
+    _original_action: EntryCreationAction,
+    _original_sibling_lobbies: SiblingLobbies,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a SiblingLobbies entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_sibling_lobbies(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_sibling_lobbies: SiblingLobbies,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "SiblingLobbies cannot be deleted",
+    )))
+}
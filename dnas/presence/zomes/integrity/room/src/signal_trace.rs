@@ -0,0 +1,51 @@
+use hdi::prelude::*;
+
+/// Step of the WebRTC handshake a traced signal belongs to
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SignalTraceEvent {
+    InitRequest,
+    InitAccept,
+    SdpData,
+    Ack,
+}
+
+/// Private record of a traced signaling message that the author sent or received,
+/// so that failed connections can be debugged by comparing the traces of both peers
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SignalTrace {
+    pub trace_id: String,
+    pub connection_id: String,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub peer: AgentPubKey,
+    pub event: SignalTraceEvent,
+    /// Whether the author sent the message rather than received it
+    pub outgoing: bool,
+}
+pub fn validate_create_signal_trace(
+    _action: EntryCreationAction,
+    _signal_trace: SignalTrace,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_signal_trace(
+    _action: Update,
+    _signal_trace: SignalTrace,
+    _original_action: EntryCreationAction,
+    _original_signal_trace: SignalTrace,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a SignalTrace entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_signal_trace(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_signal_trace: SignalTrace,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "SignalTraces cannot be deleted",
+    )))
+}
@@ -0,0 +1,129 @@
+use hdi::prelude::*;
+
+use crate::role_assignment::{validate_role_authority, RoomRole};
+
+pub const SPEAKING_GRANTS: &str = "SPEAKING_GRANTS";
+
+/// Maximum length in bytes of the name of the session of a speaking grant
+pub const MAX_SPEAKING_SESSION_LENGTH: usize = 100;
+
+/// Records that a facilitator gave the floor to an agent that had raised its hand,
+/// so that the speaking queue of a session can be reviewed for fairness. The time of
+/// the grant is the timestamp of the action.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SpeakingGrant {
+    /// Name of the session chosen by its facilitator, e.g. "2024-05-14 peer support"
+    pub session: String,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<u8>"))]
+    pub speaker: AgentPubKey,
+    /// When the speaker raised its hand
+    #[cfg_attr(feature = "schema", schemars(with = "i64"))]
+    pub raised_at: Timestamp,
+    /// The Host or Moderator role assignment of the author. Not needed if the
+    /// progenitor grants the floor.
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub granted_via: Option<ActionHash>,
+}
+
+/// The link tag used for links pointing to SpeakingGrant entries in order to be able
+/// to filter them by session
+pub fn speaking_session_tag(session: &str) -> LinkTag {
+    LinkTag::new(session.as_bytes().to_vec())
+}
+
+pub fn validate_create_speaking_grant(
+    action: EntryCreationAction,
+    speaking_grant: SpeakingGrant,
+) -> ExternResult<ValidateCallbackResult> {
+    if speaking_grant.session.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The session of a speaking grant must not be empty.".into(),
+        ));
+    }
+    if speaking_grant.session.len() > MAX_SPEAKING_SESSION_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The session of a speaking grant must not be longer than {MAX_SPEAKING_SESSION_LENGTH} bytes."
+        )));
+    }
+    if speaking_grant.raised_at > *action.timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The floor cannot be granted before the hand has been raised.".into(),
+        ));
+    }
+    validate_role_authority(
+        &action,
+        speaking_grant.granted_via,
+        &[RoomRole::Host, RoomRole::Moderator],
+        "grant the floor",
+    )
+}
+pub fn validate_update_speaking_grant(
+    _action: Update,
+    _speaking_grant: SpeakingGrant,
+    _original_action: EntryCreationAction,
+    _original_speaking_grant: SpeakingGrant,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Updating a SpeakingGrant entry is not allowed.".into(),
+    ))
+}
+pub fn validate_delete_speaking_grant(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_speaking_grant: SpeakingGrant,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "SpeakingGrants cannot be deleted",
+    )))
+}
+pub fn validate_create_link_all_speaking_grants(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(SPEAKING_GRANTS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllSpeakingGrants links must have the SPEAKING_GRANTS anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Link to SpeakingGrant entry is not an action hash"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let speaking_grant: crate::SpeakingGrant = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must point to a SpeakingGrant entry"
+        ))))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Speaking grants can only be linked by the agent that granted the floor.".into(),
+        ));
+    }
+    if tag != speaking_session_tag(&speaking_grant.session) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllSpeakingGrants link tag must contain the session of the speaking grant.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_speaking_grants(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AllSpeakingGrants links cannot be deleted",
+    )))
+}
@@ -0,0 +1,139 @@
+use hdi::prelude::*;
+
+pub const ALL_TASKS: &str = "ALL_TASKS";
+
+/// Maximum length in bytes of the title of a task
+pub const MAX_TASK_TITLE_LENGTH: usize = 500;
+
+/// Action item agreed on during a call. Tasks live in the room, so that they are
+/// still there in the next session.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Task {
+    pub title: String,
+    /// Agent responsible for the task, if it has been assigned yet
+    #[cfg_attr(feature = "schema", schemars(with = "Option<Vec<u8>>"))]
+    pub assignee: Option<AgentPubKey>,
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub due_at: Option<Timestamp>,
+    pub done: bool,
+}
+
+fn validate_task(task: &Task) -> ValidateCallbackResult {
+    if task.title.trim().is_empty() {
+        return ValidateCallbackResult::Invalid("The title of a task must not be empty.".into());
+    }
+    if task.title.len() > MAX_TASK_TITLE_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "The title of a task must not be longer than {MAX_TASK_TITLE_LENGTH} bytes."
+        ));
+    }
+    ValidateCallbackResult::Valid
+}
+
+pub fn validate_create_task(
+    _action: EntryCreationAction,
+    task: Task,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(validate_task(&task))
+}
+/// Anyone in the room may update a task, e.g. to take it over or to mark it as done
+pub fn validate_update_task(
+    _action: Update,
+    task: Task,
+    _original_action: EntryCreationAction,
+    _original_task: Task,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(validate_task(&task))
+}
+pub fn validate_delete_task(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_task: Task,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != *original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a task can delete it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_task_updates(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    for address in [base_address, target_address] {
+        let action_hash = address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "No action hash associated with link"
+            ))))?;
+        let record = must_get_valid_record(action_hash)?;
+        let _task: crate::Task = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "Linked action must reference a Task entry"
+            ))))?;
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_task_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "TaskUpdates links cannot be deleted",
+    )))
+}
+pub fn validate_create_link_all_tasks(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let path = Path::from(ALL_TASKS);
+    if EntryHash::try_from(base_address).ok() != Some(path.path_entry_hash()?) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllTasks links must have the ALL_TASKS anchor as their base.".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+                "No action hash associated with link"
+            ))))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _task: crate::Task = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Linked action must reference a Task entry"
+        ))))?;
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Deleting an AllTasks link removes the task from the list, which only its author
+/// may do
+pub fn validate_delete_link_all_tasks(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a task can remove it from the task list.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
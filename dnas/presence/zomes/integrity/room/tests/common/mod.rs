@@ -0,0 +1,136 @@
+//! Builders for the actions, hashes and entries that the validation functions of
+//! room_integrity are called with.
+//!
+//! Only validation paths that don't need to reach out to the host (must_get_*,
+//! hash_entry, verify_signature...) can be exercised natively with these fixtures.
+#![allow(dead_code)]
+
+use hdi::prelude::*;
+use room_integrity::*;
+
+pub fn fake_agent(n: u8) -> AgentPubKey {
+    AgentPubKey::from_raw_36(vec![n; 36])
+}
+
+pub fn fake_action_hash(n: u8) -> ActionHash {
+    ActionHash::from_raw_36(vec![n; 36])
+}
+
+pub fn fake_entry_hash(n: u8) -> EntryHash {
+    EntryHash::from_raw_36(vec![n; 36])
+}
+
+pub fn app_entry_type() -> EntryType {
+    EntryType::App(AppEntryDef {
+        entry_index: EntryDefIndex(0),
+        zome_index: ZomeIndex(0),
+        visibility: EntryVisibility::Public,
+    })
+}
+
+pub fn create(author: AgentPubKey) -> Create {
+    Create {
+        author,
+        timestamp: Timestamp::from_micros(0),
+        action_seq: 4,
+        prev_action: fake_action_hash(1),
+        entry_type: app_entry_type(),
+        entry_hash: fake_entry_hash(1),
+        weight: Default::default(),
+    }
+}
+
+pub fn entry_creation_action(author: AgentPubKey) -> EntryCreationAction {
+    EntryCreationAction::Create(create(author))
+}
+
+pub fn update(author: AgentPubKey) -> Update {
+    Update {
+        author,
+        timestamp: Timestamp::from_micros(1),
+        action_seq: 5,
+        prev_action: fake_action_hash(2),
+        original_action_address: fake_action_hash(3),
+        original_entry_address: fake_entry_hash(1),
+        entry_type: app_entry_type(),
+        entry_hash: fake_entry_hash(2),
+        weight: Default::default(),
+    }
+}
+
+pub fn delete(author: AgentPubKey) -> Delete {
+    Delete {
+        author,
+        timestamp: Timestamp::from_micros(1),
+        action_seq: 5,
+        prev_action: fake_action_hash(2),
+        deletes_address: fake_action_hash(3),
+        deletes_entry_address: fake_entry_hash(1),
+        weight: Default::default(),
+    }
+}
+
+pub fn create_link(
+    author: AgentPubKey,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> CreateLink {
+    CreateLink {
+        author,
+        timestamp: Timestamp::from_micros(0),
+        action_seq: 4,
+        prev_action: fake_action_hash(1),
+        base_address,
+        target_address,
+        zome_index: ZomeIndex(0),
+        link_type: LinkType(0),
+        tag,
+        weight: Default::default(),
+    }
+}
+
+pub fn delete_link(author: AgentPubKey, base_address: AnyLinkableHash) -> DeleteLink {
+    DeleteLink {
+        author,
+        timestamp: Timestamp::from_micros(1),
+        action_seq: 5,
+        prev_action: fake_action_hash(2),
+        base_address,
+        link_add_address: fake_action_hash(3),
+    }
+}
+
+pub fn room_info() -> RoomInfo {
+    RoomInfo {
+        name: String::from("Lobby"),
+        icon_src: None,
+        meta_data: None,
+        metadata: None,
+        granted_via: None,
+    }
+}
+
+pub fn descendent_room() -> DescendentRoom {
+    DescendentRoom {
+        network_seed_appendix: String::from("b7f3c2"),
+        dna_hash: DnaHash::from_raw_36(vec![7; 36]),
+        name: String::from("Breakout 1"),
+        icon_src: None,
+        meta_data: None,
+    }
+}
+
+pub fn assert_valid(result: ExternResult<ValidateCallbackResult>) {
+    match result {
+        Ok(ValidateCallbackResult::Valid) => (),
+        other => panic!("Expected Valid, got {other:?}"),
+    }
+}
+
+pub fn assert_invalid(result: ExternResult<ValidateCallbackResult>) {
+    match result {
+        Ok(ValidateCallbackResult::Invalid(_)) => (),
+        other => panic!("Expected Invalid, got {other:?}"),
+    }
+}
@@ -0,0 +1,1608 @@
+mod common;
+
+use common::*;
+use hdi::prelude::*;
+use room_integrity::*;
+
+#[test]
+fn all_agents_link_can_only_be_created_for_oneself() {
+    let alice = fake_agent(1);
+    let bob = fake_agent(2);
+    let base = AnyLinkableHash::from(fake_entry_hash(9));
+
+    let action = create_link(alice.clone(), base.clone(), alice.clone().into(), ().into());
+    assert_valid(validate_create_link_all_agents(
+        action.clone(),
+        action.base_address.clone(),
+        action.target_address.clone(),
+        action.tag.clone(),
+    ));
+
+    let action = create_link(alice.clone(), base.clone(), bob.into(), ().into());
+    assert_invalid(validate_create_link_all_agents(
+        action.clone(),
+        action.base_address.clone(),
+        action.target_address.clone(),
+        action.tag.clone(),
+    ));
+
+    let action = create_link(alice, base, fake_action_hash(4).into(), ().into());
+    assert_invalid(validate_create_link_all_agents(
+        action.clone(),
+        action.base_address.clone(),
+        action.target_address.clone(),
+        action.tag.clone(),
+    ));
+}
+
+#[test]
+fn all_agents_link_cannot_be_deleted() {
+    let alice = fake_agent(1);
+    let base = AnyLinkableHash::from(fake_entry_hash(9));
+    let original = create_link(alice.clone(), base.clone(), alice.clone().into(), ().into());
+    assert_invalid(validate_delete_link_all_agents(
+        delete_link(alice.clone(), base.clone()),
+        original,
+        base,
+        alice.into(),
+        ().into(),
+    ));
+}
+
+#[test]
+fn immutable_entries_cannot_be_updated_or_deleted() {
+    let alice = fake_agent(1);
+
+    assert_invalid(validate_update_room_info(
+        update(alice.clone()),
+        room_info(),
+        entry_creation_action(alice.clone()),
+        room_info(),
+    ));
+    assert_invalid(validate_delete_room_info(
+        delete(alice.clone()),
+        entry_creation_action(alice.clone()),
+        room_info(),
+    ));
+    assert_invalid(validate_update_descendent_room(
+        update(alice.clone()),
+        descendent_room(),
+        entry_creation_action(alice.clone()),
+        descendent_room(),
+    ));
+    assert_invalid(validate_delete_descendent_room(
+        delete(alice.clone()),
+        entry_creation_action(alice.clone()),
+        descendent_room(),
+    ));
+    assert_invalid(validate_update_room_settings(
+        update(alice.clone()),
+        RoomSettings::default(),
+        entry_creation_action(alice.clone()),
+        RoomSettings::default(),
+    ));
+    assert_invalid(validate_delete_room_settings(
+        delete(alice.clone()),
+        entry_creation_action(alice),
+        RoomSettings::default(),
+    ));
+}
+
+#[test]
+fn attachments_can_be_created_updated_and_deleted() {
+    let alice = fake_agent(1);
+    let attachment = Attachment {
+        wal: String::from("weave://hrl/uhC0k/uhCkk"),
+        sort_key: None,
+        publish_at: Some(Timestamp::from_micros(i64::MAX)),
+        audited: false,
+    };
+    assert_valid(validate_create_attachment(
+        entry_creation_action(alice.clone()),
+        attachment.clone(),
+    ));
+    assert_valid(validate_update_attachment(
+        update(alice.clone()),
+        attachment.clone(),
+        entry_creation_action(alice.clone()),
+        attachment.clone(),
+    ));
+    assert_valid(validate_delete_attachment(
+        delete(alice.clone()),
+        entry_creation_action(alice),
+        attachment,
+    ));
+}
+
+#[test]
+fn room_settings_reaction_palette_is_validated() {
+    let alice = fake_agent(1);
+    let with_palette = |palette: Vec<&str>| RoomSettings {
+        reaction_palette: Some(palette.into_iter().map(String::from).collect()),
+        ..Default::default()
+    };
+
+    assert_valid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        RoomSettings::default(),
+    ));
+    assert_valid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        with_palette(vec!["👍", "❤️", "🎉"]),
+    ));
+    assert_invalid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        with_palette(vec!["👍", "👍"]),
+    ));
+    assert_invalid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        with_palette(vec!["👍", " "]),
+    ));
+    assert_invalid(validate_create_room_settings(
+        entry_creation_action(alice),
+        RoomSettings {
+            reaction_palette: Some(
+                (0..=MAX_REACTION_PALETTE_SIZE)
+                    .map(|i| i.to_string())
+                    .collect(),
+            ),
+            ..Default::default()
+        },
+    ));
+}
+
+#[test]
+fn room_settings_reaction_palette_restricts_reactions() {
+    assert!(RoomSettings::default().is_reaction_allowed("🦄"));
+    let room_settings = RoomSettings {
+        reaction_palette: Some(vec![String::from("👍")]),
+        ..Default::default()
+    };
+    assert!(room_settings.is_reaction_allowed("👍"));
+    assert!(!room_settings.is_reaction_allowed("🦄"));
+}
+
+#[test]
+fn room_settings_allow_screen_sharing_unless_disabled() {
+    assert!(RoomSettings::default().screen_share_enabled);
+    let legacy: RoomSettings = serde_json::from_str(r#"{"reaction_palette":null}"#).unwrap();
+    assert!(legacy.screen_share_enabled);
+}
+
+#[test]
+fn room_settings_are_not_archived_unless_set() {
+    assert!(!RoomSettings::default().archived);
+    let legacy: RoomSettings = serde_json::from_str(r#"{"reaction_palette":null}"#).unwrap();
+    assert!(!legacy.archived);
+}
+
+#[test]
+fn room_settings_call_defaults_are_validated() {
+    let legacy: RoomSettings = serde_json::from_str(r#"{"reaction_palette":null}"#).unwrap();
+    assert!(legacy.chat_enabled);
+    assert!(!legacy.mute_on_join);
+    assert!(!legacy.audio_only);
+    assert!(!legacy.waiting_room);
+    assert!(!legacy.locked);
+    assert_eq!(legacy.max_participants, None);
+
+    let alice = fake_agent(1);
+    assert_valid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        RoomSettings {
+            mute_on_join: true,
+            video_off_on_join: true,
+            max_participants: Some(12),
+            chat_enabled: false,
+            ..Default::default()
+        },
+    ));
+    assert_invalid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        RoomSettings {
+            max_participants: Some(0),
+            ..Default::default()
+        },
+    ));
+    assert_valid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        RoomSettings {
+            attendance_promotion: Some(AttendancePromotion { sessions: 5 }),
+            ..Default::default()
+        },
+    ));
+    assert_invalid(validate_create_room_settings(
+        entry_creation_action(alice),
+        RoomSettings {
+            attendance_promotion: Some(AttendancePromotion { sessions: 0 }),
+            ..Default::default()
+        },
+    ));
+}
+
+#[test]
+fn room_settings_welcome_message_is_validated() {
+    let alice = fake_agent(1);
+    let with_welcome_message = |text: String| RoomSettings {
+        welcome_message: Some(WelcomeMessage {
+            text,
+            sound_src: None,
+        }),
+        ..Default::default()
+    };
+
+    assert_valid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        with_welcome_message(String::from("Please stay muted until Q&A")),
+    ));
+    assert_invalid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        with_welcome_message(String::new()),
+    ));
+    assert_invalid(validate_create_room_settings(
+        entry_creation_action(alice),
+        with_welcome_message("a".repeat(MAX_WELCOME_MESSAGE_LENGTH + 1)),
+    ));
+}
+
+#[test]
+fn room_info_metadata_is_validated() {
+    let alice = fake_agent(1);
+    let with_metadata = |metadata: RoomMetadata| RoomInfo {
+        metadata: Some(metadata),
+        ..room_info()
+    };
+
+    assert_valid(validate_create_room_info(
+        entry_creation_action(alice.clone()),
+        room_info(),
+    ));
+    assert_valid(validate_create_room_info(
+        entry_creation_action(alice.clone()),
+        with_metadata(RoomMetadata {
+            labels: vec![String::from("support-group")],
+            external_links: vec![ExternalLink {
+                label: String::from("Website"),
+                url: String::from("https://example.org"),
+            }],
+            locale: Some(String::from("en")),
+        }),
+    ));
+    assert_invalid(validate_create_room_info(
+        entry_creation_action(alice.clone()),
+        with_metadata(RoomMetadata {
+            labels: vec![String::new()],
+            ..Default::default()
+        }),
+    ));
+    assert_invalid(validate_create_room_info(
+        entry_creation_action(alice.clone()),
+        with_metadata(RoomMetadata {
+            external_links: vec![ExternalLink {
+                label: String::from("Website"),
+                url: String::new(),
+            }],
+            ..Default::default()
+        }),
+    ));
+    assert_invalid(validate_create_room_info(
+        entry_creation_action(alice),
+        with_metadata(RoomMetadata {
+            locale: Some(String::new()),
+            ..Default::default()
+        }),
+    ));
+}
+
+#[test]
+fn room_info_metadata_falls_back_to_legacy_meta_data() {
+    let legacy = RoomInfo {
+        meta_data: Some(String::from(r#"{"labels":["legacy"]}"#)),
+        ..room_info()
+    };
+    assert_eq!(
+        legacy.room_metadata(),
+        Some(RoomMetadata {
+            labels: vec![String::from("legacy")],
+            ..Default::default()
+        })
+    );
+
+    let structured = RoomInfo {
+        metadata: Some(RoomMetadata {
+            locale: Some(String::from("de")),
+            ..Default::default()
+        }),
+        ..legacy
+    };
+    assert_eq!(
+        structured.room_metadata().and_then(|m| m.locale),
+        Some(String::from("de"))
+    );
+
+    let unparseable = RoomInfo {
+        meta_data: Some(String::from("not json")),
+        ..room_info()
+    };
+    assert_eq!(unparseable.room_metadata(), None);
+}
+
+#[test]
+fn external_references_are_validated() {
+    let alice = fake_agent(1);
+    let bob = fake_agent(2);
+    assert_valid(validate_create_external_reference(
+        entry_creation_action(alice.clone()),
+        ExternalReference {
+            wal: String::from("weave://hrl/uhC0k/uhCkk"),
+        },
+    ));
+    assert_invalid(validate_create_external_reference(
+        entry_creation_action(alice.clone()),
+        ExternalReference {
+            wal: String::from("  "),
+        },
+    ));
+
+    let base = AnyLinkableHash::from(fake_entry_hash(9));
+    let target = AnyLinkableHash::from(fake_entry_hash(8));
+    let original = create_link(alice.clone(), base.clone(), target.clone(), ().into());
+    assert_valid(validate_delete_link_external_references(
+        delete_link(alice, base.clone()),
+        original.clone(),
+        base.clone(),
+        target.clone(),
+        ().into(),
+    ));
+    assert_invalid(validate_delete_link_external_references(
+        delete_link(bob, base.clone()),
+        original,
+        base,
+        target,
+        ().into(),
+    ));
+}
+
+#[test]
+fn caption_preference_language_must_not_be_empty() {
+    let alice = fake_agent(1);
+    assert_valid(validate_create_caption_preference(
+        entry_creation_action(alice.clone()),
+        CaptionPreference {
+            needs_captions: true,
+            language: None,
+        },
+    ));
+    assert_invalid(validate_create_caption_preference(
+        entry_creation_action(alice),
+        CaptionPreference {
+            needs_captions: true,
+            language: Some(String::new()),
+        },
+    ));
+}
+
+#[test]
+fn interpreter_registrations_are_validated() {
+    let alice = fake_agent(1);
+    let bob = fake_agent(2);
+    assert_valid(validate_create_interpreter_registration(
+        entry_creation_action(alice.clone()),
+        InterpreterRegistration {
+            language: String::from("de"),
+        },
+    ));
+    assert_invalid(validate_create_interpreter_registration(
+        entry_creation_action(alice.clone()),
+        InterpreterRegistration {
+            language: String::new(),
+        },
+    ));
+
+    let base = AnyLinkableHash::from(fake_entry_hash(9));
+    let target = AnyLinkableHash::from(fake_action_hash(8));
+    let original = create_link(alice.clone(), base.clone(), target.clone(), ().into());
+    assert_valid(validate_delete_link_all_interpreter_registrations(
+        delete_link(alice, base.clone()),
+        original.clone(),
+        base.clone(),
+        target.clone(),
+        ().into(),
+    ));
+    assert_invalid(validate_delete_link_all_interpreter_registrations(
+        delete_link(bob, base.clone()),
+        original,
+        base,
+        target,
+        ().into(),
+    ));
+}
+
+#[test]
+fn interpretation_language_is_parsed_from_connection_type() {
+    assert_eq!(interpretation_language("interpretation:de"), Some("de"));
+    assert_eq!(interpretation_language("interpretation:"), Some(""));
+    assert_eq!(interpretation_language("screen"), None);
+}
+
+#[test]
+fn breakout_round_tags_differ_per_round() {
+    assert_eq!(breakout_round_tag(1), breakout_round_tag(1));
+    assert_ne!(breakout_round_tag(1), breakout_round_tag(2));
+}
+
+#[test]
+fn device_binding_must_be_created_by_the_bound_device() {
+    let alice = fake_agent(1);
+    let alice_phone = fake_agent(2);
+    let device_binding = DeviceBinding {
+        primary_agent: alice.clone(),
+        device_agent: alice_phone,
+        signature: Signature([0; 64]),
+    };
+    assert_invalid(validate_create_device_binding(
+        entry_creation_action(alice.clone()),
+        device_binding,
+    ));
+
+    let self_binding = DeviceBinding {
+        primary_agent: alice.clone(),
+        device_agent: alice.clone(),
+        signature: Signature([0; 64]),
+    };
+    assert_invalid(validate_create_device_binding(
+        entry_creation_action(alice),
+        self_binding,
+    ));
+}
+
+#[test]
+fn deprecation_notice_message_is_validated() {
+    let alice = fake_agent(1);
+    let with_message = |message: String| DeprecationNotice {
+        message,
+        successor: Some(String::from("weave://hrl/uhC0k/uhCkk")),
+        effective_at: None,
+        granted_via: None,
+        withdrawn: false,
+    };
+    assert_invalid(validate_create_deprecation_notice(
+        entry_creation_action(alice.clone()),
+        with_message(String::from(" ")),
+    ));
+    assert_invalid(validate_create_deprecation_notice(
+        entry_creation_action(alice),
+        with_message("a".repeat(MAX_DEPRECATION_NOTICE_LENGTH + 1)),
+    ));
+}
+
+#[test]
+fn deprecation_notice_links_cannot_be_deleted() {
+    let alice = fake_agent(1);
+    let base = AnyLinkableHash::from(fake_entry_hash(9));
+    let target = AnyLinkableHash::from(fake_action_hash(4));
+    assert_invalid(validate_delete_link_deprecation_notice_updates(
+        delete_link(alice.clone(), base.clone()),
+        create_link(alice, base.clone(), target.clone(), ().into()),
+        base,
+        target,
+        ().into(),
+    ));
+}
+
+#[test]
+fn announcements_are_validated() {
+    let alice = fake_agent(1);
+    let announcement = Announcement {
+        message: String::from("Das Treffen beginnt um 18 Uhr"),
+        locale: Some(String::from("de")),
+        publish_at: None,
+        granted_via: None,
+    };
+    assert_invalid(validate_create_announcement(
+        entry_creation_action(alice.clone()),
+        Announcement {
+            message: String::from(" "),
+            ..announcement.clone()
+        },
+    ));
+    assert_invalid(validate_create_announcement(
+        entry_creation_action(alice.clone()),
+        Announcement {
+            message: "a".repeat(MAX_ANNOUNCEMENT_LENGTH + 1),
+            ..announcement.clone()
+        },
+    ));
+    assert_invalid(validate_create_announcement(
+        entry_creation_action(alice.clone()),
+        Announcement {
+            locale: Some(String::new()),
+            ..announcement.clone()
+        },
+    ));
+    assert_invalid(validate_delete_announcement(
+        delete(fake_agent(2)),
+        entry_creation_action(alice),
+        announcement,
+    ));
+}
+
+#[test]
+fn announcement_locales_match_their_regions() {
+    assert!(locale_matches(None, "en"));
+    assert!(locale_matches(Some("de"), "de-CH"));
+    assert!(locale_matches(Some("DE-ch"), "de-CH"));
+    assert!(!locale_matches(Some("de-CH"), "de"));
+    assert!(!locale_matches(Some("en"), "eo"));
+}
+
+#[test]
+fn tasks_are_validated() {
+    let alice = fake_agent(1);
+    let task = Task {
+        title: String::from("Send the slides around"),
+        assignee: Some(fake_agent(2)),
+        due_at: None,
+        done: false,
+    };
+    assert_valid(validate_update_task(
+        update(fake_agent(2)),
+        Task {
+            done: true,
+            ..task.clone()
+        },
+        entry_creation_action(alice.clone()),
+        task.clone(),
+    ));
+    assert_invalid(validate_create_task(
+        entry_creation_action(alice.clone()),
+        Task {
+            title: String::from(" "),
+            ..task.clone()
+        },
+    ));
+    assert_invalid(validate_create_task(
+        entry_creation_action(alice.clone()),
+        Task {
+            title: "a".repeat(MAX_TASK_TITLE_LENGTH + 1),
+            ..task.clone()
+        },
+    ));
+    assert_invalid(validate_delete_task(
+        delete(fake_agent(2)),
+        entry_creation_action(alice),
+        task,
+    ));
+}
+
+#[test]
+fn prompt_decks_are_validated() {
+    let alice = fake_agent(1);
+    let with_prompts = |prompts: Vec<String>| PromptDeck {
+        prompts,
+        granted_via: None,
+    };
+    assert_invalid(validate_create_prompt_deck(
+        entry_creation_action(alice.clone()),
+        with_prompts(vec![String::from("How are you arriving?"), String::new()]),
+    ));
+    assert_invalid(validate_create_prompt_deck(
+        entry_creation_action(alice.clone()),
+        with_prompts(vec!["a".repeat(MAX_PROMPT_LENGTH + 1)]),
+    ));
+    assert_invalid(validate_create_prompt_deck(
+        entry_creation_action(alice),
+        with_prompts(vec![String::from("Hi"); MAX_PROMPTS + 1]),
+    ));
+}
+
+#[test]
+fn prompts_are_drawn_deterministically() {
+    let prompt_deck = PromptDeck {
+        prompts: (0..7).map(|i| format!("Prompt {i}")).collect(),
+        granted_via: None,
+    };
+    assert_eq!(prompt_deck.draw("call-42"), prompt_deck.draw("call-42"));
+    assert!(prompt_deck.draw("").is_some());
+    let drawn: Vec<_> = (0..20)
+        .filter_map(|i| prompt_deck.draw(&format!("call-{i}")))
+        .collect();
+    assert!(drawn.iter().any(|prompt| *prompt != drawn[0]));
+    let empty_deck = PromptDeck {
+        prompts: vec![],
+        granted_via: None,
+    };
+    assert_eq!(empty_deck.draw("call-42"), None);
+}
+
+#[test]
+fn speaking_grants_are_validated() {
+    let alice = fake_agent(1);
+    let speaking_grant = SpeakingGrant {
+        session: String::from("2024-05-14 peer support"),
+        speaker: fake_agent(2),
+        raised_at: Timestamp::from_micros(0),
+        granted_via: None,
+    };
+    assert_invalid(validate_create_speaking_grant(
+        entry_creation_action(alice.clone()),
+        SpeakingGrant {
+            session: String::from(" "),
+            ..speaking_grant.clone()
+        },
+    ));
+    assert_invalid(validate_create_speaking_grant(
+        entry_creation_action(alice.clone()),
+        SpeakingGrant {
+            raised_at: Timestamp::from_micros(i64::MAX),
+            ..speaking_grant.clone()
+        },
+    ));
+    assert_invalid(validate_update_speaking_grant(
+        update(alice.clone()),
+        speaking_grant.clone(),
+        entry_creation_action(alice),
+        speaking_grant,
+    ));
+}
+
+#[test]
+fn chat_message_content_is_validated() {
+    let alice = fake_agent(1);
+    let chat_message = |content: String| ChatMessage {
+        content,
+        reply_to: None,
+    };
+    assert_valid(validate_create_chat_message(
+        entry_creation_action(alice.clone()),
+        chat_message(String::from("Hi all!")),
+    ));
+    assert_invalid(validate_create_chat_message(
+        entry_creation_action(alice.clone()),
+        chat_message(String::from("\n")),
+    ));
+    assert_invalid(validate_create_chat_message(
+        entry_creation_action(alice),
+        chat_message("a".repeat(MAX_CHAT_MESSAGE_LENGTH + 1)),
+    ));
+}
+
+#[test]
+fn chat_messages_can_only_be_edited_and_deleted_by_their_author() {
+    let alice = fake_agent(1);
+    let bob = fake_agent(2);
+    let chat_message = || ChatMessage {
+        content: String::from("Hi all!"),
+        reply_to: None,
+    };
+    assert_valid(validate_update_chat_message(
+        update(alice.clone()),
+        chat_message(),
+        entry_creation_action(alice.clone()),
+        chat_message(),
+    ));
+    assert_invalid(validate_update_chat_message(
+        update(bob.clone()),
+        chat_message(),
+        entry_creation_action(alice.clone()),
+        chat_message(),
+    ));
+    assert_invalid(validate_update_chat_message(
+        update(alice.clone()),
+        ChatMessage {
+            content: String::from(" "),
+            reply_to: None,
+        },
+        entry_creation_action(alice.clone()),
+        chat_message(),
+    ));
+    assert_invalid(validate_update_chat_message(
+        update(alice.clone()),
+        ChatMessage {
+            reply_to: Some(fake_action_hash(6)),
+            ..chat_message()
+        },
+        entry_creation_action(alice.clone()),
+        chat_message(),
+    ));
+    assert_valid(validate_delete_chat_message(
+        delete(alice.clone()),
+        entry_creation_action(alice.clone()),
+        chat_message(),
+    ));
+    assert_invalid(validate_delete_chat_message(
+        delete(bob.clone()),
+        entry_creation_action(alice.clone()),
+        chat_message(),
+    ));
+
+    let base = AnyLinkableHash::from(fake_entry_hash(9));
+    let target = AnyLinkableHash::from(fake_action_hash(5));
+    let link = create_link(alice.clone(), base.clone(), target.clone(), ().into());
+    assert_valid(validate_delete_link_all_chat_messages(
+        delete_link(alice, base.clone()),
+        link.clone(),
+        base.clone(),
+        target.clone(),
+        ().into(),
+    ));
+    assert_invalid(validate_delete_link_all_chat_messages(
+        delete_link(bob, base.clone()),
+        link,
+        base,
+        target,
+        ().into(),
+    ));
+}
+
+#[test]
+fn attachments_are_published_at_their_publish_time() {
+    let attachment = |publish_at: Option<i64>| Attachment {
+        wal: String::from("weave://hrl/uhC0k/uhCkk"),
+        sort_key: None,
+        publish_at: publish_at.map(Timestamp::from_micros),
+        audited: false,
+    };
+    let now = Timestamp::from_micros(1_000);
+    assert!(attachment(None).is_published_at(now));
+    assert!(attachment(Some(999)).is_published_at(now));
+    assert!(attachment(Some(1_000)).is_published_at(now));
+    assert!(!attachment(Some(1_001)).is_published_at(now));
+}
+
+#[test]
+fn announcements_are_published_at_their_publish_time() {
+    let announcement = |publish_at: Option<i64>| Announcement {
+        message: String::from("The meeting starts at 6pm"),
+        locale: None,
+        publish_at: publish_at.map(Timestamp::from_micros),
+        granted_via: None,
+    };
+    let now = Timestamp::from_micros(1_000);
+    assert!(announcement(None).is_published_at(now));
+    assert!(announcement(Some(1_000)).is_published_at(now));
+    assert!(!announcement(Some(1_001)).is_published_at(now));
+}
+
+#[test]
+fn questions_are_validated() {
+    let alice = fake_agent(1);
+    let question = |content: String| Question { content };
+    assert_valid(validate_create_question(
+        entry_creation_action(alice.clone()),
+        question(String::from("Will the slides be shared?")),
+    ));
+    assert_invalid(validate_create_question(
+        entry_creation_action(alice.clone()),
+        question(String::from(" ")),
+    ));
+    assert_invalid(validate_create_question(
+        entry_creation_action(alice),
+        question("a".repeat(MAX_QUESTION_LENGTH + 1)),
+    ));
+}
+
+#[test]
+fn question_upvotes_can_only_be_cast_and_withdrawn_by_the_voter() {
+    let alice = fake_agent(1);
+    let bob = fake_agent(2);
+    let base = AnyLinkableHash::from(fake_action_hash(5));
+    assert_invalid(validate_create_link_question_upvotes(
+        create_link(alice.clone(), base.clone(), bob.clone().into(), ().into()),
+        base.clone(),
+        bob.clone().into(),
+        ().into(),
+    ));
+
+    let upvote = create_link(alice.clone(), base.clone(), alice.clone().into(), ().into());
+    assert_valid(validate_delete_link_question_upvotes(
+        delete_link(alice.clone(), base.clone()),
+        upvote.clone(),
+        base.clone(),
+        alice.clone().into(),
+        ().into(),
+    ));
+    assert_invalid(validate_delete_link_question_upvotes(
+        delete_link(bob, base.clone()),
+        upvote,
+        base,
+        alice.into(),
+        ().into(),
+    ));
+}
+
+#[test]
+fn check_ins_are_validated() {
+    let alice = fake_agent(1);
+    let check_in = CheckIn {
+        session: String::from("2024-05-14 opening"),
+        emoji: Some(String::from("🙂")),
+        score: Some(4),
+        word: Some(String::from("rested")),
+    };
+    assert_valid(validate_create_check_in(
+        entry_creation_action(alice.clone()),
+        check_in.clone(),
+    ));
+    assert_valid(validate_create_check_in(
+        entry_creation_action(alice.clone()),
+        CheckIn {
+            emoji: None,
+            word: None,
+            ..check_in.clone()
+        },
+    ));
+    for invalid in [
+        CheckIn {
+            session: String::from(" "),
+            ..check_in.clone()
+        },
+        CheckIn {
+            emoji: None,
+            score: None,
+            ..check_in.clone()
+        },
+        CheckIn {
+            score: Some(MAX_CHECK_IN_SCORE + 1),
+            ..check_in.clone()
+        },
+        CheckIn {
+            score: Some(0),
+            ..check_in.clone()
+        },
+        CheckIn {
+            word: Some(String::from("two words")),
+            ..check_in.clone()
+        },
+        CheckIn {
+            emoji: Some("🙂".repeat(MAX_CHECK_IN_EMOJI_LENGTH)),
+            ..check_in.clone()
+        },
+    ] {
+        assert_invalid(validate_create_check_in(
+            entry_creation_action(alice.clone()),
+            invalid,
+        ));
+    }
+}
+
+#[test]
+fn pinned_content_is_validated() {
+    let alice = fake_agent(1);
+    let bob = fake_agent(2);
+    let base = AnyLinkableHash::from(fake_entry_hash(9));
+    let entry_target = AnyLinkableHash::from(fake_entry_hash(5));
+    assert_invalid(validate_create_link_pinned_content(
+        create_link(alice.clone(), base.clone(), entry_target.clone(), ().into()),
+        base.clone(),
+        entry_target,
+        ().into(),
+    ));
+
+    let target = AnyLinkableHash::from(fake_action_hash(5));
+    let pin = create_link(alice.clone(), base.clone(), target.clone(), ().into());
+    assert_valid(validate_delete_link_pinned_content(
+        delete_link(alice, base.clone()),
+        pin.clone(),
+        base.clone(),
+        target.clone(),
+        ().into(),
+    ));
+    assert_invalid(validate_delete_link_pinned_content(
+        delete_link(bob, base.clone()),
+        pin,
+        base,
+        target,
+        ().into(),
+    ));
+}
+
+#[test]
+fn event_subscriptions_are_validated() {
+    let alice = fake_agent(1);
+    let bot = fake_agent(3);
+    let event_subscription = |event_kinds: Vec<EventKind>| EventSubscription {
+        event_kinds,
+        delivery_agent: bot.clone(),
+    };
+    assert_valid(validate_create_event_subscription(
+        entry_creation_action(alice.clone()),
+        event_subscription(vec![EventKind::AttachmentCreated, EventKind::CallStarted]),
+    ));
+    assert_invalid(validate_create_event_subscription(
+        entry_creation_action(alice.clone()),
+        event_subscription(vec![]),
+    ));
+    assert_invalid(validate_create_event_subscription(
+        entry_creation_action(alice.clone()),
+        event_subscription(vec![EventKind::CallStarted, EventKind::CallStarted]),
+    ));
+    assert_invalid(validate_delete_event_subscription(
+        delete(bot.clone()),
+        entry_creation_action(alice),
+        event_subscription(vec![EventKind::CallStarted]),
+    ));
+}
+
+#[test]
+fn directory_listing_is_validated() {
+    let alice = fake_agent(1);
+    let directory_listing = DirectoryListing {
+        name: String::from("Rust meetup"),
+        description: String::from("Monthly talks and pairing sessions"),
+        icon_src: None,
+        metadata: RoomMetadata {
+            labels: vec![String::from("rust")],
+            external_links: vec![],
+            locale: Some(String::from("en")),
+        },
+        join_link: None,
+    };
+    assert_valid(validate_create_directory_listing(
+        entry_creation_action(alice.clone()),
+        directory_listing.clone(),
+    ));
+    for invalid in [
+        DirectoryListing {
+            name: String::from(" "),
+            ..directory_listing.clone()
+        },
+        DirectoryListing {
+            description: "a".repeat(MAX_DIRECTORY_LISTING_DESCRIPTION_LENGTH + 1),
+            ..directory_listing.clone()
+        },
+        DirectoryListing {
+            metadata: RoomMetadata {
+                labels: vec![String::from("")],
+                external_links: vec![],
+                locale: None,
+            },
+            ..directory_listing.clone()
+        },
+    ] {
+        assert_invalid(validate_create_directory_listing(
+            entry_creation_action(alice.clone()),
+            invalid,
+        ));
+    }
+}
+
+#[test]
+fn role_assignments_can_be_given_up_and_revoked_by_their_granter() {
+    let alice = fake_agent(1);
+    let bob = fake_agent(2);
+    let role_assignment = RoleAssignment {
+        agent: bob.clone(),
+        role: RoomRole::Host,
+        granted_via: None,
+        attended: Vec::new(),
+    };
+    assert_invalid(validate_update_role_assignment(
+        update(alice.clone()),
+        role_assignment.clone(),
+        entry_creation_action(alice.clone()),
+        role_assignment.clone(),
+    ));
+    assert_valid(validate_delete_role_assignment(
+        delete(alice.clone()),
+        entry_creation_action(alice.clone()),
+        role_assignment.clone(),
+    ));
+    assert_valid(validate_delete_role_assignment(
+        delete(bob),
+        entry_creation_action(alice),
+        role_assignment,
+    ));
+}
+
+#[test]
+fn roles_earned_by_attendance_are_self_assigned_contributor_roles() {
+    let alice = fake_agent(1);
+    let bob = fake_agent(2);
+    let earned =
+        |agent: AgentPubKey, role: RoomRole, granted_via: Option<ActionHash>| RoleAssignment {
+            agent,
+            role,
+            granted_via,
+            attended: vec![fake_action_hash(7)],
+        };
+    // These are rejected before the referenced room settings are fetched
+    assert_invalid(validate_create_role_assignment(
+        entry_creation_action(alice.clone()),
+        earned(bob, RoomRole::Contributor, Some(fake_action_hash(6))),
+    ));
+    assert_invalid(validate_create_role_assignment(
+        entry_creation_action(alice.clone()),
+        earned(
+            alice.clone(),
+            RoomRole::Moderator,
+            Some(fake_action_hash(6)),
+        ),
+    ));
+    assert_invalid(validate_create_role_assignment(
+        entry_creation_action(alice.clone()),
+        earned(alice, RoomRole::Contributor, None),
+    ));
+}
+
+#[test]
+fn attendance_attestations_are_validated() {
+    let alice = fake_agent(1);
+    let with_agents = |agents: Vec<AgentPubKey>| AttendanceAttestation {
+        session: fake_action_hash(5),
+        agents,
+        granted_via: None,
+    };
+    assert_invalid(validate_create_attendance_attestation(
+        entry_creation_action(alice.clone()),
+        with_agents(Vec::new()),
+    ));
+    assert_invalid(validate_create_attendance_attestation(
+        entry_creation_action(alice.clone()),
+        with_agents(vec![fake_agent(2); MAX_ATTESTED_AGENTS + 1]),
+    ));
+    assert_invalid(validate_update_attendance_attestation(
+        update(alice.clone()),
+        with_agents(vec![fake_agent(2)]),
+        entry_creation_action(alice.clone()),
+        with_agents(vec![fake_agent(2)]),
+    ));
+    assert_invalid(validate_delete_attendance_attestation(
+        delete(alice.clone()),
+        entry_creation_action(alice.clone()),
+        with_agents(vec![fake_agent(2)]),
+    ));
+
+    let base = AnyLinkableHash::from(fake_agent(2));
+    let target = AnyLinkableHash::from(fake_action_hash(8));
+    assert_invalid(validate_delete_link_agent_to_attendance_attestations(
+        delete_link(alice.clone(), base.clone()),
+        create_link(alice, base.clone(), target.clone(), ().into()),
+        base,
+        target,
+        ().into(),
+    ));
+}
+
+#[test]
+fn agent_bans_are_validated() {
+    let alice = fake_agent(1);
+    let bob = fake_agent(2);
+    let agent_ban = AgentBan {
+        agent: bob.clone(),
+        reason: Some(String::from("Spam")),
+        granted_via: None,
+    };
+    assert_invalid(validate_create_agent_ban(
+        entry_creation_action(bob.clone()),
+        agent_ban.clone(),
+    ));
+    assert_invalid(validate_create_agent_ban(
+        entry_creation_action(alice.clone()),
+        AgentBan {
+            reason: Some("a".repeat(MAX_BAN_REASON_LENGTH + 1)),
+            ..agent_ban.clone()
+        },
+    ));
+    assert_invalid(validate_update_agent_ban(
+        update(alice.clone()),
+        agent_ban.clone(),
+        entry_creation_action(alice.clone()),
+        agent_ban.clone(),
+    ));
+    assert_valid(validate_delete_agent_ban(
+        delete(alice.clone()),
+        entry_creation_action(alice),
+        agent_ban,
+    ));
+}
+
+#[test]
+fn calls_can_only_be_handed_off_to_other_devices() {
+    let laptop = fake_agent(1);
+    let phone = fake_agent(2);
+    let call_hand_off = CallHandOff {
+        from_device: laptop.clone(),
+        peers: vec![fake_agent(3)],
+        connection_ids: vec![String::from("0a1b")],
+    };
+    assert_valid(validate_create_call_hand_off(
+        entry_creation_action(phone),
+        call_hand_off.clone(),
+    ));
+    assert_invalid(validate_create_call_hand_off(
+        entry_creation_action(laptop),
+        call_hand_off,
+    ));
+}
+
+#[test]
+fn permission_overrides_can_be_removed_by_their_issuer() {
+    let alice = fake_agent(1);
+    let permission_override = PermissionOverride {
+        agent: fake_agent(2),
+        capability: Capability::ScreenShare,
+        allowed: true,
+        granted_via: None,
+    };
+    assert_invalid(validate_update_permission_override(
+        update(alice.clone()),
+        PermissionOverride {
+            allowed: false,
+            ..permission_override.clone()
+        },
+        entry_creation_action(alice.clone()),
+        permission_override.clone(),
+    ));
+    assert_valid(validate_delete_permission_override(
+        delete(alice.clone()),
+        entry_creation_action(alice),
+        permission_override,
+    ));
+}
+
+#[test]
+fn ownership_claims_follow_their_timeline() {
+    let alice = fake_agent(1);
+    let claimed_at = create(alice.clone()).timestamp;
+    let ownership_claim = OwnershipClaim {
+        last_host_action: fake_action_hash(1),
+        host_role_assignment: None,
+        matures_at: claimed_at,
+    };
+    assert_invalid(validate_create_ownership_claim(
+        entry_creation_action(alice.clone()),
+        ownership_claim.clone(),
+    ));
+    assert!(ownership_claim_maturity(claimed_at) > claimed_at);
+    assert!(ownership_claimable_from(claimed_at) > ownership_claim_maturity(claimed_at));
+    assert_valid(validate_delete_ownership_claim(
+        delete(alice.clone()),
+        entry_creation_action(alice.clone()),
+        ownership_claim.clone(),
+    ));
+    assert_invalid(validate_delete_ownership_claim(
+        delete(fake_agent(2)),
+        entry_creation_action(alice),
+        ownership_claim,
+    ));
+    assert_invalid(validate_delete_link_ownership_claim_vetoes(
+        delete_link(fake_agent(2), fake_action_hash(1).into()),
+        create_link(
+            fake_agent(2),
+            fake_action_hash(1).into(),
+            fake_agent(2).into(),
+            ().into(),
+        ),
+        fake_action_hash(1).into(),
+        fake_agent(2).into(),
+        ().into(),
+    ));
+}
+
+#[test]
+fn admissions_can_only_be_revoked_by_the_admitting_agent() {
+    let alice = fake_agent(1);
+    let admission = Admission {
+        agent: fake_agent(2),
+        granted_via: None,
+    };
+    assert_valid(validate_delete_admission(
+        delete(alice.clone()),
+        entry_creation_action(alice.clone()),
+        admission.clone(),
+    ));
+    assert_invalid(validate_delete_admission(
+        delete(fake_agent(2)),
+        entry_creation_action(alice),
+        admission,
+    ));
+}
+
+#[test]
+fn meta_data_must_be_decodable() {
+    let metadata = RoomMetadata {
+        labels: vec![String::from("rust")],
+        ..Default::default()
+    };
+    let encoded = encode_metadata(&metadata).unwrap();
+    assert_eq!(
+        decode_metadata::<RoomMetadata>(&encoded),
+        Ok(metadata.clone())
+    );
+    assert_eq!(
+        decode_metadata::<RoomMetadata>(r#"{"labels":["rust"]}"#),
+        Ok(metadata)
+    );
+    assert!(decode_metadata::<RoomMetadata>(r#"{"version":99,"data":{}}"#).is_err());
+
+    let alice = fake_agent(1);
+    assert_valid(validate_create_room_info(
+        entry_creation_action(alice.clone()),
+        RoomInfo {
+            meta_data: Some(encoded.clone()),
+            ..room_info()
+        },
+    ));
+    assert_invalid(validate_create_room_info(
+        entry_creation_action(alice.clone()),
+        RoomInfo {
+            meta_data: Some(String::from("not json")),
+            ..room_info()
+        },
+    ));
+    assert_valid(validate_create_descendent_room(
+        entry_creation_action(alice.clone()),
+        DescendentRoom {
+            meta_data: Some(encoded),
+            ..descendent_room()
+        },
+    ));
+    assert_invalid(validate_create_descendent_room(
+        entry_creation_action(alice),
+        DescendentRoom {
+            meta_data: Some(String::from("not json")),
+            ..descendent_room()
+        },
+    ));
+}
+
+#[test]
+fn descendent_room_network_seeds_are_derived_unambiguously() {
+    assert_eq!(
+        descendent_room_network_seed("applet-seed", "b7f3c2"),
+        "groupRoom#applet-seed#b7f3c2"
+    );
+    let alice = fake_agent(1);
+    assert_invalid(validate_create_descendent_room(
+        entry_creation_action(alice.clone()),
+        DescendentRoom {
+            network_seed_appendix: String::from("b7#f3c2"),
+            ..descendent_room()
+        },
+    ));
+    assert_invalid(validate_create_descendent_room(
+        entry_creation_action(alice),
+        DescendentRoom {
+            network_seed_appendix: String::new(),
+            ..descendent_room()
+        },
+    ));
+}
+
+#[test]
+fn host_transfers_are_immutable_and_involve_both_hosts() {
+    let alice = fake_agent(1);
+    let host_transfer = HostTransfer {
+        from_host: alice.clone(),
+        to_host: fake_agent(2),
+        granted_via: None,
+    };
+    assert_invalid(validate_create_host_transfer(
+        entry_creation_action(alice.clone()),
+        HostTransfer {
+            to_host: alice.clone(),
+            ..host_transfer.clone()
+        },
+    ));
+    assert_invalid(validate_create_host_transfer(
+        entry_creation_action(fake_agent(3)),
+        host_transfer.clone(),
+    ));
+    assert_invalid(validate_update_host_transfer(
+        update(alice.clone()),
+        host_transfer.clone(),
+        entry_creation_action(alice.clone()),
+        host_transfer.clone(),
+    ));
+    assert_invalid(validate_delete_host_transfer(
+        delete(alice.clone()),
+        entry_creation_action(alice),
+        host_transfer,
+    ));
+}
+
+#[test]
+fn finalized_minutes_are_immutable_and_involve_both_signers() {
+    let alice = fake_agent(1);
+    let minutes_finalized = MinutesFinalized {
+        note_revision: fake_action_hash(1),
+        facilitator: alice.clone(),
+        co_signer: fake_agent(2),
+    };
+    assert_invalid(validate_create_minutes_finalized(
+        entry_creation_action(alice.clone()),
+        MinutesFinalized {
+            co_signer: alice.clone(),
+            ..minutes_finalized.clone()
+        },
+    ));
+    assert_invalid(validate_create_minutes_finalized(
+        entry_creation_action(fake_agent(3)),
+        minutes_finalized.clone(),
+    ));
+    assert_invalid(validate_update_minutes_finalized(
+        update(alice.clone()),
+        minutes_finalized.clone(),
+        entry_creation_action(alice.clone()),
+        minutes_finalized.clone(),
+    ));
+    assert_invalid(validate_delete_minutes_finalized(
+        delete(alice.clone()),
+        entry_creation_action(alice),
+        minutes_finalized,
+    ));
+}
+
+#[test]
+fn presenter_states_are_immutable_and_cannot_start_in_the_future() {
+    let alice = fake_agent(1);
+    let action = entry_creation_action(alice.clone());
+    let presenter_state = PresenterState {
+        presenter: Some(fake_agent(2)),
+        started_at: *action.timestamp(),
+        granted_via: None,
+    };
+    assert_invalid(validate_create_presenter_state(
+        action.clone(),
+        PresenterState {
+            started_at: Timestamp::from_micros(action.timestamp().as_micros() + 1),
+            ..presenter_state.clone()
+        },
+    ));
+    assert_invalid(validate_update_presenter_state(
+        update(alice.clone()),
+        presenter_state.clone(),
+        action.clone(),
+        presenter_state.clone(),
+    ));
+    assert_invalid(validate_delete_presenter_state(
+        delete(alice),
+        action,
+        presenter_state,
+    ));
+}
+
+#[test]
+fn media_keys_must_have_the_expected_length() {
+    let alice = fake_agent(1);
+    let with_key = |key: Vec<u8>| MediaKeyShare {
+        sender: alice.clone(),
+        epoch: 0,
+        key,
+    };
+    assert_valid(validate_create_media_key_share(
+        entry_creation_action(alice.clone()),
+        with_key(vec![7; MEDIA_KEY_LENGTH]),
+    ));
+    assert_invalid(validate_create_media_key_share(
+        entry_creation_action(alice.clone()),
+        with_key(vec![7; MEDIA_KEY_LENGTH - 1]),
+    ));
+    assert_invalid(validate_delete_media_key_share(
+        delete(alice.clone()),
+        entry_creation_action(alice.clone()),
+        with_key(vec![7; MEDIA_KEY_LENGTH]),
+    ));
+}
+
+#[test]
+fn attachment_accesses_cannot_be_recorded_ahead_of_time() {
+    let alice = fake_agent(1);
+    let access = AttachmentAccess {
+        attachment_hash: fake_action_hash(1),
+        accessed_by: alice.clone(),
+        accessed_at: Timestamp::from_micros(0),
+    };
+    assert_valid(validate_create_attachment_access(
+        entry_creation_action(alice.clone()),
+        access.clone(),
+    ));
+    assert_invalid(validate_create_attachment_access(
+        entry_creation_action(alice),
+        AttachmentAccess {
+            accessed_at: Timestamp::from_micros(1),
+            ..access
+        },
+    ));
+}
+
+#[test]
+fn presence_heartbeats_are_recorded_for_the_current_bucket() {
+    let alice = fake_agent(1);
+    let base = AnyLinkableHash::from(alice.clone());
+    let bucket = presence_bucket(Timestamp::from_micros(0));
+    assert_eq!(
+        parse_presence_heartbeat_tag(&presence_heartbeat_tag(bucket, true)),
+        Some((bucket, true))
+    );
+    assert_eq!(
+        parse_presence_heartbeat_tag(&LinkTag::new(vec![1, 2])),
+        None
+    );
+
+    let action = create_link(
+        alice.clone(),
+        base.clone(),
+        base.clone(),
+        presence_heartbeat_tag(bucket, false),
+    );
+    assert_valid(validate_create_link_presence_heartbeats(
+        action.clone(),
+        action.base_address.clone(),
+        action.target_address.clone(),
+        action.tag.clone(),
+    ));
+
+    let action = create_link(
+        alice.clone(),
+        base.clone(),
+        base.clone(),
+        presence_heartbeat_tag(bucket + 1, false),
+    );
+    assert_invalid(validate_create_link_presence_heartbeats(
+        action.clone(),
+        action.base_address.clone(),
+        action.target_address.clone(),
+        action.tag.clone(),
+    ));
+
+    let action = create_link(
+        fake_agent(2),
+        base.clone(),
+        base,
+        presence_heartbeat_tag(bucket, false),
+    );
+    assert_invalid(validate_create_link_presence_heartbeats(
+        action.clone(),
+        action.base_address.clone(),
+        action.target_address.clone(),
+        action.tag.clone(),
+    ));
+}
+
+#[test]
+fn call_session_ends_are_permanent() {
+    let alice = fake_agent(1);
+    let call_session_end = CallSessionEnd { granted_via: None };
+    assert_invalid(validate_update_call_session_end(
+        update(alice.clone()),
+        call_session_end.clone(),
+        entry_creation_action(alice.clone()),
+        call_session_end.clone(),
+    ));
+    assert_invalid(validate_delete_call_session_end(
+        delete(alice.clone()),
+        entry_creation_action(alice),
+        call_session_end,
+    ));
+}
+
+#[test]
+fn moderation_actions_are_permanent_and_bounded() {
+    let alice = fake_agent(1);
+    let moderation_action = ModerationAction {
+        kind: ModerationActionKind::RevokeRole {
+            role: RoomRole::Moderator,
+        },
+        target: fake_agent(2),
+        reason: None,
+        subject: Some(fake_action_hash(1)),
+        granted_via: None,
+    };
+    assert_invalid(validate_create_moderation_action(
+        entry_creation_action(alice.clone()),
+        ModerationAction {
+            subject: None,
+            ..moderation_action.clone()
+        },
+    ));
+    assert_invalid(validate_create_moderation_action(
+        entry_creation_action(alice.clone()),
+        ModerationAction {
+            reason: Some("a".repeat(MAX_MODERATION_REASON_LENGTH + 1)),
+            ..moderation_action.clone()
+        },
+    ));
+    assert_invalid(validate_update_moderation_action(
+        update(alice.clone()),
+        moderation_action.clone(),
+        entry_creation_action(alice.clone()),
+        moderation_action.clone(),
+    ));
+    assert_invalid(validate_delete_moderation_action(
+        delete(alice.clone()),
+        entry_creation_action(alice),
+        moderation_action,
+    ));
+}
+
+#[test]
+fn only_rooms_without_properties_get_the_default_properties() {
+    let from_bytes = |bytes: Vec<u8>| {
+        RoomDnaProperties::from_properties(SerializedBytes::from(UnsafeBytes::from(bytes)))
+    };
+    let defaults = from_bytes(vec![]).unwrap();
+    assert_eq!(defaults.progenitor, None);
+    assert!(!defaults.require_invitation);
+    assert!(from_bytes(vec![0xc0]).unwrap().invite_code_hash.is_none());
+    let properties = SerializedBytes::try_from(RoomDnaProperties {
+        require_invitation: true,
+        ..RoomDnaProperties::default()
+    })
+    .unwrap();
+    assert!(
+        RoomDnaProperties::from_properties(properties)
+            .unwrap()
+            .require_invitation
+    );
+    // A string where a map is expected
+    assert!(from_bytes(vec![0xa3, b'f', b'o', b'o']).is_err());
+}
+
+#[test]
+fn invite_codes_are_checked_against_the_membrane_proof() {
+    let invite_code_hash = "0".repeat(64);
+    let not_an_invite_code_proof: Option<MembraneProof> = Some(std::sync::Arc::new(
+        SerializedBytes::try_from(InvitationProof {
+            signature: Signature([0; 64]),
+        })
+        .unwrap(),
+    ));
+    assert_valid(check_invite_code(None, &None));
+    assert_invalid(check_invite_code(Some(invite_code_hash.as_str()), &None));
+    assert_invalid(check_invite_code(
+        Some(invite_code_hash.as_str()),
+        &not_an_invite_code_proof,
+    ));
+}
+
+#[test]
+fn storage_quota_must_not_be_zero() {
+    let alice = fake_agent(1);
+    assert_valid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        RoomSettings {
+            authored_bytes_quota: Some(50_000_000),
+            ..Default::default()
+        },
+    ));
+    assert_invalid(validate_create_room_settings(
+        entry_creation_action(alice),
+        RoomSettings {
+            authored_bytes_quota: Some(0),
+            ..Default::default()
+        },
+    ));
+}
+
+#[test]
+fn chime_policy_decides_which_chimes_are_played() {
+    assert_eq!(
+        RoomSettings::default().chime_policy,
+        ChimePolicy::JoinAndLeave
+    );
+    assert!(ChimePolicy::JoinAndLeave.plays_chime(false));
+    assert!(ChimePolicy::JoinOnly.plays_chime(true));
+    assert!(!ChimePolicy::JoinOnly.plays_chime(false));
+    assert!(!ChimePolicy::Off.plays_chime(true));
+}
+
+#[test]
+fn signal_max_age_must_not_be_zero() {
+    let alice = fake_agent(1);
+    assert_valid(validate_create_room_settings(
+        entry_creation_action(alice.clone()),
+        RoomSettings {
+            signal_max_age_secs: Some(30),
+            ..Default::default()
+        },
+    ));
+    assert_invalid(validate_create_room_settings(
+        entry_creation_action(alice),
+        RoomSettings {
+            signal_max_age_secs: Some(0),
+            ..Default::default()
+        },
+    ));
+}
@@ -0,0 +1,137 @@
+//! Property-based tests asserting that validation never panics on arbitrary input
+//! and agrees with a simplified model of the respective validation rules.
+mod common;
+
+use common::*;
+use hdi::prelude::*;
+use proptest::prelude::*;
+use room_integrity::*;
+
+fn arb_agent() -> impl Strategy<Value = AgentPubKey> {
+    prop::collection::vec(any::<u8>(), 36).prop_map(AgentPubKey::from_raw_36)
+}
+
+fn arb_any_linkable_hash() -> impl Strategy<Value = AnyLinkableHash> {
+    let bytes = prop::collection::vec(any::<u8>(), 36);
+    prop_oneof![
+        bytes
+            .clone()
+            .prop_map(|b| AnyLinkableHash::from(AgentPubKey::from_raw_36(b))),
+        bytes
+            .clone()
+            .prop_map(|b| AnyLinkableHash::from(EntryHash::from_raw_36(b))),
+        bytes.prop_map(|b| AnyLinkableHash::from(ActionHash::from_raw_36(b))),
+    ]
+}
+
+fn arb_link_tag() -> impl Strategy<Value = LinkTag> {
+    prop::collection::vec(any::<u8>(), 0..64).prop_map(LinkTag::new)
+}
+
+fn arb_room_metadata() -> impl Strategy<Value = RoomMetadata> {
+    (
+        prop::collection::vec(".{0,8}", 0..MAX_ROOM_METADATA_LABELS + 3),
+        prop::collection::vec(
+            (".{0,8}", ".{0,8}"),
+            0..MAX_ROOM_METADATA_EXTERNAL_LINKS + 3,
+        ),
+        prop::option::of(".{0,8}"),
+    )
+        .prop_map(|(labels, external_links, locale)| RoomMetadata {
+            labels,
+            external_links: external_links
+                .into_iter()
+                .map(|(label, url)| ExternalLink { label, url })
+                .collect(),
+            locale,
+        })
+}
+
+fn is_valid(result: ExternResult<ValidateCallbackResult>) -> bool {
+    matches!(result, Ok(ValidateCallbackResult::Valid))
+}
+
+proptest! {
+    #[test]
+    fn all_agents_link_agrees_with_model(
+        author in arb_agent(),
+        base in arb_any_linkable_hash(),
+        target in arb_any_linkable_hash(),
+        tag in arb_link_tag(),
+    ) {
+        let expected = AgentPubKey::try_from(target.clone()).ok() == Some(author.clone());
+        let action = create_link(author, base.clone(), target.clone(), tag.clone());
+        let result = validate_create_link_all_agents(action, base, target, tag);
+        prop_assert_eq!(is_valid(result), expected);
+    }
+
+    #[test]
+    fn reaction_palette_agrees_with_model(
+        palette in prop::option::of(prop::collection::vec(".{0,3}", 0..MAX_REACTION_PALETTE_SIZE + 3)),
+    ) {
+        let expected = match &palette {
+            None => true,
+            Some(palette) => {
+                let mut deduplicated = palette.clone();
+                deduplicated.sort();
+                deduplicated.dedup();
+                palette.len() <= MAX_REACTION_PALETTE_SIZE
+                    && palette.iter().all(|r| !r.trim().is_empty())
+                    && deduplicated.len() == palette.len()
+            }
+        };
+        let room_settings = RoomSettings {
+            reaction_palette: palette,
+            ..Default::default()
+        };
+        let result = validate_create_room_settings(entry_creation_action(fake_agent(1)), room_settings);
+        prop_assert_eq!(is_valid(result), expected);
+    }
+
+    #[test]
+    fn welcome_message_agrees_with_model(text in ".{0,40}", oversize in any::<bool>()) {
+        let text = if oversize {
+            text.repeat(MAX_WELCOME_MESSAGE_LENGTH)
+        } else {
+            text
+        };
+        let expected = !text.trim().is_empty() && text.len() <= MAX_WELCOME_MESSAGE_LENGTH;
+        let room_settings = RoomSettings {
+            welcome_message: Some(WelcomeMessage { text, sound_src: None }),
+            ..Default::default()
+        };
+        let result = validate_create_room_settings(entry_creation_action(fake_agent(1)), room_settings);
+        prop_assert_eq!(is_valid(result), expected);
+    }
+
+    #[test]
+    fn room_metadata_agrees_with_model(metadata in arb_room_metadata()) {
+        let expected = metadata.labels.len() <= MAX_ROOM_METADATA_LABELS
+            && metadata.labels.iter().all(|l| !l.trim().is_empty())
+            && metadata.external_links.len() <= MAX_ROOM_METADATA_EXTERNAL_LINKS
+            && metadata.external_links.iter().all(|l| !l.url.trim().is_empty())
+            && !metadata.locale.as_ref().is_some_and(|l| l.trim().is_empty());
+        let room_info = RoomInfo {
+            metadata: Some(metadata),
+            ..room_info()
+        };
+        let result = validate_create_room_info(entry_creation_action(fake_agent(1)), room_info);
+        prop_assert_eq!(is_valid(result), expected);
+    }
+
+    #[test]
+    fn legacy_meta_data_never_panics(meta_data in ".*") {
+        let room_info = RoomInfo {
+            meta_data: Some(meta_data),
+            ..room_info()
+        };
+        let _ = room_info.room_metadata();
+    }
+
+    #[test]
+    fn interpretation_language_never_panics(connection_type in ".*") {
+        if let Some(language) = interpretation_language(&connection_type) {
+            prop_assert!(connection_type.ends_with(language));
+        }
+    }
+}